@@ -0,0 +1,3 @@
+-- External targets (IPs, CIDRs or domains outside the topology) for NetworkChaos
+-- Stored as a JSON array; NULL means the condition only targets topology pods
+ALTER TABLE chaos_conditions ADD COLUMN external_targets TEXT;
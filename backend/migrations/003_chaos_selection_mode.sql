@@ -0,0 +1,3 @@
+-- Pod selection (blast radius) of chaos conditions: Chaos Mesh `mode` and `value`
+ALTER TABLE chaos_conditions ADD COLUMN selection_mode TEXT NOT NULL DEFAULT 'all';
+ALTER TABLE chaos_conditions ADD COLUMN selection_value TEXT;
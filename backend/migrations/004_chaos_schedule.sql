@@ -0,0 +1,3 @@
+-- Recurring chaos conditions (Chaos Mesh Schedule): cron expression and history limit
+ALTER TABLE chaos_conditions ADD COLUMN schedule TEXT;
+ALTER TABLE chaos_conditions ADD COLUMN history_limit INTEGER;
@@ -0,0 +1,3 @@
+-- Abort criteria (JSON) of chaos conditions and scenarios, evaluated by the chaos supervisor
+ALTER TABLE chaos_conditions ADD COLUMN abort_criteria TEXT;
+ALTER TABLE scenarios ADD COLUMN abort_criteria TEXT;
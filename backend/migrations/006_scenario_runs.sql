@@ -0,0 +1,19 @@
+-- Scenario runs: the plan of a run (steps, abort criteria) and the state of every step
+CREATE TABLE IF NOT EXISTS scenario_runs (
+    id TEXT PRIMARY KEY NOT NULL,
+    scenario_id TEXT NOT NULL,
+    topology_id TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'running',
+    force INTEGER NOT NULL DEFAULT 0,
+    total_duration INTEGER NOT NULL,
+    abort_criteria TEXT,
+    steps TEXT NOT NULL,
+    error TEXT,
+    started_at TEXT NOT NULL,
+    finished_at TEXT,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (scenario_id) REFERENCES scenarios(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_scenario_runs_scenario ON scenario_runs(scenario_id);
+CREATE INDEX IF NOT EXISTS idx_scenario_runs_status ON scenario_runs(status);
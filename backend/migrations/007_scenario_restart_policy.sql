@@ -0,0 +1,3 @@
+-- What to do with the runs of a scenario left running by a server restart
+ALTER TABLE scenarios ADD COLUMN restart_policy TEXT NOT NULL DEFAULT 'rollback';
+ALTER TABLE scenario_runs ADD COLUMN restart_policy TEXT NOT NULL DEFAULT 'rollback';
@@ -0,0 +1,3 @@
+-- Scenario run clock: pausing freezes the timeline, advancing moves it ahead
+ALTER TABLE scenario_runs ADD COLUMN time_shift REAL NOT NULL DEFAULT 0;
+ALTER TABLE scenario_runs ADD COLUMN paused_at REAL;
@@ -2,11 +2,14 @@ use axum::{
     extract::{Path, State},
     Json,
 };
+use k8s_openapi::api::apps::v1::Deployment;
 use uuid::Uuid;
 
 use crate::api::{AppState, Event};
+use crate::chaos::TC_BACKEND;
 use crate::error::{AppError, AppResult};
 use crate::helm::types::DeployAppRequest;
+use crate::k8s::resources::{add_tc_sidecar_to_deployment, create_application_deployment};
 use crate::k8s::{DeploymentManager, DeploymentState};
 use crate::models::Application;
 use serde::Deserialize;
@@ -199,7 +202,7 @@ pub async fn deploy(
 
     // Create the deployment
     tracing::info!("⚓ Creating Kubernetes deployment specification...");
-    let deployment = crate::k8s::resources::create_application_deployment(&app, &node_id, &topology_id.to_string());
+    let deployment = application_deployment(&state, &app, &node_id, &topology_id.to_string());
     tracing::info!("📦 Deployment spec created for: {}", deployment_name);
 
     tracing::info!("🚀 Sending deployment to Kubernetes API...");
@@ -832,6 +835,22 @@ pub async fn update_application(
     Ok(Json(app))
 }
 
+/// Deployment of an application on a node
+///
+/// Under the tc chaos backend its pods get the `tc` sidecar, like node pods.
+fn application_deployment(
+    state: &AppState,
+    app: &Application,
+    node_id: &str,
+    topology_id: &str,
+) -> Deployment {
+    let mut deployment = create_application_deployment(app, node_id, topology_id);
+    if state.config.chaos_backend == TC_BACKEND {
+        add_tc_sidecar_to_deployment(&mut deployment);
+    }
+    deployment
+}
+
 /// Deploy application as sidecar to a specific node
 pub async fn deploy_application_to_node(
     state: &AppState,
@@ -842,8 +861,6 @@ pub async fn deploy_application_to_node(
     let k8s = state.k8s.read().await.clone().ok_or("K8s client not available")?;
     
     // Create a separate deployment for the application
-    use crate::k8s::resources::create_application_deployment;
-    
     let deployment_name = crate::k8s::resources::make_deployment_name(&app.id.simple().to_string(), node_id);
     
     // Check if deployment already exists
@@ -853,7 +870,7 @@ pub async fn deploy_application_to_node(
     }
     
     // Create deployment spec for the application
-    let deployment = create_application_deployment(app, node_id, topology_id);
+    let deployment = application_deployment(state, app, node_id, topology_id);
     
     // Create the deployment
     k8s.create_deployment(&deployment).await?;
@@ -3,20 +3,28 @@
 //! Create, list, start, stop and delete chaos conditions on deployed topologies
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
+use serde::Deserialize;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use crate::api::events::{emit_event, EventSeverity, EventSourceType};
 use crate::api::AppState;
 use crate::chaos::{
-    ChaosClient, ChaosCondition, ChaosConditionStatus, CreateChaosRequest, UpdateChaosRequest,
+    chaos_resource_name, conflict_message, create_condition_manifest, create_schedule_manifest,
+    effective_fault_set, find_conflicts, reconcile_chaos, validate_abort_criteria,
+    validate_chaos_params, validate_duration, validate_external_targets, validate_recurrence,
+    validate_selection, ChaosClient, ChaosCondition, ChaosConditionStatus, ChaosConflict,
+    ChaosCorrection, ChaosCrdKind, ChaosDirection, ChaosRecurrence, ChaosType, CreateChaosRequest,
+    EmergencyStopReport, NodePairFaults,
+    ParamError, PodSelection, SelectionMode, UpdateChaosRequest,
 };
 use crate::error::{AppError, AppResult};
-
-/// Namespace for chaos resources
-const CHAOS_NAMESPACE: &str = "networksim-sim";
+use crate::k8s::resources::{
+    application_container_name, application_volume_mounts, NODE_MAIN_CONTAINER,
+};
 
 /// List all chaos conditions for a topology (from DB)
 #[utoipa::path(
@@ -72,6 +80,24 @@ pub async fn create(
         req.topology_id, req.chaos_type, req.source_node_id, req.target_node_id
     );
 
+    let condition = build_condition(&state, req).await?;
+
+    // Save to database
+    state.db.create_chaos_condition(&condition).await?;
+
+    info!("Created chaos condition {} (pending)", condition.id);
+
+    Ok(Json(condition))
+}
+
+/// Validate a create request and build the (pending, unsaved) condition
+///
+/// Scenario chaos steps are validated and built here too, so they behave like conditions
+/// created through this API.
+pub(crate) async fn build_condition(
+    state: &AppState,
+    mut req: CreateChaosRequest,
+) -> AppResult<ChaosCondition> {
     // Verify topology exists
     let topology = state
         .db
@@ -99,33 +125,117 @@ pub async fn create(
         }
     }
 
+    validate_params(&req.chaos_type, &req.params)?;
+    let external_targets = req.external_targets.take().unwrap_or_default();
+    check_external_targets(&req.chaos_type, &req.direction, &external_targets)?;
+    validate_selection(&req.selection).map_err(invalid_params)?;
+    validate_duration(req.duration.as_deref()).map_err(invalid_params)?;
+    validate_recurrence(&req.chaos_type, req.duration.as_deref(), &req.recurrence)
+        .map_err(invalid_params)?;
+    if let Some(criteria) = &req.abort_criteria {
+        validate_abort_criteria(criteria).map_err(invalid_params)?;
+    }
+    apply_io_volume_defaults(
+        state,
+        &req.topology_id,
+        &req.source_node_id,
+        &req.chaos_type,
+        &mut req.params,
+    )
+    .await?;
+    validate_container_names(
+        state,
+        &req.topology_id,
+        &req.source_node_id,
+        &req.chaos_type,
+        &req.params,
+    )
+    .await?;
+
     // Generate condition ID
     let condition_id = Uuid::new_v4().to_string()[..8].to_string();
     let now = chrono::Utc::now();
 
     // Build condition (pending status - not yet applied)
     let condition = ChaosCondition {
-        id: condition_id.clone(),
+        id: condition_id,
         topology_id: req.topology_id.clone(),
         source_node_id: req.source_node_id.clone(),
         target_node_id: req.target_node_id,
+        external_targets,
+        selection: req.selection,
+        recurrence: req.recurrence,
         chaos_type: req.chaos_type,
         direction: req.direction,
         duration: req.duration,
         params: req.params,
+        abort_criteria: req.abort_criteria,
         k8s_name: None,
         status: ChaosConditionStatus::Pending,
         started_at: None,
+        ends_at: None,
         created_at: now,
         updated_at: now,
     };
 
-    // Save to database
-    state.db.create_chaos_condition(&condition).await?;
+    // The configured backend may not implement every chaos type
+    state.chaos.check_supported(&condition)?;
+    // Builders reject params they cannot render as requested
+    create_condition_manifest(&condition, &state.config.k8s_namespace_sim)?;
 
-    info!("Created chaos condition {} (pending)", condition_id);
+    Ok(condition)
+}
 
-    Ok(Json(condition))
+/// Query parameters for starting conditions
+#[derive(Debug, Default, Deserialize)]
+pub struct StartChaosQuery {
+    /// Start even if the condition conflicts with active conditions
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Check a condition against the active conditions of its topology before starting it
+///
+/// Conflicts reject the start unless `force` is set, in which case they are recorded as a
+/// warning event and returned.
+pub(crate) async fn check_conflicts(
+    state: &AppState,
+    condition: &ChaosCondition,
+    force: bool,
+) -> AppResult<Vec<ChaosConflict>> {
+    let active: Vec<ChaosCondition> = state
+        .db
+        .list_chaos_conditions(&condition.topology_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.status == ChaosConditionStatus::Active)
+        .collect();
+    let conflicts = find_conflicts(condition, &active);
+    if conflicts.is_empty() {
+        return Ok(conflicts);
+    }
+
+    let message = conflict_message(&condition.id, &conflicts);
+    if !force {
+        return Err(AppError::Conflict(message));
+    }
+
+    warn!("{} (forced)", message);
+    emit_event(
+        state,
+        Some(&condition.topology_id),
+        "chaos",
+        Some("conflict"),
+        EventSeverity::Warning,
+        &format!("Chaos condition {} started despite conflicts", condition.id),
+        Some(&message),
+        Some(EventSourceType::Chaos),
+        Some(&condition.id),
+        Some(serde_json::json!({ "conflicts": conflicts })),
+    )
+    .await;
+
+    Ok(conflicts)
 }
 
 /// Start (activate) a chaos condition - applies it to K8s
@@ -135,17 +245,20 @@ pub async fn create(
     tag = "chaos",
     params(
         ("topology_id" = String, Path, description = "Topology ID"),
-        ("condition_id" = String, Path, description = "Chaos condition ID")
+        ("condition_id" = String, Path, description = "Chaos condition ID"),
+        ("force" = Option<bool>, Query, description = "Start despite conflicting active conditions"),
     ),
     responses(
         (status = 200, description = "Chaos condition started", body = ChaosCondition),
         (status = 404, description = "Condition not found"),
+        (status = 409, description = "Conflicts with active conditions on the same link"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn start(
     State(state): State<AppState>,
     Path((topology_id, condition_id)): Path<(String, String)>,
+    Query(query): Query<StartChaosQuery>,
 ) -> AppResult<Json<ChaosCondition>> {
     info!(
         "Starting chaos condition {} for topology {}",
@@ -171,22 +284,23 @@ pub async fn start(
         return Ok(Json(condition)); // Already running
     }
 
-    // Get chaos client
-    let chaos_client = ChaosClient::new(CHAOS_NAMESPACE).await?;
+    activate_condition(&state, &mut condition, query.force).await?;
 
-    // Create the chaos resource in K8s
-    let k8s_name = chaos_client
-        .create_chaos(
-            &condition.topology_id,
-            &condition.id,
-            &condition.source_node_id,
-            condition.target_node_id.as_deref(),
-            &condition.chaos_type,
-            &condition.direction,
-            condition.duration.as_deref(),
-            &condition.params,
-        )
-        .await?;
+    Ok(Json(condition))
+}
+
+/// Check conflicts, create the chaos resource and mark the condition active
+///
+/// Shared by the start and start-all endpoints and scenario chaos steps.
+pub(crate) async fn activate_condition(
+    state: &AppState,
+    condition: &mut ChaosCondition,
+    force: bool,
+) -> AppResult<()> {
+    check_conflicts(state, condition, force).await?;
+
+    // Create the chaos resource through the configured backend
+    let k8s_name = apply_resource(state, condition).await?;
 
     // Update DB
     state
@@ -200,14 +314,45 @@ pub async fn start(
 
     condition.status = ChaosConditionStatus::Active;
     condition.k8s_name = Some(k8s_name);
+    condition.started_at = Some(chrono::Utc::now());
+    condition.ends_at = condition.expected_end();
 
     // Broadcast event
     let _ = state.event_tx.send(crate::api::Event::ChaosApplied {
-        id: condition_id,
+        id: condition.id.clone(),
         target: condition.source_node_id.clone(),
     });
 
-    Ok(Json(condition))
+    Ok(())
+}
+
+/// Apply the resource of a condition, resuming the paused `Schedule` of a recurring one
+async fn apply_resource(state: &AppState, condition: &ChaosCondition) -> AppResult<String> {
+    if condition.recurrence.schedule.is_some()
+        && state
+            .chaos
+            .pause_schedule(&condition.topology_id, &condition.id, false)
+            .await?
+    {
+        return Ok(chaos_resource_name(&condition.topology_id, &condition.id));
+    }
+    state.chaos.create(condition).await
+}
+
+/// Remove the resource of a condition
+///
+/// Recurring conditions pause their `Schedule` instead, keeping its firing history until
+/// they are started again or deleted.
+async fn remove_resource(state: &AppState, condition: &ChaosCondition) -> AppResult<()> {
+    if condition.recurrence.schedule.is_some()
+        && state
+            .chaos
+            .pause_schedule(&condition.topology_id, &condition.id, true)
+            .await?
+    {
+        return Ok(());
+    }
+    state.chaos.delete(&condition.topology_id, &condition.id).await
 }
 
 /// Stop (pause) a chaos condition - removes from K8s but keeps in DB
@@ -253,13 +398,8 @@ pub async fn stop(
         return Ok(Json(condition)); // Not running
     }
 
-    // Get chaos client
-    let chaos_client = ChaosClient::new(CHAOS_NAMESPACE).await?;
-
-    // Delete from K8s
-    chaos_client
-        .delete_chaos(&topology_id, &condition_id)
-        .await?;
+    // Delete from K8s (recurring conditions pause their Schedule)
+    remove_resource(&state, &condition).await?;
 
     // Update DB
     state
@@ -269,6 +409,8 @@ pub async fn stop(
 
     condition.status = ChaosConditionStatus::Paused;
     condition.k8s_name = None;
+    condition.started_at = None;
+    condition.ends_at = None;
 
     // Broadcast event
     let _ = state
@@ -279,20 +421,28 @@ pub async fn stop(
 }
 
 /// Update a chaos condition (only editable fields)
+///
+/// An active condition is applied again with the new fields, after the same conflict
+/// check as a start.
 #[utoipa::path(
     put,
     path = "/api/topologies/{id}/chaos/{condition_id}",
     tag = "chaos",
     params(
         ("id" = String, Path, description = "Topology ID"),
-        ("condition_id" = String, Path, description = "Condition ID")
+        ("condition_id" = String, Path, description = "Condition ID"),
+        ("force" = Option<bool>, Query, description = "Apply despite conflicting active conditions"),
     ),
-    responses((status = 200, description = "Updated"))
+    responses(
+        (status = 200, description = "Updated"),
+        (status = 409, description = "Active condition conflicts with others on the same link")
+    )
 )]
 pub async fn update(
     State(state): State<AppState>,
     Path((topology_id, condition_id)): Path<(String, String)>,
-    Json(req): Json<UpdateChaosRequest>,
+    Query(query): Query<StartChaosQuery>,
+    Json(mut req): Json<UpdateChaosRequest>,
 ) -> AppResult<Json<ChaosCondition>> {
     info!(
         "Updating chaos condition {} for topology {}",
@@ -313,17 +463,81 @@ pub async fn update(
         ));
     }
 
+    validate_params(&condition.chaos_type, &req.params)?;
+    let external_targets = req
+        .external_targets
+        .take()
+        .unwrap_or_else(|| condition.external_targets.clone());
+    check_external_targets(&condition.chaos_type, &req.direction, &external_targets)?;
+    let selection = match req.selection_mode.take() {
+        Some(selection_mode) => PodSelection {
+            selection_mode,
+            selection_value: req.selection_value.take(),
+        },
+        None => condition.selection.clone(),
+    };
+    validate_selection(&selection).map_err(invalid_params)?;
+    let recurrence = match req.schedule.take() {
+        Some(schedule) if schedule.is_empty() => ChaosRecurrence::default(),
+        Some(schedule) => ChaosRecurrence {
+            schedule: Some(schedule),
+            history_limit: req.history_limit,
+        },
+        None => ChaosRecurrence {
+            history_limit: req.history_limit.or(condition.recurrence.history_limit),
+            ..condition.recurrence.clone()
+        },
+    };
+    validate_duration(req.duration.as_deref()).map_err(invalid_params)?;
+    validate_recurrence(&condition.chaos_type, req.duration.as_deref(), &recurrence)
+        .map_err(invalid_params)?;
+    let abort_criteria = req
+        .abort_criteria
+        .take()
+        .unwrap_or_else(|| condition.abort_criteria.take());
+    if let Some(criteria) = &abort_criteria {
+        validate_abort_criteria(criteria).map_err(invalid_params)?;
+    }
+    apply_io_volume_defaults(
+        &state,
+        &condition.topology_id,
+        &condition.source_node_id,
+        &condition.chaos_type,
+        &mut req.params,
+    )
+    .await?;
+    validate_container_names(
+        &state,
+        &condition.topology_id,
+        &condition.source_node_id,
+        &condition.chaos_type,
+        &req.params,
+    )
+    .await?;
+
+    // A stopped recurring condition keeps a paused Schedule with the old spec
+    let paused_schedule = condition.status != ChaosConditionStatus::Active
+        && condition.recurrence.schedule.is_some();
+
     // Update the condition fields
     condition.direction = req.direction;
+    condition.external_targets = external_targets;
+    condition.selection = selection;
+    condition.recurrence = recurrence;
     condition.duration = req.duration;
     condition.params = req.params;
+    condition.abort_criteria = abort_criteria;
     condition.updated_at = chrono::Utc::now();
+    state.chaos.check_supported(&condition)?;
+    create_condition_manifest(&condition, &state.config.k8s_namespace_sim)?;
 
     // If condition is active, we need to restart it with new parameters
     if condition.status == ChaosConditionStatus::Active {
+        // The new fields may overlap other active conditions
+        check_conflicts(&state, &condition, query.force).await?;
+
         // Stop the current chaos
-        let chaos_client = ChaosClient::new(CHAOS_NAMESPACE).await?;
-        if let Err(e) = chaos_client.delete_chaos(&topology_id, &condition.id).await {
+        if let Err(e) = state.chaos.delete(&topology_id, &condition.id).await {
             warn!(
                 "Failed to delete old chaos {} from K8s: {}",
                 condition.id, e
@@ -331,20 +545,17 @@ pub async fn update(
         }
 
         // Create new chaos with updated parameters
-        let k8s_name = chaos_client
-            .create_chaos(
-                &condition.topology_id,
-                &condition.id,
-                &condition.source_node_id,
-                condition.target_node_id.as_deref(),
-                &condition.chaos_type,
-                &condition.direction,
-                condition.duration.as_deref(),
-                &condition.params,
-            )
-            .await?;
+        let k8s_name = state.chaos.create(&condition).await?;
 
         condition.k8s_name = Some(k8s_name);
+    } else if paused_schedule {
+        // Dropped so the next start creates the updated spec
+        if let Err(e) = state.chaos.delete(&topology_id, &condition.id).await {
+            warn!(
+                "Failed to delete paused Schedule of {} from K8s: {}",
+                condition.id, e
+            );
+        }
     }
 
     // Update in DB
@@ -363,12 +574,16 @@ pub async fn update(
     post,
     path = "/api/topologies/{id}/chaos/start",
     tag = "chaos",
-    params(("id" = String, Path, description = "Topology ID")),
+    params(
+        ("id" = String, Path, description = "Topology ID"),
+        ("force" = Option<bool>, Query, description = "Start despite conflicting active conditions"),
+    ),
     responses((status = 200, description = "Started all chaos conditions"))
 )]
 pub async fn start_all(
     State(state): State<AppState>,
     Path(topology_id): Path<String>,
+    Query(query): Query<StartChaosQuery>,
 ) -> AppResult<Json<serde_json::Value>> {
     info!("Starting all chaos conditions for topology {}", topology_id);
 
@@ -382,42 +597,14 @@ pub async fn start_all(
     // Get all conditions
     let conditions = state.db.list_chaos_conditions(&topology_id).await?;
 
-    let chaos_client = ChaosClient::new(CHAOS_NAMESPACE).await?;
     let mut started = 0;
     let mut errors = Vec::new();
 
-    for condition in conditions {
+    for mut condition in conditions {
         if condition.status != ChaosConditionStatus::Active {
-            match chaos_client
-                .create_chaos(
-                    &condition.topology_id,
-                    &condition.id,
-                    &condition.source_node_id,
-                    condition.target_node_id.as_deref(),
-                    &condition.chaos_type,
-                    &condition.direction,
-                    condition.duration.as_deref(),
-                    &condition.params,
-                )
-                .await
-            {
-                Ok(k8s_name) => {
-                    let _ = state
-                        .db
-                        .update_chaos_condition_status(
-                            &condition.id,
-                            &ChaosConditionStatus::Active,
-                            Some(&k8s_name),
-                        )
-                        .await;
-
-                    let _ = state.event_tx.send(crate::api::Event::ChaosApplied {
-                        id: condition.id.clone(),
-                        target: condition.source_node_id.clone(),
-                    });
-
-                    started += 1;
-                }
+            // Conditions started earlier in this loop count as active
+            match activate_condition(&state, &mut condition, query.force).await {
+                Ok(()) => started += 1,
                 Err(e) => {
                     warn!("Failed to start condition {}: {}", condition.id, e);
                     errors.push(format!("{}: {}", condition.id, e));
@@ -457,13 +644,12 @@ pub async fn stop_all(
     // Get all active conditions
     let conditions = state.db.list_chaos_conditions(&topology_id).await?;
 
-    let chaos_client = ChaosClient::new(CHAOS_NAMESPACE).await?;
     let mut stopped = 0;
 
     for condition in conditions {
         if condition.status == ChaosConditionStatus::Active {
-            // Delete from K8s
-            if let Err(e) = chaos_client.delete_chaos(&topology_id, &condition.id).await {
+            // Delete from K8s (recurring conditions pause their Schedule)
+            if let Err(e) = remove_resource(&state, &condition).await {
                 warn!("Failed to delete chaos {} from K8s: {}", condition.id, e);
             }
 
@@ -514,10 +700,10 @@ pub async fn delete(
     let condition = state.db.get_chaos_condition(&condition_id).await?;
 
     if let Some(cond) = condition {
-        // If active, remove from K8s first
-        if cond.status == ChaosConditionStatus::Active {
-            let chaos_client = ChaosClient::new(CHAOS_NAMESPACE).await?;
-            let _ = chaos_client.delete_chaos(&topology_id, &condition_id).await;
+        // If active, remove from K8s first; stopped recurring conditions keep a paused
+        // Schedule
+        if cond.status == ChaosConditionStatus::Active || cond.recurrence.schedule.is_some() {
+            let _ = state.chaos.delete(&topology_id, &condition_id).await;
         }
 
         // Delete from DB
@@ -566,10 +752,11 @@ pub async fn delete_all(
     let conditions = state.db.list_chaos_conditions(&topology_id).await?;
 
     // Clean up K8s resources
-    let chaos_client = ChaosClient::new(CHAOS_NAMESPACE).await?;
     for condition in &conditions {
-        if condition.status == ChaosConditionStatus::Active {
-            if let Err(e) = chaos_client.delete_chaos(&topology_id, &condition.id).await {
+        if condition.status == ChaosConditionStatus::Active
+            || condition.recurrence.schedule.is_some()
+        {
+            if let Err(e) = state.chaos.delete(&topology_id, &condition.id).await {
                 warn!("Failed to delete chaos {} from K8s: {}", condition.id, e);
             }
         }
@@ -589,7 +776,7 @@ pub async fn delete_all(
 }
 
 /// Application affected by chaos condition
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
 pub struct AffectedApp {
     pub app_id: String,
     pub app_name: String,
@@ -605,6 +792,8 @@ pub struct AffectedAppsResponse {
     pub chaos_type: String,
     pub source_node_id: String,
     pub target_node_id: Option<String>,
+    /// Destinations outside the topology whose traffic is affected
+    pub external_targets: Vec<String>,
     pub affected_apps: Vec<AffectedApp>,
     pub total_affected: usize,
 }
@@ -638,6 +827,25 @@ pub async fn affected_apps(
         .await?
         .ok_or_else(|| AppError::not_found(&format!("Condition {} not found", condition_id)))?;
 
+    let affected_apps = resolve_affected_apps(&state, &condition).await?;
+    let total = affected_apps.len();
+
+    Ok(Json(AffectedAppsResponse {
+        condition_id: condition.id,
+        chaos_type: condition.chaos_type.to_string(),
+        source_node_id: condition.source_node_id,
+        target_node_id: condition.target_node_id,
+        external_targets: condition.external_targets,
+        affected_apps,
+        total_affected: total,
+    }))
+}
+
+/// Apps deployed on the source node (direct impact) and target node (indirect impact)
+async fn resolve_affected_apps(
+    state: &AppState,
+    condition: &ChaosCondition,
+) -> AppResult<Vec<AffectedApp>> {
     // Get topology to resolve node names
     let topology = state
         .db
@@ -693,14 +901,470 @@ pub async fn affected_apps(
         }
     }
 
-    let total = affected_apps.len();
+    Ok(affected_apps)
+}
 
-    Ok(Json(AffectedAppsResponse {
-        condition_id: condition.id,
-        chaos_type: format!("{:?}", condition.chaos_type).to_lowercase(),
-        source_node_id: condition.source_node_id,
-        target_node_id: condition.target_node_id,
-        affected_apps,
-        total_affected: total,
+/// Rendered manifest and cluster-side checks for a condition, without applying it
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct ChaosPreviewResponse {
+    /// Condition id (freshly generated when previewing a create request)
+    pub condition_id: String,
+    /// Resource kind that would be created (`Schedule` for recurring conditions)
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    /// Manifest exactly as it would be applied
+    pub manifest: serde_json::Value,
+    pub manifest_yaml: String,
+    /// Pods the fault would be injected into right now
+    pub target_pods: Vec<String>,
+    /// Pods on the other side of a NetworkChaos (the `target` selector)
+    pub peer_pods: Vec<String>,
+    pub affected_apps: Vec<AffectedApp>,
+    /// Whether the server-side dry-run apply was accepted
+    pub dry_run_ok: bool,
+    /// Validation or admission error returned by the API server
+    pub dry_run_error: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Reconcile chaos conditions with the resources in the cluster now
+///
+/// Runs the same pass as the periodic reconciler and returns the corrections made.
+/// Corrections that could not be applied carry their `error` and are retried on the next pass.
+#[utoipa::path(
+    post,
+    path = "/api/chaos/reconcile",
+    tag = "chaos",
+    responses(
+        (status = 200, description = "Corrections made", body = Vec<ChaosCorrection>),
+        (status = 500, description = "Chaos backend unavailable")
+    )
+)]
+pub async fn reconcile(State(state): State<AppState>) -> AppResult<Json<Vec<ChaosCorrection>>> {
+    info!("Reconciling chaos state");
+
+    Ok(Json(reconcile_chaos(&state).await?))
+}
+
+/// Remove every chaos resource applied by NetworkSim, in all topologies and namespaces
+///
+/// Resources the database does not know are removed too (`known: false`). Every active
+/// condition is paused. Safe to call repeatedly.
+#[utoipa::path(
+    post,
+    path = "/api/chaos/emergency-stop",
+    tag = "chaos",
+    responses(
+        (status = 200, description = "Per-resource results", body = EmergencyStopReport),
+        (status = 500, description = "Chaos backend unavailable")
+    )
+)]
+pub async fn emergency_stop(
+    State(state): State<AppState>,
+) -> AppResult<Json<EmergencyStopReport>> {
+    Ok(Json(crate::chaos::emergency_stop(&state).await?))
+}
+
+/// Faults currently in effect on a topology
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct EffectiveFaultSet {
+    pub topology_id: String,
+    /// One entry per node pair with at least one active fault
+    pub pairs: Vec<NodePairFaults>,
+}
+
+/// Active faults per node pair, with the conditions that overwrite each other
+#[utoipa::path(
+    get,
+    path = "/api/topologies/{id}/chaos/effective",
+    tag = "chaos",
+    params(("id" = String, Path, description = "Topology ID")),
+    responses(
+        (status = 200, description = "Effective fault set", body = EffectiveFaultSet),
+        (status = 404, description = "Topology not found")
+    )
+)]
+pub async fn effective_faults(
+    State(state): State<AppState>,
+    Path(topology_id): Path<String>,
+) -> AppResult<Json<EffectiveFaultSet>> {
+    let topology = state
+        .db
+        .get_topology(&topology_id)
+        .await?
+        .ok_or_else(|| AppError::not_found(&format!("Topology {} not found", topology_id)))?;
+
+    let active: Vec<ChaosCondition> = state
+        .db
+        .list_chaos_conditions(&topology_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.status == ChaosConditionStatus::Active)
+        .collect();
+    let node_ids: Vec<String> = topology.nodes.iter().map(|n| n.id.clone()).collect();
+
+    Ok(Json(EffectiveFaultSet {
+        pairs: effective_fault_set(&node_ids, &active),
+        topology_id,
     }))
 }
+
+/// Preview the manifest of a chaos request without saving or applying it
+///
+/// POST /api/chaos/preview
+#[utoipa::path(
+    post,
+    path = "/api/chaos/preview",
+    tag = "chaos",
+    request_body = CreateChaosRequest,
+    responses(
+        (status = 200, description = "Rendered manifest, selected pods and dry-run result",
+            body = ChaosPreviewResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Topology or node not found")
+    )
+)]
+pub async fn preview(
+    State(state): State<AppState>,
+    Json(req): Json<CreateChaosRequest>,
+) -> AppResult<Json<ChaosPreviewResponse>> {
+    info!(
+        "Previewing chaos for topology {} (type={:?}, source={})",
+        req.topology_id, req.chaos_type, req.source_node_id
+    );
+
+    let condition = build_condition(&state, req).await?;
+
+    Ok(Json(render_preview(&state, &condition).await?))
+}
+
+/// Preview the manifest of an existing chaos condition without applying it
+///
+/// GET /api/topologies/:id/chaos/:condition_id/preview
+#[utoipa::path(
+    get,
+    path = "/api/topologies/{id}/chaos/{condition_id}/preview",
+    tag = "chaos",
+    params(
+        ("id" = String, Path, description = "Topology ID"),
+        ("condition_id" = String, Path, description = "Chaos condition ID")
+    ),
+    responses(
+        (status = 200, description = "Rendered manifest, selected pods and dry-run result",
+            body = ChaosPreviewResponse),
+        (status = 404, description = "Condition not found")
+    )
+)]
+pub async fn preview_condition(
+    State(state): State<AppState>,
+    Path((topology_id, condition_id)): Path<(String, String)>,
+) -> AppResult<Json<ChaosPreviewResponse>> {
+    let condition = state
+        .db
+        .get_chaos_condition(&condition_id)
+        .await?
+        .ok_or_else(|| AppError::not_found(&format!("Condition {} not found", condition_id)))?;
+
+    if condition.topology_id != topology_id {
+        return Err(AppError::bad_request(
+            "Condition does not belong to this topology",
+        ));
+    }
+
+    Ok(Json(render_preview(&state, &condition).await?))
+}
+
+async fn render_preview(
+    state: &AppState,
+    condition: &ChaosCondition,
+) -> AppResult<ChaosPreviewResponse> {
+    let crd_kind = condition.chaos_type.crd_kind();
+    let namespace = &state.config.k8s_namespace_sim;
+    let chaos_manifest = create_condition_manifest(condition, namespace)?;
+    let manifest = if condition.recurrence.schedule.is_some() {
+        create_schedule_manifest(&chaos_manifest, &crd_kind, &condition.recurrence)
+    } else {
+        chaos_manifest.clone()
+    };
+    let manifest_yaml = serde_yaml::to_string(&manifest)
+        .map_err(|e| AppError::internal(&format!("Failed to render YAML: {}", e)))?;
+
+    let affected_apps = resolve_affected_apps(state, condition).await?;
+
+    let mut warnings = Vec::new();
+    if condition.duration.is_none() {
+        warnings.push(
+            "No duration set: the fault stays injected until the condition is stopped".to_string(),
+        );
+    }
+
+    let mut target_pods = Vec::new();
+    let mut peer_pods = Vec::new();
+    let mut dry_run_ok = false;
+    let mut dry_run_error = None;
+
+    match ChaosClient::new(namespace).await {
+        Ok(client) => {
+            match client.selected_pods(&chaos_manifest["spec"]["selector"]).await {
+                Ok(pods) => target_pods = pods,
+                Err(e) => warnings.push(format!("Failed to resolve target pods: {}", e)),
+            }
+            if let Some(target) = chaos_manifest["spec"].get("target") {
+                match client.selected_pods(&target["selector"]).await {
+                    Ok(pods) => peer_pods = pods,
+                    Err(e) => warnings.push(format!("Failed to resolve peer pods: {}", e)),
+                }
+            }
+
+            if target_pods.is_empty() {
+                warnings.push("No pod matches the selector (is the topology deployed?)".to_string());
+            } else if condition.selection.selection_mode != SelectionMode::All {
+                warnings.push(format!(
+                    "Selection mode '{}' injects only a subset of the {} matched pods",
+                    condition.selection.selection_mode,
+                    target_pods.len()
+                ));
+            }
+
+            match client.dry_run_create(&crd_kind, &manifest).await {
+                Ok(()) => dry_run_ok = true,
+                Err(e) => dry_run_error = Some(e.to_string()),
+            }
+        }
+        Err(e) => warnings.push(format!(
+            "Kubernetes unavailable, pod resolution and dry-run skipped: {}",
+            e
+        )),
+    }
+
+    Ok(ChaosPreviewResponse {
+        condition_id: condition.id.clone(),
+        kind: manifest["kind"].as_str().unwrap_or_default().to_string(),
+        name: chaos_resource_name(&condition.topology_id, &condition.id),
+        namespace: namespace.clone(),
+        manifest,
+        manifest_yaml,
+        target_pods,
+        peer_pods,
+        affected_apps,
+        dry_run_ok,
+        dry_run_error,
+        warnings,
+    })
+}
+
+/// Container of a node that can be targeted by container-scoped chaos
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct ChaosContainerTarget {
+    /// Container name to use in `container_names`
+    pub container_name: String,
+    /// Application running in the container (None for the node's base container)
+    pub app_id: Option<String>,
+    /// Image of the container's application
+    pub image_name: Option<String>,
+}
+
+/// Containers of a node, derived from the node's Application records
+async fn node_container_targets(
+    state: &AppState,
+    topology_id: &str,
+    node_id: &str,
+) -> AppResult<Vec<ChaosContainerTarget>> {
+    let apps = state.db.list_applications(topology_id).await?;
+
+    let mut targets = vec![ChaosContainerTarget {
+        container_name: NODE_MAIN_CONTAINER.to_string(),
+        app_id: None,
+        image_name: None,
+    }];
+
+    for app in apps
+        .iter()
+        .filter(|a| a.node_selector.iter().any(|n| n == node_id))
+    {
+        targets.push(ChaosContainerTarget {
+            container_name: application_container_name(app),
+            app_id: Some(app.id.to_string()),
+            image_name: Some(app.image_name.clone()),
+        });
+    }
+
+    Ok(targets)
+}
+
+/// Reject params that the manifest builders would otherwise replace with defaults
+fn validate_params(chaos_type: &ChaosType, params: &serde_json::Value) -> AppResult<()> {
+    validate_chaos_params(chaos_type, params).map_err(invalid_params)
+}
+
+/// External targets are only supported by NetworkChaos on egress traffic
+fn check_external_targets(
+    chaos_type: &ChaosType,
+    direction: &ChaosDirection,
+    targets: &[String],
+) -> AppResult<()> {
+    if targets.is_empty() {
+        return Ok(());
+    }
+
+    if !chaos_type.is_network_chaos() {
+        return Err(AppError::bad_request(&format!(
+            "external_targets are not supported for {} (NetworkChaos types only)",
+            chaos_type
+        )));
+    }
+
+    if !matches!(direction, ChaosDirection::To) {
+        return Err(AppError::bad_request(
+            "external_targets require direction \"to\"",
+        ));
+    }
+
+    validate_external_targets(targets).map_err(invalid_params)
+}
+
+/// Field-level errors are returned in `error.details` as `[{field, message}]`
+fn invalid_params(errors: Vec<ParamError>) -> AppError {
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    AppError::validation(
+        &format!("Invalid chaos params: {}", messages.join("; ")),
+        serde_json::json!(errors),
+    )
+}
+
+/// Default the volume of IOChaos params to the first volume mounted by the node's apps
+///
+/// Sets `volume_path` (the builder derives `path` from it) and `container_names`
+/// so the fault lands in the container that owns the mount.
+async fn apply_io_volume_defaults(
+    state: &AppState,
+    topology_id: &str,
+    node_id: &str,
+    chaos_type: &ChaosType,
+    params: &mut serde_json::Value,
+) -> AppResult<()> {
+    if chaos_type.crd_kind() != ChaosCrdKind::IOChaos {
+        return Ok(());
+    }
+
+    if params.is_null() {
+        *params = serde_json::json!({});
+    }
+    let Some(obj) = params.as_object_mut() else {
+        return Ok(());
+    };
+    if obj.get("volume_path").is_some_and(|v| !v.is_null()) {
+        return Ok(());
+    }
+
+    let apps = state.db.list_applications(topology_id).await?;
+    let mount = apps
+        .iter()
+        .filter(|a| a.node_selector.iter().any(|n| n == node_id))
+        .find_map(|app| {
+            application_volume_mounts(app)
+                .into_iter()
+                .next()
+                .map(|m| (application_container_name(app), m.mount_path))
+        });
+
+    match mount {
+        Some((container, mount_path)) => {
+            info!(
+                "Defaulting {} volume_path to {} ({})",
+                chaos_type, mount_path, container
+            );
+            obj.insert("volume_path".to_string(), serde_json::json!(mount_path));
+            if obj.get("container_names").is_none_or(|v| v.is_null()) {
+                obj.insert("container_names".to_string(), serde_json::json!([container]));
+            }
+            Ok(())
+        }
+        // io-delay historically targets the whole pod filesystem
+        None if *chaos_type == ChaosType::IoDelay => Ok(()),
+        None => Err(AppError::bad_request(&format!(
+            "Node {} has no application volume mounts; set params.volume_path",
+            node_id
+        ))),
+    }
+}
+
+/// Check that every container named in `params.container_names` exists on the node
+async fn validate_container_names(
+    state: &AppState,
+    topology_id: &str,
+    node_id: &str,
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> AppResult<()> {
+    let names: Vec<String> = match params.get("container_names") {
+        None | Some(serde_json::Value::Null) => Vec::new(),
+        Some(value) => serde_json::from_value(value.clone()).map_err(|_| {
+            AppError::bad_request("params.container_names must be a list of container names")
+        })?,
+    };
+
+    if names.is_empty() {
+        if *chaos_type == ChaosType::ContainerKill {
+            return Err(AppError::bad_request(
+                "container-kill requires at least one entry in params.container_names",
+            ));
+        }
+        return Ok(());
+    }
+
+    let targets = node_container_targets(state, topology_id, node_id).await?;
+    for name in &names {
+        if !targets.iter().any(|t| &t.container_name == name) {
+            let available: Vec<&str> = targets.iter().map(|t| t.container_name.as_str()).collect();
+            return Err(AppError::bad_request(&format!(
+                "Container '{}' not found on node {} (available: {})",
+                name,
+                node_id,
+                available.join(", ")
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// List the containers of a node that can be targeted by chaos
+///
+/// GET /api/topologies/:id/nodes/:node_id/chaos/containers
+#[utoipa::path(
+    get,
+    path = "/api/topologies/{id}/nodes/{node_id}/chaos/containers",
+    tag = "chaos",
+    params(
+        ("id" = String, Path, description = "Topology ID"),
+        ("node_id" = String, Path, description = "Node ID")
+    ),
+    responses(
+        (status = 200, description = "Selectable containers", body = Vec<ChaosContainerTarget>),
+        (status = 404, description = "Topology or node not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn container_targets(
+    State(state): State<AppState>,
+    Path((topology_id, node_id)): Path<(String, String)>,
+) -> AppResult<Json<Vec<ChaosContainerTarget>>> {
+    let topology = state
+        .db
+        .get_topology(&topology_id)
+        .await?
+        .ok_or_else(|| AppError::not_found(&format!("Topology {} not found", topology_id)))?;
+
+    if !topology.nodes.iter().any(|n| n.id == node_id) {
+        return Err(AppError::not_found(&format!(
+            "Node {} not found in topology",
+            node_id
+        )));
+    }
+
+    let targets = node_container_targets(&state, &topology_id, &node_id).await?;
+
+    Ok(Json(targets))
+}
@@ -7,6 +7,7 @@ use tracing::{info, warn};
 
 use crate::api::AppState;
 use crate::api::applications::deploy_application_to_node;
+use crate::chaos::TC_BACKEND;
 use crate::error::{AppError, AppResult};
 use crate::k8s::{DeploymentManager, DeploymentState, DeploymentStatus as K8sDeploymentStatus};
 use utoipa::ToSchema;
@@ -133,7 +134,8 @@ pub async fn deploy(
     })?;
 
     // Create deployment manager and deploy
-    let manager = DeploymentManager::new(k8s.clone());
+    let manager = DeploymentManager::new(k8s.clone())
+        .with_tc_sidecar(state.config.chaos_backend == TC_BACKEND);
     let status = manager.deploy(&topology).await.map_err(|e| {
         warn!(error = %e, "Failed to deploy topology");
         AppError::internal(&format!("Deployment failed: {}", e))
@@ -760,7 +760,7 @@ pub async fn run_app_to_app_test(
 
     // Get pod info for both apps
     let client: &Client = k8s.inner();
-    let pods: Api<Pod> = Api::namespaced(client.clone(), "networksim-sim");
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &state.config.k8s_namespace_sim);
 
     // Get source app pod
     let from_node_id = from_app.node_selector.first()
@@ -822,18 +822,18 @@ pub async fn run_app_to_app_test(
         "http" => {
             let port = request.port.unwrap_or(80);
             let path = request.path.as_deref().unwrap_or("/");
-            run_http_test(client, &from_pod_name, &to_pod_ip, port, path, request.timeout_secs).await
+            run_http_test(&pods, &from_pod_name, &to_pod_ip, port, path, request.timeout_secs).await
         }
         "tcp" => {
             let port = request.port.ok_or_else(|| AppError::bad_request("Port required for TCP test"))?;
-            run_tcp_test(client, &from_pod_name, &to_pod_ip, port, request.timeout_secs).await
+            run_tcp_test(&pods, &from_pod_name, &to_pod_ip, port, request.timeout_secs).await
         }
         "ping" => {
-            run_ping_test(client, &from_pod_name, &to_pod_ip, request.timeout_secs).await
+            run_ping_test(&pods, &from_pod_name, &to_pod_ip, request.timeout_secs).await
         }
         _ => {
             // Default to ping for unknown types (preserve previous behavior)
-            run_ping_test(client, &from_pod_name, &to_pod_ip, request.timeout_secs).await
+            run_ping_test(&pods, &from_pod_name, &to_pod_ip, request.timeout_secs).await
         }
     };
 
@@ -875,17 +875,16 @@ async fn get_pod_for_deployment(pods: &Api<Pod>, deployment_name: &str) -> Resul
 
 /// Run HTTP connectivity test
 async fn run_http_test(
-    client: &Client,
+    pods: &Api<Pod>,
     from_pod: &str,
     to_ip: &str,
     port: u16,
     path: &str,
     timeout_secs: u32,
 ) -> (bool, Option<f64>, Option<u16>, Option<String>) {
-    use kube::api::{Api, AttachParams};
+    use kube::api::AttachParams;
     use tokio::io::AsyncReadExt;
 
-    let pods: Api<Pod> = Api::namespaced(client.clone(), "networksim-sim");
     let ap = AttachParams {
         stdin: false,
         stdout: true,
@@ -932,16 +931,15 @@ async fn run_http_test(
 
 /// Run TCP connectivity test
 async fn run_tcp_test(
-    client: &Client,
+    pods: &Api<Pod>,
     from_pod: &str,
     to_ip: &str,
     port: u16,
     timeout_secs: u32,
 ) -> (bool, Option<f64>, Option<u16>, Option<String>) {
-    use kube::api::{Api, AttachParams};
+    use kube::api::AttachParams;
     use tokio::io::AsyncReadExt;
 
-    let pods: Api<Pod> = Api::namespaced(client.clone(), "networksim-sim");
     let ap = AttachParams {
         stdin: false,
         stdout: true,
@@ -988,15 +986,14 @@ async fn run_tcp_test(
 
 /// Run ping connectivity test
 async fn run_ping_test(
-    client: &Client,
+    pods: &Api<Pod>,
     from_pod: &str,
     to_ip: &str,
     timeout_secs: u32,
 ) -> (bool, Option<f64>, Option<u16>, Option<String>) {
-    use kube::api::{Api, AttachParams};
+    use kube::api::AttachParams;
     use tokio::io::AsyncReadExt;
 
-    let pods: Api<Pod> = Api::namespaced(client.clone(), "networksim-sim");
     let ap = AttachParams {
         stdin: false,
         stdout: true,
@@ -49,6 +49,7 @@ pub enum EventSourceType {
     Application,
     System,
     Test,
+    Scenario,
 }
 
 impl std::fmt::Display for EventSourceType {
@@ -61,6 +62,7 @@ impl std::fmt::Display for EventSourceType {
             EventSourceType::Application => write!(f, "application"),
             EventSourceType::System => write!(f, "system"),
             EventSourceType::Test => write!(f, "test"),
+            EventSourceType::Scenario => write!(f, "scenario"),
         }
     }
 }
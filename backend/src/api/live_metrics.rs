@@ -182,6 +182,34 @@ pub async fn get_live_metrics(
 ) -> AppResult<Json<LiveMetricsSnapshot>> {
     info!(topology_id = %topology_id, "Getting live metrics");
 
+    Ok(Json(collect_live_metrics(&state, &topology_id).await?))
+}
+
+/// Measure connectivity between every pair of nodes of a topology and save the metrics
+pub async fn collect_live_metrics(
+    state: &AppState,
+    topology_id: &str,
+) -> AppResult<LiveMetricsSnapshot> {
+    live_metrics(state, topology_id, true).await
+}
+
+/// Measure connectivity like `collect_live_metrics`, without saving the metrics
+///
+/// For periodic checks, which would otherwise fill the metrics history.
+pub async fn measure_live_metrics(
+    state: &AppState,
+    topology_id: &str,
+) -> AppResult<LiveMetricsSnapshot> {
+    live_metrics(state, topology_id, false).await
+}
+
+async fn live_metrics(
+    state: &AppState,
+    topology_id: &str,
+    save: bool,
+) -> AppResult<LiveMetricsSnapshot> {
+    let topology_id = topology_id.to_string();
+
     // Check K8s client
     let k8s = state
         .k8s
@@ -199,35 +227,8 @@ pub async fn get_live_metrics(
 
     // Get pods
     let client: &Client = k8s.inner();
-    let pods: Api<Pod> = Api::namespaced(client.clone(), "networksim-sim");
-
-    let pod_list = pods
-        .list(&kube::api::ListParams::default().labels(&format!("networksim.io/topology={}", topology_id)))
-        .await
-        .map_err(|e| AppError::internal(&format!("Failed to list pods: {}", e)))?;
-
-    // Build pod info
-    let mut pod_info: HashMap<String, (String, String)> = HashMap::new(); // node_id -> (pod_name, pod_ip)
-    for pod in &pod_list.items {
-        let node_id = pod
-            .metadata
-            .labels
-            .as_ref()
-            .and_then(|l| l.get("networksim.io/node"))
-            .cloned()
-            .unwrap_or_default();
-
-        let pod_name = pod.metadata.name.clone().unwrap_or_default();
-        let pod_ip = pod
-            .status
-            .as_ref()
-            .and_then(|s| s.pod_ip.clone())
-            .unwrap_or_default();
-
-        if !node_id.is_empty() && !pod_ip.is_empty() {
-            pod_info.insert(node_id, (pod_name, pod_ip));
-        }
-    }
+    let namespace = state.config.k8s_namespace_sim.as_str();
+    let (pod_list, pod_info) = topology_pods(client, namespace, &topology_id).await?;
 
     let now = Utc::now();
     let mut network_metrics = Vec::new();
@@ -268,7 +269,7 @@ pub async fn get_live_metrics(
     let futures = tasks.iter().map(|(_, _, from_pod, to_ip)| {
         let client = client.clone();
         async move {
-            measure_connectivity(&client, from_pod, to_ip).await
+            measure_connectivity(&client, namespace, from_pod, to_ip).await
         }
     });
     
@@ -310,7 +311,9 @@ pub async fn get_live_metrics(
             chaos_conditions: if affecting_chaos.is_empty() { None } else { Some(affecting_chaos) },
         };
 
-        let _ = save_network_metric(&state, &metric).await;
+        if save {
+            let _ = save_network_metric(state, &metric).await;
+        }
         network_metrics.push(metric);
     }
 
@@ -338,7 +341,9 @@ pub async fn get_live_metrics(
             measured_at: now,
         };
 
-        let _ = save_node_metric(&state, &node_metric).await;
+        if save {
+            let _ = save_node_metric(state, &node_metric).await;
+        }
         node_metrics.push(node_metric);
     }
 
@@ -412,7 +417,7 @@ pub async fn get_live_metrics(
         },
     };
 
-    Ok(Json(snapshot))
+    Ok(snapshot)
 }
 
 /// Get historical network metrics
@@ -593,15 +598,84 @@ pub async fn get_aggregated_metrics(
     }))
 }
 
+/// Pods of a topology, and their name and IP by node id
+async fn topology_pods(
+    client: &Client,
+    namespace: &str,
+    topology_id: &str,
+) -> AppResult<(kube::api::ObjectList<Pod>, HashMap<String, (String, String)>)> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+
+    let pod_list = pods
+        .list(&kube::api::ListParams::default().labels(&format!("networksim.io/topology={}", topology_id)))
+        .await
+        .map_err(|e| AppError::internal(&format!("Failed to list pods: {}", e)))?;
+
+    // Build pod info
+    let mut pod_info: HashMap<String, (String, String)> = HashMap::new(); // node_id -> (pod_name, pod_ip)
+    for pod in &pod_list.items {
+        let node_id = pod
+            .metadata
+            .labels
+            .as_ref()
+            .and_then(|l| l.get("networksim.io/node"))
+            .cloned()
+            .unwrap_or_default();
+
+        let pod_name = pod.metadata.name.clone().unwrap_or_default();
+        let pod_ip = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.pod_ip.clone())
+            .unwrap_or_default();
+
+        if !node_id.is_empty() && !pod_ip.is_empty() {
+            pod_info.insert(node_id, (pod_name, pod_ip));
+        }
+    }
+
+    Ok((pod_list, pod_info))
+}
+
+/// Measure connectivity and latency from one node of a topology to another
+///
+/// Returns whether the target is reachable, the latency and the packet loss.
+pub async fn measure_node_pair(
+    state: &AppState,
+    topology_id: &str,
+    from_node: &str,
+    to_node: &str,
+) -> AppResult<(bool, Option<f64>, Option<f64>)> {
+    let k8s = state
+        .k8s
+        .read()
+        .await
+        .clone()
+        .ok_or_else(|| AppError::internal("Kubernetes client not configured"))?;
+    let client: &Client = k8s.inner();
+
+    let namespace = state.config.k8s_namespace_sim.as_str();
+    let (_, pod_info) = topology_pods(client, namespace, topology_id).await?;
+    let (from_pod, _) = pod_info
+        .get(from_node)
+        .ok_or_else(|| AppError::not_found(&format!("No pod for node {}", from_node)))?;
+    let (_, to_ip) = pod_info
+        .get(to_node)
+        .ok_or_else(|| AppError::not_found(&format!("No pod for node {}", to_node)))?;
+
+    Ok(measure_connectivity(client, namespace, from_pod, to_ip).await)
+}
+
 /// Measure connectivity and latency between pods
 async fn measure_connectivity(
     client: &Client,
+    namespace: &str,
     from_pod: &str,
     to_ip: &str,
 ) -> (bool, Option<f64>, Option<f64>) {
     use kube::api::{Api, AttachParams};
 
-    let pods: Api<Pod> = Api::namespaced(client.clone(), "networksim-sim");
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
 
     let ap = AttachParams {
         stdin: true, // Required for some exec implementations even if not used
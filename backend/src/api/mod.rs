@@ -18,11 +18,12 @@ pub mod ws;
 pub mod volumes;
 pub mod scenarios;
 
+use crate::chaos::{backend_from_config, ChaosBackend, PodInjection};
 use crate::config::Config;
 use crate::db::Database;
 use crate::helm::HelmClient;
 use crate::k8s::K8sClient;
-#[allow(unused_imports)]
+use crate::scenarios::ScenarioRunManager;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 
@@ -35,17 +36,22 @@ pub struct AppState {
     pub event_tx: broadcast::Sender<Event>,
     pub k8s: Arc<RwLock<Option<K8sClient>>>,
     pub helm: Option<HelmClient>,
+    pub chaos: Arc<dyn ChaosBackend>,
+    pub runs: ScenarioRunManager,
 }
 
 impl AppState {
     pub fn new(db: Database, config: Config) -> Self {
         let (event_tx, _) = broadcast::channel(100);
+        let chaos = backend_from_config(&config);
         Self {
             db,
             config,
             event_tx,
             k8s: Arc::new(RwLock::new(None)),
             helm: None,
+            chaos,
+            runs: ScenarioRunManager::default(),
         }
     }
 
@@ -58,6 +64,11 @@ impl AppState {
         self.helm = Some(helm);
         self
     }
+
+    pub fn with_chaos_backend(mut self, chaos: Arc<dyn ChaosBackend>) -> Self {
+        self.chaos = chaos;
+        self
+    }
 }
 
 /// Events broadcasted via WebSocket
@@ -81,6 +92,51 @@ pub enum Event {
     ChaosRemoved { id: String },
     #[serde(rename = "chaos:updated")]
     ChaosUpdated { id: String },
+    #[serde(rename = "chaos:schedule_fired")]
+    ChaosScheduleFired { id: String, topology_id: String, chaos_name: String, fired_at: String },
+    #[serde(rename = "chaos:reconciled")]
+    ChaosReconciled { id: String, topology_id: String, action: String, message: String },
+    #[serde(rename = "chaos:expired")]
+    ChaosExpired { id: String, topology_id: String, status: String },
+    #[serde(rename = "chaos:aborted")]
+    ChaosAborted { topology_id: String, reason: String, source_id: String },
+    #[serde(rename = "chaos:emergency_stop")]
+    ChaosEmergencyStop { deleted: usize, failed: usize, paused: Vec<String> },
+    #[serde(rename = "chaos:injected")]
+    ChaosInjected { id: String, topology_id: String, kind: String, pods: Vec<PodInjection> },
+    #[serde(rename = "chaos:injection_failed")]
+    ChaosInjectionFailed {
+        id: String,
+        topology_id: String,
+        kind: String,
+        pods: Vec<PodInjection>,
+        message: String,
+    },
+    #[serde(rename = "chaos:recovered")]
+    ChaosRecovered { id: String, topology_id: String, kind: String, pods: Vec<PodInjection> },
+    #[serde(rename = "scenario:run_started")]
+    ScenarioRunStarted { run_id: String, scenario_id: String, topology_id: String },
+    #[serde(rename = "scenario:step_updated")]
+    ScenarioStepUpdated {
+        run_id: String,
+        step_id: String,
+        status: String,
+        condition_id: Option<String>,
+        error: Option<String>,
+    },
+    #[serde(rename = "scenario:run_paused")]
+    ScenarioRunPaused { run_id: String, position: f64, lift_faults: bool },
+    #[serde(rename = "scenario:run_resumed")]
+    ScenarioRunResumed { run_id: String, position: f64 },
+    #[serde(rename = "scenario:run_advanced")]
+    ScenarioRunAdvanced { run_id: String, position: f64 },
+    #[serde(rename = "scenario:run_finished")]
+    ScenarioRunFinished {
+        run_id: String,
+        scenario_id: String,
+        status: String,
+        error: Option<String>,
+    },
     #[serde(rename = "test:started")]
     TestStarted { id: String, test_type: String },
     #[serde(rename = "test:completed")]
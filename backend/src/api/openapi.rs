@@ -45,6 +45,12 @@ use utoipa::OpenApi;
         crate::api::chaos::stop,
         crate::api::chaos::delete,
         crate::api::chaos::delete_all,
+        crate::api::chaos::container_targets,
+        crate::api::chaos::preview,
+        crate::api::chaos::preview_condition,
+        crate::api::chaos::reconcile,
+        crate::api::chaos::emergency_stop,
+        crate::api::chaos::effective_faults,
         // Presets
         crate::api::presets::list_presets,
         crate::api::presets::get_preset,
@@ -81,6 +87,12 @@ use utoipa::OpenApi;
         crate::api::scenarios::update_scenario,
         crate::api::scenarios::delete_scenario,
         crate::api::scenarios::run_scenario,
+        crate::api::scenarios::list_runs,
+        crate::api::scenarios::get_run,
+        crate::api::scenarios::cancel_scenario_run,
+        crate::api::scenarios::pause_scenario_run,
+        crate::api::scenarios::resume_scenario_run,
+        crate::api::scenarios::advance_scenario_run,
     ),
     components(
         schemas(
@@ -114,16 +126,47 @@ use utoipa::OpenApi;
             crate::chaos::CreateChaosRequest,
             crate::chaos::ChaosType,
             crate::chaos::ChaosDirection,
+            crate::chaos::SelectionMode,
+            crate::chaos::PodSelection,
+            crate::chaos::ChaosRecurrence,
+            crate::chaos::ChaosCorrection,
+            crate::chaos::ChaosCorrectionAction,
+            crate::chaos::EmergencyStopReport,
+            crate::chaos::StoppedResource,
+            crate::chaos::StopOutcome,
+            crate::chaos::ChaosConflict,
+            crate::chaos::EffectiveFault,
+            crate::chaos::NodePairFaults,
+            crate::api::chaos::EffectiveFaultSet,
             crate::chaos::ChaosParams,
             crate::chaos::DelayParams,
             crate::chaos::LossParams,
             crate::chaos::BandwidthParams,
             crate::chaos::CorruptParams,
             crate::chaos::DuplicateParams,
+            crate::chaos::ReorderParams,
+            crate::chaos::FlakyLinkParams,
             crate::chaos::StressCpuParams,
+            crate::chaos::StressMemoryParams,
+            crate::chaos::CpuStressor,
+            crate::chaos::MemoryStressor,
             crate::chaos::PodKillParams,
+            crate::chaos::PodFailureParams,
+            crate::chaos::ContainerKillParams,
             crate::chaos::IoDelayParams,
+            crate::chaos::IoFaultParams,
+            crate::chaos::IoMistakeParams,
+            crate::chaos::IoAttrParams,
             crate::chaos::HttpAbortParams,
+            crate::chaos::HttpTarget,
+            crate::chaos::HttpDelayParams,
+            crate::chaos::HttpReplaceParams,
+            crate::chaos::HttpPatchParams,
+            crate::api::chaos::ChaosContainerTarget,
+            crate::api::chaos::ChaosPreviewResponse,
+            crate::api::chaos::AffectedApp,
+            crate::chaos::DnsChaosParams,
+            crate::chaos::TimeChaosParams,
             // Preset schemas
             ChaosPresetSchema,
             crate::api::presets::CreatePresetRequest,
@@ -152,6 +195,13 @@ use utoipa::OpenApi;
             crate::models::scenarios::ScenarioStep,
             crate::models::scenarios::CreateScenarioRequest,
             crate::models::scenarios::UpdateScenarioRequest,
+            crate::models::scenarios::ScenarioRun,
+            crate::models::scenarios::ScenarioRunStep,
+            crate::models::scenarios::StepAssertion,
+            crate::models::scenarios::ProbeSample,
+            crate::models::scenarios::AssertionResult,
+            crate::models::scenarios::ScenarioRunStatus,
+            crate::models::scenarios::RunStepStatus,
         )
     )
 )]
@@ -77,6 +77,8 @@ pub struct NodeSummary {
 pub struct ChaosSummary {
     pub total_conditions: usize,
     pub active_conditions: usize,
+    pub completed_conditions: usize,
+    pub failed_conditions: usize,
     pub conditions_by_type: Vec<ChaosTypeCount>,
     pub conditions: Vec<ChaosConditionSummary>,
 }
@@ -93,8 +95,11 @@ pub struct ChaosConditionSummary {
     pub chaos_type: String,
     pub source_node: String,
     pub target_node: Option<String>,
+    pub external_targets: Vec<String>,
     pub status: String,
     pub duration: Option<String>,
+    pub started_at: Option<String>,
+    pub ends_at: Option<String>,
     pub params: serde_json::Value,
 }
 
@@ -235,6 +240,8 @@ async fn build_report(
 
     // Build chaos summary
     let active_count = chaos_conditions.iter().filter(|c| c.status == crate::chaos::ChaosConditionStatus::Active).count();
+    let completed_count = chaos_conditions.iter().filter(|c| c.status == crate::chaos::ChaosConditionStatus::Completed).count();
+    let failed_count = chaos_conditions.iter().filter(|c| c.status == crate::chaos::ChaosConditionStatus::Failed).count();
 
     let mut type_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
     for condition in &chaos_conditions {
@@ -254,8 +261,11 @@ async fn build_report(
             chaos_type: c.chaos_type.to_string(),
             source_node: node_names.get(&c.source_node_id).cloned().unwrap_or_else(|| c.source_node_id.clone()),
             target_node: c.target_node_id.as_ref().map(|t| node_names.get(t).cloned().unwrap_or_else(|| t.clone())),
+            external_targets: c.external_targets.clone(),
             status: format!("{:?}", c.status).to_lowercase(),
             duration: c.duration.clone(),
+            started_at: c.started_at.map(|t| t.to_rfc3339()),
+            ends_at: c.ends_at.map(|t| t.to_rfc3339()),
             params: c.params.clone(),
         })
         .collect();
@@ -308,6 +318,8 @@ async fn build_report(
         chaos_summary: ChaosSummary {
             total_conditions: chaos_conditions.len(),
             active_conditions: active_count,
+            completed_conditions: completed_count,
+            failed_conditions: failed_count,
             conditions_by_type,
             conditions: chaos_summaries,
         },
@@ -523,6 +535,16 @@ fn render_nodes_html(nodes: &[NodeSummary]) -> String {
         .join("\n")
 }
 
+fn render_chaos_target(c: &ChaosConditionSummary) -> String {
+    let mut targets: Vec<String> = c.target_node.iter().cloned().collect();
+    targets.extend(c.external_targets.iter().map(|t| format!("{} (external)", t)));
+    if targets.is_empty() {
+        "-".to_string()
+    } else {
+        targets.join(", ")
+    }
+}
+
 fn render_chaos_table(conditions: &[ChaosConditionSummary]) -> String {
     if conditions.is_empty() {
         return "<div class=\"empty\">No chaos conditions configured</div>".to_string();
@@ -533,7 +555,7 @@ fn render_chaos_table(conditions: &[ChaosConditionSummary]) -> String {
             "<tr><td><span class=\"chaos-type\">{}</span></td><td>{}</td><td>{}</td><td><span class=\"status status-{}\">{}</span></td><td>{}</td></tr>",
             c.chaos_type,
             c.source_node,
-            c.target_node.as_ref().unwrap_or(&"-".to_string()),
+            render_chaos_target(c),
             c.status,
             c.status,
             c.duration.as_ref().unwrap_or(&"indefinite".to_string())
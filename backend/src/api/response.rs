@@ -188,14 +188,23 @@ impl<T: Serialize> IntoApiResponse<T> for Result<T, crate::error::AppError> {
         match self {
             Ok(data) => ApiResponse::success(data),
             Err(e) => {
-                let (code, message): (&str, String) = match &e {
-                    crate::error::AppError::NotFound(msg) => ("NOT_FOUND", msg.clone()),
-                    crate::error::AppError::BadRequest(msg) => ("BAD_REQUEST", msg.clone()),
-                    crate::error::AppError::Conflict(msg) => ("CONFLICT", msg.clone()),
-                    crate::error::AppError::Internal(msg) => ("INTERNAL_ERROR", msg.clone()),
-                    crate::error::AppError::Database(err) => ("DATABASE_ERROR", err.to_string()),
-                    crate::error::AppError::Kubernetes(err) => ("KUBERNETES_ERROR", err.to_string()),
-                    crate::error::AppError::Serialization(err) => ("JSON_ERROR", err.to_string()),
+                let (code, message, details) = match e {
+                    crate::error::AppError::NotFound(msg) => ("NOT_FOUND", msg, None),
+                    crate::error::AppError::BadRequest(msg) => ("BAD_REQUEST", msg, None),
+                    crate::error::AppError::Conflict(msg) => ("CONFLICT", msg, None),
+                    crate::error::AppError::Internal(msg) => ("INTERNAL_ERROR", msg, None),
+                    crate::error::AppError::Validation { message, details } => {
+                        ("VALIDATION_ERROR", message, Some(details))
+                    }
+                    crate::error::AppError::Database(err) => {
+                        ("DATABASE_ERROR", err.to_string(), None)
+                    }
+                    crate::error::AppError::Kubernetes(err) => {
+                        ("KUBERNETES_ERROR", err.to_string(), None)
+                    }
+                    crate::error::AppError::Serialization(err) => {
+                        ("JSON_ERROR", err.to_string(), None)
+                    }
                 };
                 ApiResponse {
                     success: false,
@@ -203,7 +212,7 @@ impl<T: Serialize> IntoApiResponse<T> for Result<T, crate::error::AppError> {
                     error: Some(ApiError {
                         code: code.to_string(),
                         message,
-                        details: None,
+                        details,
                     }),
                     meta: None,
                 }
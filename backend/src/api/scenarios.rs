@@ -1,14 +1,21 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json, Router, routing::{get, post},
 };
 // use tracing::{info, error};
 use crate::{
-    api::{AppState, response::ApiResponse},
-    models::scenarios::{Scenario, CreateScenarioRequest, UpdateScenarioRequest},
+    api::{chaos::{build_condition, StartChaosQuery}, AppState, response::ApiResponse},
+    models::scenarios::{
+        Scenario, ScenarioRun, ScenarioStep, CreateScenarioRequest, UpdateScenarioRequest,
+    },
     error::AppError,
-    chaos::{ChaosDirection, ChaosCondition, ChaosConditionStatus},
+    chaos::{find_conflicts, validate_abort_criteria, ChaosCondition, ParamError},
+    scenarios::{
+        advance_run, cancel_run, pause_run, resume_run, start_run, step_condition, step_request,
+        validate_assertion,
+    },
 };
+use serde::Deserialize;
 use chrono::Utc;
 use uuid::Uuid;
 
@@ -17,19 +24,39 @@ pub fn router() -> Router<AppState> {
         .route("/api/topologies/:topology_id/scenarios", get(list_scenarios).post(create_scenario))
         .route("/api/scenarios/:id", get(get_scenario).put(update_scenario).delete(delete_scenario))
         .route("/api/scenarios/:id/run", post(run_scenario))
+        .route("/api/scenarios/:id/runs", get(list_runs))
+        .route("/api/scenario-runs/:run_id", get(get_run))
+        .route("/api/scenario-runs/:run_id/cancel", post(cancel_scenario_run))
+        .route("/api/scenario-runs/:run_id/pause", post(pause_scenario_run))
+        .route("/api/scenario-runs/:run_id/resume", post(resume_scenario_run))
+        .route("/api/scenario-runs/:run_id/advance", post(advance_scenario_run))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PauseRunQuery {
+    /// Remove the active faults while paused instead of holding them
+    #[serde(default)]
+    pub lift_faults: bool,
 }
 
 #[utoipa::path(
     post,
     path = "/api/scenarios/{id}/run",
     tag = "scenarios",
-    params(("id" = String, Path, description = "Scenario ID")),
-    responses((status = 200, description = "Scenario execution started"))
+    params(
+        ("id" = String, Path, description = "Scenario ID"),
+        ("force" = Option<bool>, Query, description = "Run despite conflicting steps or active conditions"),
+    ),
+    responses(
+        (status = 200, description = "Scenario run started", body = ScenarioRun),
+        (status = 409, description = "Steps overlapping in time conflict with each other")
+    )
 )]
 async fn run_scenario(
     State(state): State<AppState>,
     Path(id): Path<String>,
-) -> Result<ApiResponse<()>, AppError> {
+    Query(query): Query<StartChaosQuery>,
+) -> Result<ApiResponse<ScenarioRun>, AppError> {
     let scenario = sqlx::query_as::<_, Scenario>(
         "SELECT * FROM scenarios WHERE id = ?",
     )
@@ -38,112 +65,208 @@ async fn run_scenario(
     .await?
     .ok_or_else(|| AppError::NotFound("Scenario not found".to_string()))?;
 
-    // Spawn execution task
-    tokio::spawn(async move {
-        if let Err(e) = execute_scenario_logic(state, scenario).await {
-            tracing::error!("Scenario execution failed: {}", e);
+    let conflicts = step_conflicts(&scenario.topology_id, &scenario.steps.0);
+    if !conflicts.is_empty() && !query.force {
+        return Err(AppError::Conflict(format!(
+            "Scenario steps conflict: {}",
+            conflicts.join("; ")
+        )));
+    }
+
+    let run = start_run(&state, &scenario, query.force).await?;
+
+    Ok(ApiResponse::success(run))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scenarios/{id}/runs",
+    tag = "scenarios",
+    params(("id" = String, Path, description = "Scenario ID")),
+    responses((status = 200, description = "Runs of the scenario, most recent first", body = Vec<ScenarioRun>))
+)]
+async fn list_runs(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<ApiResponse<Vec<ScenarioRun>>, AppError> {
+    Ok(ApiResponse::success(state.db.list_scenario_runs(&id).await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scenario-runs/{run_id}",
+    tag = "scenarios",
+    params(("run_id" = String, Path, description = "Scenario run ID")),
+    responses(
+        (status = 200, description = "Scenario run with the state of each step", body = ScenarioRun),
+        (status = 404, description = "Scenario run not found")
+    )
+)]
+async fn get_run(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+) -> Result<ApiResponse<ScenarioRun>, AppError> {
+    let run = state
+        .db
+        .get_scenario_run(&run_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Scenario run not found".to_string()))?;
+
+    Ok(ApiResponse::success(run))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scenario-runs/{run_id}/cancel",
+    tag = "scenarios",
+    params(("run_id" = String, Path, description = "Scenario run ID")),
+    responses(
+        (status = 200, description = "Run cancelled, the chaos of its steps removed", body = ScenarioRun),
+        (status = 404, description = "Scenario run not found"),
+        (status = 409, description = "Scenario run already finished")
+    )
+)]
+async fn cancel_scenario_run(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+) -> Result<ApiResponse<ScenarioRun>, AppError> {
+    Ok(ApiResponse::success(cancel_run(&state, &run_id).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scenario-runs/{run_id}/pause",
+    tag = "scenarios",
+    params(
+        ("run_id" = String, Path, description = "Scenario run ID"),
+        ("lift_faults" = Option<bool>, Query, description = "Remove the active faults until resumed instead of holding them"),
+    ),
+    responses(
+        (status = 200, description = "Run paused, its timeline frozen", body = ScenarioRun),
+        (status = 404, description = "Scenario run not found"),
+        (status = 409, description = "Scenario run already paused, finished or not executing in this server")
+    )
+)]
+async fn pause_scenario_run(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+    Query(query): Query<PauseRunQuery>,
+) -> Result<ApiResponse<ScenarioRun>, AppError> {
+    Ok(ApiResponse::success(pause_run(&state, &run_id, query.lift_faults).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scenario-runs/{run_id}/resume",
+    tag = "scenarios",
+    params(("run_id" = String, Path, description = "Scenario run ID")),
+    responses(
+        (status = 200, description = "Run resumed, lifted faults injected again", body = ScenarioRun),
+        (status = 404, description = "Scenario run not found"),
+        (status = 409, description = "Scenario run not paused, finished or not executing in this server")
+    )
+)]
+async fn resume_scenario_run(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+) -> Result<ApiResponse<ScenarioRun>, AppError> {
+    Ok(ApiResponse::success(resume_run(&state, &run_id).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scenario-runs/{run_id}/advance",
+    tag = "scenarios",
+    params(("run_id" = String, Path, description = "Scenario run ID")),
+    responses(
+        (status = 200, description = "Timeline moved to the next step start or end", body = ScenarioRun),
+        (status = 404, description = "Scenario run not found"),
+        (status = 409, description = "Scenario run finished or not executing in this server")
+    )
+)]
+async fn advance_scenario_run(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+) -> Result<ApiResponse<ScenarioRun>, AppError> {
+    Ok(ApiResponse::success(advance_run(&state, &run_id).await?))
+}
+
+/// Conflicts between chaos steps that are active at the same time, named by step id
+fn step_conflicts(topology_id: &str, steps: &[ScenarioStep]) -> Vec<String> {
+    let chaos_steps: Vec<(&ScenarioStep, ChaosCondition)> = steps
+        .iter()
+        .filter_map(|step| Some((step, step_condition(topology_id, step, &step.id)?)))
+        .collect();
+
+    let mut conflicts = Vec::new();
+    for (i, (a, condition_a)) in chaos_steps.iter().enumerate() {
+        for (b, condition_b) in chaos_steps.iter().skip(i + 1) {
+            let overlapping =
+                a.start_at < b.start_at + b.duration && b.start_at < a.start_at + a.duration;
+            if !overlapping {
+                continue;
+            }
+            for conflict in find_conflicts(condition_a, std::slice::from_ref(condition_b)) {
+                conflicts.push(format!("{} and {}: {}", a.id, b.id, conflict.reason));
+            }
         }
-    });
+    }
+    conflicts
+}
 
-    Ok(ApiResponse::success(()))
+fn invalid_abort_criteria(errors: Vec<ParamError>) -> AppError {
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    AppError::validation(
+        &format!("Invalid abort criteria: {}", messages.join("; ")),
+        serde_json::json!(errors),
+    )
 }
 
-async fn execute_scenario_logic(state: AppState, scenario: Scenario) -> Result<(), AppError> {
-    tracing::info!("Starting scenario: {}", scenario.name);
-    
-    // Launch all steps
-    for step in scenario.steps.0 {
-        let state_clone = state.clone();
-        let topology_id = scenario.topology_id.clone();
-        
-        tokio::spawn(async move {
-            // Wait for step start time
-            let delay_ms = (step.start_at * 1000.0) as u64;
-            tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
-
-
-            let condition_id = Uuid::new_v4().to_string();
-            let now = Utc::now();
-            
-            let condition = ChaosCondition {
-                id: condition_id.clone(),
-                topology_id: topology_id.clone(),
-                source_node_id: step.source_node_id.clone(),
-                target_node_id: step.target_node_id.clone(),
-                chaos_type: step.chaos_type.clone(),
-                direction: ChaosDirection::Both,
-                duration: Some(format!("{}s", step.duration)),
-                params: step.params.clone(),
-                status: ChaosConditionStatus::Pending,
-                k8s_name: None,
-                started_at: None,
-                created_at: now,
-                updated_at: now,
-            };
-
-            // 1. Persist condition in DB
-            match state_clone.db.create_chaos_condition(&condition).await {
-                Ok(_) => {},
-                Err(e) => {
-                    tracing::error!("Failed to create chaos condition for step: {}", e);
-                    return;
+/// Validate every step: chaos steps as `POST /api/chaos` validates a condition, assertion
+/// steps against what their assertion needs
+async fn validate_steps(
+    state: &AppState,
+    topology_id: &str,
+    steps: &[ScenarioStep],
+) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+    for (i, step) in steps.iter().enumerate() {
+        let step_errors = match (&step.assertion, step_request(topology_id, step)) {
+            (Some(assertion), _) => validate_assertion(step, assertion),
+            (None, Some(request)) => match build_condition(state, request).await {
+                Ok(_) => Vec::new(),
+                Err(AppError::Validation { details, .. }) => {
+                    serde_json::from_value(details).unwrap_or_default()
                 }
-            };
-
-            tracing::info!("Executing Scenario Step: {} on {}", step.chaos_type, step.source_node_id);
-
-            // 2. Apply to Kubernetes using ChaosClient
-            // We use the "networksim-sim" namespace or from config if available (hardcoded for now to match other modules)
-            match crate::chaos::ChaosClient::new("networksim-sim").await {
-               Ok(client) => {
-                   let direction = if condition.target_node_id.is_some() {
-                        ChaosDirection::To
-                   } else {
-                        ChaosDirection::To // Default to egress only for "all" 
-                   };
-
-                   let res = client.create_chaos(
-                       &condition.topology_id,
-                       &condition.id,
-                       &condition.source_node_id,
-                       condition.target_node_id.as_deref(),
-                       &condition.chaos_type,
-                       &direction,
-                       condition.duration.as_deref(),
-                       &condition.params
-                   ).await;
-
-                   if let Err(e) = res {
-                        tracing::error!("Failed to apply chaos to K8s: {}", e);
-                        // Try to mark as failed in DB
-                        let _ = state_clone.db.update_chaos_condition_status(&condition.id, &ChaosConditionStatus::Paused, None).await;
-                        return;
-                   }
-                   
-                   // Mark as Active
-                   let _ = state_clone.db.update_chaos_condition_status(&condition.id, &ChaosConditionStatus::Active, None).await;
-
-                   // 3. Wait for duration
-                   let duration_ms = (step.duration * 1000.0) as u64;
-                   tokio::time::sleep(tokio::time::Duration::from_millis(duration_ms)).await;
-
-                   // 4. Cleanup (Stop chaos)
-                   // Even if duration is passed to Chaos Mesh, we explicitly delete it to clean up CRDs
-                   if let Err(e) = client.delete_chaos(&condition.topology_id, &condition.id).await {
-                        tracing::error!("Failed to cleanup chaos: {}", e);
-                   }
-
-                   // Mark as finished/paused
-                   let _ = state_clone.db.update_chaos_condition_status(&condition.id, &ChaosConditionStatus::Paused, None).await;
-               },
-               Err(e) => {
-                   tracing::error!("Failed to create K8s client: {}", e);
-               }
-            }
-        });
+                Err(AppError::NotFound(message) | AppError::BadRequest(message)) => {
+                    vec![ParamError { field: String::new(), message }]
+                }
+                Err(e) => return Err(e),
+            },
+            (None, None) => vec![ParamError {
+                field: "type".to_string(),
+                message: "either a chaos type or an assertion is required".to_string(),
+            }],
+        };
+        errors.extend(step_errors.into_iter().map(|e| ParamError {
+            field: if e.field.is_empty() {
+                format!("steps[{}]", i)
+            } else {
+                format!("steps[{}].{}", i, e.field)
+            },
+            message: e.message,
+        }));
+    }
+
+    if errors.is_empty() {
+        return Ok(());
     }
 
-    Ok(())
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    Err(AppError::validation(
+        &format!("Invalid step params: {}", messages.join("; ")),
+        serde_json::json!(errors),
+    ))
 }
 
 #[utoipa::path(
@@ -228,6 +351,9 @@ async fn create_scenario(
             return Err(AppError::bad_request(&format!("steps[{}].start_at must be >= 0", i)));
         }
     }
+    if let Some(criteria) = &payload.abort_criteria {
+        validate_abort_criteria(criteria).map_err(invalid_abort_criteria)?;
+    }
 
     // Ensure topology exists
     let topo_exists: Option<(i64,)> = sqlx::query_as("SELECT 1 FROM topologies WHERE id = ? LIMIT 1")
@@ -238,6 +364,7 @@ async fn create_scenario(
     if topo_exists.is_none() {
         return Err(AppError::NotFound("Topology not found".to_string()));
     }
+    validate_steps(&state, &topology_id, &payload.steps).await?;
     let id = Uuid::new_v4().to_string();
     let now = Utc::now().to_rfc3339();
     
@@ -248,14 +375,16 @@ async fn create_scenario(
         description: payload.description,
         total_duration: payload.total_duration,
         steps: sqlx::types::Json(payload.steps),
+        abort_criteria: payload.abort_criteria.map(sqlx::types::Json),
+        restart_policy: payload.restart_policy,
         created_at: now.clone(),
         updated_at: now.clone(),
     };
 
     sqlx::query(
         r#"
-        INSERT INTO scenarios (id, topology_id, name, description, total_duration, steps, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO scenarios (id, topology_id, name, description, total_duration, steps, abort_criteria, restart_policy, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(&scenario.id)
@@ -264,6 +393,8 @@ async fn create_scenario(
     .bind(&scenario.description)
     .bind(scenario.total_duration)
     .bind(&scenario.steps)
+    .bind(&scenario.abort_criteria)
+    .bind(scenario.restart_policy)
     .bind(&scenario.created_at)
     .bind(&scenario.updated_at)
     .execute(state.db.pool())
@@ -304,14 +435,24 @@ async fn update_scenario(
         scenario.total_duration = dur;
     }
     if let Some(steps) = payload.steps {
+        validate_steps(&state, &scenario.topology_id, &steps).await?;
         scenario.steps = sqlx::types::Json(steps);
     }
+    if let Some(criteria) = payload.abort_criteria {
+        if let Some(criteria) = &criteria {
+            validate_abort_criteria(criteria).map_err(invalid_abort_criteria)?;
+        }
+        scenario.abort_criteria = criteria.map(sqlx::types::Json);
+    }
+    if let Some(policy) = payload.restart_policy {
+        scenario.restart_policy = policy;
+    }
     scenario.updated_at = now.clone();
 
     sqlx::query(
         r#"
         UPDATE scenarios 
-        SET name = ?, description = ?, total_duration = ?, steps = ?, updated_at = ?
+        SET name = ?, description = ?, total_duration = ?, steps = ?, abort_criteria = ?, restart_policy = ?, updated_at = ?
         WHERE id = ?
         "#,
     )
@@ -319,6 +460,8 @@ async fn update_scenario(
     .bind(&scenario.description)
     .bind(scenario.total_duration)
     .bind(&scenario.steps)
+    .bind(&scenario.abort_criteria)
+    .bind(scenario.restart_policy)
     .bind(&scenario.updated_at)
     .bind(&id)
     .execute(state.db.pool())
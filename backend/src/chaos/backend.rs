@@ -0,0 +1,428 @@
+//! Chaos backends
+//!
+//! A `ChaosBackend` applies chaos conditions to the simulation. The API, scenarios and
+//! presets only talk to the backend selected in `AppState`:
+//! - `ChaosMeshBackend`: Chaos Mesh CRDs in the simulation namespace (default)
+//! - `TcBackend`: `tc`/netem run in a sidecar of the node pods, for clusters without
+//!   Chaos Mesh (network conditions only)
+//! - `InMemoryChaosBackend`: keeps the rendered manifests in memory, for tests and
+//!   demos without a cluster
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+use super::client::ChaosClient;
+use super::conditions::{chaos_resource_name, create_condition_manifest, create_schedule_manifest};
+use super::tc::TcBackend;
+use super::types::*;
+use crate::config::Config;
+use crate::error::{AppError, AppResult};
+
+/// `Config::chaos_backend` value selecting the native tc/netem backend
+pub const TC_BACKEND: &str = "tc";
+
+/// Applies and removes chaos conditions
+#[async_trait]
+pub trait ChaosBackend: Send + Sync {
+    /// Reject conditions this backend cannot apply, before they are saved
+    fn check_supported(&self, _condition: &ChaosCondition) -> AppResult<()> {
+        Ok(())
+    }
+
+    /// Apply a condition, returning the name of the created resource
+    async fn create(&self, condition: &ChaosCondition) -> AppResult<String>;
+
+    /// Remove the resource of a condition (succeeds if it does not exist)
+    async fn delete(&self, topology_id: &str, condition_id: &str) -> AppResult<()>;
+
+    /// Pause or resume the `Schedule` of a recurring condition, keeping its history
+    ///
+    /// Returns whether a Schedule existed; backends without schedules never have one.
+    async fn pause_schedule(
+        &self,
+        _topology_id: &str,
+        _condition_id: &str,
+        _paused: bool,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    /// Resources currently applied for a topology
+    async fn list(&self, topology_id: &str) -> AppResult<Vec<ChaosStatus>>;
+
+    /// Every resource applied by NetworkSim, whatever its topology
+    async fn list_managed(&self) -> AppResult<Vec<ChaosStatus>>;
+
+    /// Status of the resource of a condition, `None` if it is not applied
+    async fn status(&self, topology_id: &str, condition_id: &str) -> AppResult<Option<ChaosStatus>> {
+        Ok(self
+            .list(topology_id)
+            .await?
+            .into_iter()
+            .find(|s| s.condition_id == condition_id))
+    }
+
+    /// Remove every resource of a topology
+    async fn cleanup_topology(&self, topology_id: &str) -> AppResult<()> {
+        for status in self.list(topology_id).await? {
+            self.delete(topology_id, &status.condition_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Remove every resource applied by NetworkSim, reporting each one
+    ///
+    /// Keeps going when a removal fails, so it can be called again until nothing is left.
+    async fn emergency_stop(&self) -> AppResult<Vec<StoppedResource>> {
+        let mut stopped = Vec::new();
+        for status in self.list_managed().await? {
+            let error = self
+                .delete(&status.topology_id, &status.condition_id)
+                .await
+                .err()
+                .map(|e| e.to_string());
+            stopped.push(StoppedResource {
+                kind: format!("{:?}", status.chaos_type.crd_kind()),
+                namespace: None,
+                name: status.name,
+                topology_id: Some(status.topology_id),
+                condition_id: Some(status.condition_id),
+                outcome: match error {
+                    None => StopOutcome::Deleted,
+                    Some(_) => StopOutcome::Failed,
+                },
+                error,
+                known: false,
+            });
+        }
+        Ok(stopped)
+    }
+}
+
+/// Build the backend selected by `Config::chaos_backend`
+pub fn backend_from_config(config: &Config) -> Arc<dyn ChaosBackend> {
+    match config.chaos_backend.as_str() {
+        "memory" => Arc::new(InMemoryChaosBackend::new(&config.k8s_namespace_sim)),
+        TC_BACKEND => Arc::new(TcBackend::new(&config.k8s_namespace_sim)),
+        "chaos-mesh" => Arc::new(ChaosMeshBackend::new(&config.k8s_namespace_sim)),
+        other => {
+            // `Config::load` rejects these; only reachable with a hand-built config
+            warn!("Unknown chaos backend '{}', using chaos-mesh", other);
+            Arc::new(ChaosMeshBackend::new(&config.k8s_namespace_sim))
+        }
+    }
+}
+
+/// Chaos Mesh backend
+///
+/// Connects to the cluster on every call, so the server starts (and fails fast per
+/// request) when no cluster is reachable.
+#[derive(Clone)]
+pub struct ChaosMeshBackend {
+    namespace: String,
+}
+
+impl ChaosMeshBackend {
+    pub fn new(namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+        }
+    }
+
+    async fn client(&self) -> AppResult<ChaosClient> {
+        ChaosClient::new(&self.namespace).await
+    }
+}
+
+#[async_trait]
+impl ChaosBackend for ChaosMeshBackend {
+    async fn create(&self, condition: &ChaosCondition) -> AppResult<String> {
+        self.client()
+            .await?
+            .create_chaos(
+                &condition.topology_id,
+                &condition.id,
+                &condition.source_node_id,
+                condition.target_node_id.as_deref(),
+                &condition.external_targets,
+                &condition.selection,
+                &condition.recurrence,
+                &condition.chaos_type,
+                &condition.direction,
+                condition.duration.as_deref(),
+                &condition.params,
+            )
+            .await
+    }
+
+    async fn delete(&self, topology_id: &str, condition_id: &str) -> AppResult<()> {
+        self.client().await?.delete_chaos(topology_id, condition_id).await
+    }
+
+    async fn pause_schedule(
+        &self,
+        topology_id: &str,
+        condition_id: &str,
+        paused: bool,
+    ) -> AppResult<bool> {
+        self.client()
+            .await?
+            .pause_schedule(topology_id, condition_id, paused)
+            .await
+    }
+
+    async fn list(&self, topology_id: &str) -> AppResult<Vec<ChaosStatus>> {
+        self.client().await?.list_chaos(topology_id).await
+    }
+
+    async fn list_managed(&self) -> AppResult<Vec<ChaosStatus>> {
+        self.client().await?.list_managed_chaos().await
+    }
+
+    async fn cleanup_topology(&self, topology_id: &str) -> AppResult<()> {
+        // Also removes the Schedules of recurring conditions
+        self.client().await?.cleanup_topology(topology_id).await
+    }
+
+    async fn emergency_stop(&self) -> AppResult<Vec<StoppedResource>> {
+        // Whatever the namespace, as topologies may have been deployed with another config
+        self.client().await?.delete_all_managed().await
+    }
+}
+
+/// A resource held by the in-memory backend
+#[derive(Debug, Clone)]
+struct AppliedChaos {
+    topology_id: String,
+    manifest: Value,
+    status: ChaosStatus,
+}
+
+/// In-memory backend: records the manifests Chaos Mesh would receive, injects nothing
+#[derive(Clone)]
+pub struct InMemoryChaosBackend {
+    namespace: String,
+    resources: Arc<Mutex<HashMap<String, AppliedChaos>>>,
+}
+
+impl InMemoryChaosBackend {
+    pub fn new(namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            resources: Arc::default(),
+        }
+    }
+
+    /// Manifest applied for a condition (a `Schedule` for recurring conditions)
+    pub fn manifest(&self, topology_id: &str, condition_id: &str) -> Option<Value> {
+        let name = chaos_resource_name(topology_id, condition_id);
+        self.resources
+            .lock()
+            .unwrap()
+            .get(&name)
+            .map(|r| r.manifest.clone())
+    }
+
+    /// Number of resources currently applied, across all topologies
+    pub fn len(&self) -> usize {
+        self.resources.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Set the phase of an applied resource, e.g. `Finished` to simulate an elapsed duration
+    pub fn set_phase(&self, topology_id: &str, condition_id: &str, phase: &str) {
+        let name = chaos_resource_name(topology_id, condition_id);
+        if let Some(resource) = self.resources.lock().unwrap().get_mut(&name) {
+            resource.status.phase = phase.to_string();
+        }
+    }
+}
+
+/// `networksim.io/node` labels of the selectors of a manifest
+fn selector_nodes(spec: &Value) -> Vec<String> {
+    [spec.pointer("/selector"), spec.pointer("/target/selector")]
+        .into_iter()
+        .flatten()
+        .filter_map(|s| s.pointer("/labelSelectors/networksim.io~1node"))
+        .filter_map(|n| n.as_str().map(String::from))
+        .collect()
+}
+
+#[async_trait]
+impl ChaosBackend for InMemoryChaosBackend {
+    async fn create(&self, condition: &ChaosCondition) -> AppResult<String> {
+        let name = chaos_resource_name(&condition.topology_id, &condition.id);
+        let crd_kind = condition.chaos_type.crd_kind();
+
+        let chaos_manifest = create_condition_manifest(condition, &self.namespace)?;
+        let target_pods = selector_nodes(&chaos_manifest["spec"]);
+        let manifest = if condition.recurrence.schedule.is_some() {
+            create_schedule_manifest(&chaos_manifest, &crd_kind, &condition.recurrence)
+        } else {
+            chaos_manifest
+        };
+
+        let mut resources = self.resources.lock().unwrap();
+        if resources.contains_key(&name) {
+            return Err(AppError::internal(&format!(
+                "Failed to create chaos: {} already exists",
+                name
+            )));
+        }
+
+        info!("Created in-memory {:?}: {}", crd_kind, name);
+        resources.insert(
+            name.clone(),
+            AppliedChaos {
+                topology_id: condition.topology_id.clone(),
+                manifest,
+                status: ChaosStatus {
+                    name: name.clone(),
+                    topology_id: condition.topology_id.clone(),
+                    condition_id: condition.id.clone(),
+                    chaos_type: condition.chaos_type.clone(),
+                    phase: "Running".to_string(),
+                    injected_pods: target_pods.clone(),
+                    matched_pods: target_pods.len(),
+                    target_pods,
+                    selection_mode: condition.selection.selection_mode.clone(),
+                    message: None,
+                },
+            },
+        );
+
+        Ok(name)
+    }
+
+    async fn delete(&self, topology_id: &str, condition_id: &str) -> AppResult<()> {
+        let name = chaos_resource_name(topology_id, condition_id);
+        if self.resources.lock().unwrap().remove(&name).is_some() {
+            info!("Deleted in-memory chaos: {}", name);
+        }
+        Ok(())
+    }
+
+    async fn pause_schedule(
+        &self,
+        topology_id: &str,
+        condition_id: &str,
+        paused: bool,
+    ) -> AppResult<bool> {
+        let name = chaos_resource_name(topology_id, condition_id);
+        let mut resources = self.resources.lock().unwrap();
+        let Some(resource) = resources
+            .get_mut(&name)
+            .filter(|r| r.manifest["kind"] == "Schedule")
+        else {
+            return Ok(false);
+        };
+
+        // Same annotation Chaos Mesh reads, a null value removes it
+        resource.manifest["metadata"]["annotations"]["experiment.chaos-mesh.org/pause"] =
+            json!(paused.then_some("true"));
+        resource.status.phase = if paused { "Paused" } else { "Running" }.to_string();
+        info!("{} in-memory Schedule: {}", if paused { "Paused" } else { "Resumed" }, name);
+        Ok(true)
+    }
+
+    async fn list(&self, topology_id: &str) -> AppResult<Vec<ChaosStatus>> {
+        let mut statuses: Vec<ChaosStatus> = self
+            .resources
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|r| r.topology_id == topology_id)
+            .map(|r| r.status.clone())
+            .collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(statuses)
+    }
+
+    async fn list_managed(&self) -> AppResult<Vec<ChaosStatus>> {
+        let mut statuses: Vec<ChaosStatus> = self
+            .resources
+            .lock()
+            .unwrap()
+            .values()
+            .map(|r| r.status.clone())
+            .collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(statuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn condition(id: &str, schedule: Option<&str>) -> ChaosCondition {
+        let now = Utc::now();
+        ChaosCondition {
+            id: id.to_string(),
+            topology_id: "topology-1234".to_string(),
+            source_node_id: "node-1".to_string(),
+            target_node_id: Some("node-2".to_string()),
+            external_targets: Vec::new(),
+            selection: PodSelection::default(),
+            recurrence: ChaosRecurrence {
+                schedule: schedule.map(String::from),
+                history_limit: None,
+            },
+            chaos_type: ChaosType::Delay,
+            direction: ChaosDirection::To,
+            duration: Some("30s".to_string()),
+            params: json!({"latency": "100ms"}),
+            status: ChaosConditionStatus::Pending,
+            k8s_name: None,
+            started_at: None,
+            ends_at: None,
+            abort_criteria: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_backend_lifecycle() {
+        let backend = InMemoryChaosBackend::new("networksim-sim");
+
+        let name = backend.create(&condition("c1", None)).await.unwrap();
+        assert_eq!(name, "ns-topology-c1");
+        assert!(backend.create(&condition("c1", None)).await.is_err());
+
+        let status = backend.status("topology-1234", "c1").await.unwrap().unwrap();
+        assert_eq!(status.phase, "Running");
+        assert_eq!(status.target_pods, vec!["node-1", "node-2"]);
+
+        let manifest = backend.manifest("topology-1234", "c1").unwrap();
+        assert_eq!(manifest["kind"], "NetworkChaos");
+        assert_eq!(manifest["metadata"]["namespace"], "networksim-sim");
+
+        backend.delete("topology-1234", "c1").await.unwrap();
+        assert!(backend.status("topology-1234", "c1").await.unwrap().is_none());
+        // Deleting a missing resource is not an error
+        backend.delete("topology-1234", "c1").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_backend_schedule_and_cleanup() {
+        let backend = InMemoryChaosBackend::new("networksim-sim");
+
+        backend.create(&condition("c1", Some("@every 2m"))).await.unwrap();
+        backend.create(&condition("c2", None)).await.unwrap();
+        assert_eq!(backend.manifest("topology-1234", "c1").unwrap()["kind"], "Schedule");
+        assert_eq!(backend.list("topology-1234").await.unwrap().len(), 2);
+        assert!(backend.list("other").await.unwrap().is_empty());
+
+        backend.cleanup_topology("topology-1234").await.unwrap();
+        assert!(backend.is_empty());
+    }
+}
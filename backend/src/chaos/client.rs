@@ -1,16 +1,18 @@
 //! Chaos Mesh Kubernetes client
 //!
-//! Handles creating, listing, and deleting Chaos Mesh CRDs (NetworkChaos, StressChaos, PodChaos, IOChaos, HTTPChaos)
+//! Handles creating, listing, and deleting Chaos Mesh CRDs (NetworkChaos, StressChaos, PodChaos, IOChaos, HTTPChaos, DNSChaos, TimeChaos)
+//! and the `Schedule` objects wrapping recurring conditions
 
 use kube::{
-    api::{Api, DeleteParams, DynamicObject, ListParams, PostParams},
+    api::{Api, DeleteParams, DynamicObject, ListParams, Patch, PatchParams, PostParams},
     discovery::ApiResource,
     Client,
 };
+use k8s_openapi::api::core::v1::Pod;
 use serde_json::Value;
 use tracing::{error, info, warn};
 
-use super::conditions::create_chaos_manifest;
+use super::conditions::{chaos_resource_name, create_chaos_manifest, create_schedule_manifest};
 use super::types::*;
 use crate::error::{AppError, AppResult};
 
@@ -35,13 +37,15 @@ impl ChaosClient {
     }
 
     /// Get the ApiResource for a specific CRD kind
-    fn api_resource_for_kind(kind: &ChaosCrdKind) -> ApiResource {
+    pub fn api_resource_for_kind(kind: &ChaosCrdKind) -> ApiResource {
         let (k, p) = match kind {
             ChaosCrdKind::NetworkChaos => ("NetworkChaos", "networkchaos"),
             ChaosCrdKind::StressChaos => ("StressChaos", "stresschaos"),
             ChaosCrdKind::PodChaos => ("PodChaos", "podchaos"),
             ChaosCrdKind::IOChaos => ("IOChaos", "iochaos"),
             ChaosCrdKind::HTTPChaos => ("HTTPChaos", "httpchaos"),
+            ChaosCrdKind::DNSChaos => ("DNSChaos", "dnschaos"),
+            ChaosCrdKind::TimeChaos => ("TimeChaos", "timechaos"),
         };
         ApiResource {
             group: "chaos-mesh.org".to_string(),
@@ -52,6 +56,25 @@ impl ChaosClient {
         }
     }
 
+    /// Get the ApiResource for Chaos Mesh `Schedule` objects
+    fn schedule_api_resource() -> ApiResource {
+        ApiResource {
+            group: "chaos-mesh.org".to_string(),
+            version: "v1alpha1".to_string(),
+            api_version: "chaos-mesh.org/v1alpha1".to_string(),
+            kind: "Schedule".to_string(),
+            plural: "schedules".to_string(),
+        }
+    }
+
+    fn schedule_api(&self) -> Api<DynamicObject> {
+        Api::namespaced_with(
+            self.client.clone(),
+            &self.namespace,
+            &Self::schedule_api_resource(),
+        )
+    }
+
     /// Create a chaos resource (supports all CRD types)
     #[allow(clippy::too_many_arguments)]
     pub async fn create_chaos(
@@ -60,14 +83,16 @@ impl ChaosClient {
         condition_id: &str,
         source_node_id: &str,
         target_node_id: Option<&str>,
+        external_targets: &[String],
+        selection: &PodSelection,
+        recurrence: &ChaosRecurrence,
         chaos_type: &ChaosType,
         direction: &ChaosDirection,
         duration: Option<&str>,
         params: &serde_json::Value,
     ) -> AppResult<String> {
         // Build the name for the chaos resource
-        let short_topo = &topology_id[..8.min(topology_id.len())];
-        let name = format!("ns-{}-{}", short_topo, condition_id);
+        let name = chaos_resource_name(topology_id, condition_id);
         let crd_kind = chaos_type.crd_kind();
 
         info!(
@@ -82,11 +107,19 @@ impl ChaosClient {
             topology_id,
             source_node_id,
             target_node_id,
+            external_targets,
+            selection,
             chaos_type,
             direction,
             duration,
             params,
-        );
+        )?;
+
+        // Recurring conditions are created as a Schedule embedding the chaos spec
+        if recurrence.schedule.is_some() {
+            let schedule_manifest = create_schedule_manifest(&chaos_manifest, &crd_kind, recurrence);
+            return self.create_schedule(schedule_manifest).await;
+        }
 
         // Get the appropriate API resource for this CRD kind
         let ar = Self::api_resource_for_kind(&crd_kind);
@@ -115,6 +148,151 @@ impl ChaosClient {
         }
     }
 
+    /// Submit a manifest with `dryRun=All`: the API server runs validation and
+    /// admission webhooks without persisting anything
+    pub async fn dry_run_create(
+        &self,
+        crd_kind: &ChaosCrdKind,
+        manifest: &serde_json::Value,
+    ) -> AppResult<()> {
+        let api = if manifest["kind"] == "Schedule" {
+            self.schedule_api()
+        } else {
+            Api::namespaced_with(
+                self.client.clone(),
+                &self.namespace,
+                &Self::api_resource_for_kind(crd_kind),
+            )
+        };
+
+        let obj: DynamicObject = serde_json::from_value(manifest.clone())
+            .map_err(|e| AppError::internal(&format!("Failed to create chaos object: {}", e)))?;
+
+        let pp = PostParams {
+            dry_run: true,
+            ..Default::default()
+        };
+        match api.create(&pp, &obj).await {
+            Ok(_) => Ok(()),
+            Err(kube::Error::Api(ae)) => Err(AppError::bad_request(&ae.message)),
+            Err(e) => Err(AppError::internal(&format!("Dry-run failed: {}", e))),
+        }
+    }
+
+    /// Names of the pods currently matched by a Chaos Mesh selector
+    /// (`namespaces` + `labelSelectors`)
+    pub async fn selected_pods(&self, selector: &serde_json::Value) -> AppResult<Vec<String>> {
+        let labels: Vec<String> = selector
+            .get("labelSelectors")
+            .and_then(|l| l.as_object())
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, value)| value.as_str().map(|v| format!("{}={}", key, v)))
+            .collect();
+        let lp = ListParams::default().labels(&labels.join(","));
+
+        let namespaces: Vec<String> = selector
+            .get("namespaces")
+            .and_then(|n| n.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|n| n.as_str().map(String::from))
+            .collect();
+
+        let mut pods = Vec::new();
+        for namespace in namespaces {
+            let api: Api<Pod> = Api::namespaced(self.client.clone(), &namespace);
+            let list = api.list(&lp).await?;
+            pods.extend(list.items.into_iter().filter_map(|pod| pod.metadata.name));
+        }
+
+        Ok(pods)
+    }
+
+    /// Create a `Schedule` from a manifest built by `create_schedule_manifest`
+    pub async fn create_schedule(&self, manifest: Value) -> AppResult<String> {
+        let obj: DynamicObject = serde_json::from_value(manifest)
+            .map_err(|e| AppError::internal(&format!("Failed to create schedule object: {}", e)))?;
+
+        match self.schedule_api().create(&PostParams::default(), &obj).await {
+            Ok(created) => {
+                let created_name = created.metadata.name.unwrap_or_default();
+                info!("Created Schedule: {}", created_name);
+                Ok(created_name)
+            }
+            Err(e) => {
+                error!("Failed to create Schedule: {}", e);
+                Err(AppError::internal(&format!(
+                    "Failed to create schedule: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    /// Pause or resume the `Schedule` of a recurring condition
+    ///
+    /// A paused Schedule stops firing but keeps its history. Returns whether a Schedule
+    /// existed.
+    pub async fn pause_schedule(
+        &self,
+        topology_id: &str,
+        condition_id: &str,
+        paused: bool,
+    ) -> AppResult<bool> {
+        let name = chaos_resource_name(topology_id, condition_id);
+
+        info!("{} Schedule '{}'", if paused { "Pausing" } else { "Resuming" }, name);
+
+        // A null value removes the annotation in a merge patch
+        let pause = paused.then_some("true");
+        let patch = serde_json::json!({
+            "metadata": {
+                "annotations": { "experiment.chaos-mesh.org/pause": pause }
+            }
+        });
+
+        match self
+            .schedule_api()
+            .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+        {
+            Ok(_) => Ok(true),
+            // Not found, or Schedule CRD not installed
+            Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(false),
+            Err(e) => {
+                error!("Failed to patch Schedule {}: {}", name, e);
+                Err(AppError::internal(&format!(
+                    "Failed to pause schedule: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    /// Delete the `Schedule` of a recurring condition (its spawned chaos is garbage collected)
+    ///
+    /// Returns whether a Schedule existed.
+    pub async fn delete_schedule(&self, topology_id: &str, condition_id: &str) -> AppResult<bool> {
+        let name = chaos_resource_name(topology_id, condition_id);
+
+        match self.schedule_api().delete(&name, &DeleteParams::default()).await {
+            Ok(_) => {
+                info!("Deleted Schedule: {}", name);
+                Ok(true)
+            }
+            // Not found, or Schedule CRD not installed
+            Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(false),
+            Err(e) => {
+                error!("Failed to delete Schedule {}: {}", name, e);
+                Err(AppError::internal(&format!(
+                    "Failed to delete schedule: {}",
+                    e
+                )))
+            }
+        }
+    }
+
     /// Delete a chaos resource by condition ID and type
     pub async fn delete_chaos_typed(
         &self,
@@ -122,12 +300,15 @@ impl ChaosClient {
         condition_id: &str,
         chaos_type: &ChaosType,
     ) -> AppResult<()> {
-        let short_topo = &topology_id[..8.min(topology_id.len())];
-        let name = format!("ns-{}-{}", short_topo, condition_id);
+        let name = chaos_resource_name(topology_id, condition_id);
         let crd_kind = chaos_type.crd_kind();
 
         info!("Deleting {:?} '{}'", crd_kind, name);
 
+        if self.delete_schedule(topology_id, condition_id).await? {
+            return Ok(());
+        }
+
         let ar = Self::api_resource_for_kind(&crd_kind);
         let api: Api<DynamicObject> =
             Api::namespaced_with(self.client.clone(), &self.namespace, &ar);
@@ -153,22 +334,20 @@ impl ChaosClient {
 
     /// Delete a chaos resource by condition ID (tries all CRD types)
     pub async fn delete_chaos(&self, topology_id: &str, condition_id: &str) -> AppResult<()> {
-        let short_topo = &topology_id[..8.min(topology_id.len())];
-        let name = format!("ns-{}-{}", short_topo, condition_id);
+        let name = chaos_resource_name(topology_id, condition_id);
 
         info!("Deleting chaos resource '{}'", name);
 
+        // Recurring conditions only exist as a Schedule
+        if self.delete_schedule(topology_id, condition_id).await? {
+            return Ok(());
+        }
+
         // Try to delete from all CRD types (will succeed on the correct one)
-        let crd_kinds = [
-            ChaosCrdKind::NetworkChaos,
-            ChaosCrdKind::StressChaos,
-            ChaosCrdKind::PodChaos,
-            ChaosCrdKind::IOChaos,
-            ChaosCrdKind::HTTPChaos,
-        ];
+        let crd_kinds = ChaosCrdKind::all();
 
         let mut deleted = false;
-        for crd_kind in &crd_kinds {
+        for crd_kind in crd_kinds {
             let ar = Self::api_resource_for_kind(crd_kind);
             let api: Api<DynamicObject> =
                 Api::namespaced_with(self.client.clone(), &self.namespace, &ar);
@@ -198,24 +377,27 @@ impl ChaosClient {
         }
     }
 
-    /// List all chaos resources for a topology (from all CRD types)
+    /// List all chaos resources for a topology (from all CRD types and Schedules)
     pub async fn list_chaos(&self, topology_id: &str) -> AppResult<Vec<ChaosStatus>> {
         info!("Listing all chaos resources for topology {}", topology_id);
 
-        let crd_kinds = [
-            ChaosCrdKind::NetworkChaos,
-            ChaosCrdKind::StressChaos,
-            ChaosCrdKind::PodChaos,
-            ChaosCrdKind::IOChaos,
-            ChaosCrdKind::HTTPChaos,
-        ];
+        self.list_with_labels(&format!("networksim.io/topology={}", topology_id))
+            .await
+    }
 
-        let label_selector = format!("networksim.io/topology={}", topology_id);
-        let lp = ListParams::default().labels(&label_selector);
+    /// List every chaos resource created by NetworkSim, whatever its topology
+    pub async fn list_managed_chaos(&self) -> AppResult<Vec<ChaosStatus>> {
+        self.list_with_labels("app.kubernetes.io/managed-by=networksim")
+            .await
+    }
+
+    async fn list_with_labels(&self, label_selector: &str) -> AppResult<Vec<ChaosStatus>> {
+        let crd_kinds = ChaosCrdKind::all();
+        let lp = ListParams::default().labels(label_selector);
 
         let mut all_statuses = Vec::new();
 
-        for crd_kind in &crd_kinds {
+        for crd_kind in crd_kinds {
             let ar = Self::api_resource_for_kind(crd_kind);
             let api: Api<DynamicObject> =
                 Api::namespaced_with(self.client.clone(), &self.namespace, &ar);
@@ -240,6 +422,13 @@ impl ChaosClient {
             }
         }
 
+        // Recurring conditions only exist as a Schedule between firings
+        match self.schedule_api().list(&lp).await {
+            Ok(list) => all_statuses.extend(list.items.into_iter().filter_map(parse_schedule_status)),
+            Err(kube::Error::Api(ae)) if ae.code == 404 => {}
+            Err(e) => warn!("Failed to list Schedules: {}", e),
+        }
+
         Ok(all_statuses)
     }
 
@@ -255,8 +444,92 @@ impl ChaosClient {
             }
         }
 
+        let label_selector = format!("networksim.io/topology={}", topology_id);
+        let lp = ListParams::default().labels(&label_selector);
+        match self.schedule_api().list(&lp).await {
+            Ok(list) => {
+                for schedule in list.items {
+                    let name = schedule.metadata.name.unwrap_or_default();
+                    if let Err(e) = self.schedule_api().delete(&name, &DeleteParams::default()).await {
+                        warn!("Failed to delete Schedule {}: {}", name, e);
+                    }
+                }
+            }
+            Err(kube::Error::Api(ae)) if ae.code == 404 => {}
+            Err(e) => warn!("Failed to list Schedules: {}", e),
+        }
+
         Ok(())
     }
+
+    /// Delete every chaos resource created by NetworkSim, in all namespaces
+    ///
+    /// Schedules go first so they stop spawning chaos, the chaos they spawned is then
+    /// reported `already_gone` when the garbage collector got to it first.
+    pub async fn delete_all_managed(&self) -> AppResult<Vec<StoppedResource>> {
+        warn!("Emergency stop: deleting all NetworkSim chaos resources");
+
+        let lp = ListParams::default().labels("app.kubernetes.io/managed-by=networksim");
+        let resources = std::iter::once(Self::schedule_api_resource())
+            .chain(ChaosCrdKind::all().iter().map(Self::api_resource_for_kind));
+
+        let mut stopped = Vec::new();
+        for ar in resources {
+            let list = match Api::<DynamicObject>::all_with(self.client.clone(), &ar)
+                .list(&lp)
+                .await
+            {
+                Ok(list) => list,
+                // CRD not installed
+                Err(kube::Error::Api(ae)) if ae.code == 404 => continue,
+                Err(e) => {
+                    warn!("Failed to list {}: {}", ar.kind, e);
+                    continue;
+                }
+            };
+
+            for obj in list.items {
+                let name = obj.metadata.name.clone().unwrap_or_default();
+                let namespace = obj.metadata.namespace.clone().unwrap_or_default();
+                let topology_id = obj
+                    .metadata
+                    .labels
+                    .as_ref()
+                    .and_then(|l| l.get("networksim.io/topology"))
+                    .cloned();
+                let condition_id = topology_id
+                    .as_deref()
+                    .map(|topology_id| condition_id_from_name(&name, topology_id));
+
+                let api: Api<DynamicObject> =
+                    Api::namespaced_with(self.client.clone(), &namespace, &ar);
+                let (outcome, error) = match api.delete(&name, &DeleteParams::default()).await {
+                    Ok(_) => {
+                        info!("Deleted {} {}/{}", ar.kind, namespace, name);
+                        (StopOutcome::Deleted, None)
+                    }
+                    Err(kube::Error::Api(ae)) if ae.code == 404 => (StopOutcome::AlreadyGone, None),
+                    Err(e) => {
+                        error!("Failed to delete {} {}/{}: {}", ar.kind, namespace, name, e);
+                        (StopOutcome::Failed, Some(e.to_string()))
+                    }
+                };
+
+                stopped.push(StoppedResource {
+                    kind: ar.kind.clone(),
+                    namespace: Some(namespace),
+                    name,
+                    topology_id,
+                    condition_id,
+                    outcome,
+                    error,
+                    known: false,
+                });
+            }
+        }
+
+        Ok(stopped)
+    }
 }
 
 /// Extract target pod names from spec
@@ -282,6 +555,40 @@ fn extract_target_pods(spec: &Value) -> Vec<String> {
     pods
 }
 
+/// Extract pods with an injected fault from `status.experiment.containerRecords`
+fn extract_injected_pods(status: &Value) -> Vec<String> {
+    extract_record_pods(status, true)
+}
+
+/// Extract the pods selected by Chaos Mesh (one record per selected pod/container)
+///
+/// Record ids are `namespace/pod` or `namespace/pod/container`
+fn extract_record_pods(status: &Value, injected_only: bool) -> Vec<String> {
+    let mut pods: Vec<String> = Vec::new();
+
+    let records = status
+        .get("experiment")
+        .and_then(|e| e.get("containerRecords"))
+        .and_then(|r| r.as_array());
+
+    for record in records.into_iter().flatten() {
+        if injected_only && record.get("phase").and_then(|p| p.as_str()) != Some("Injected") {
+            continue;
+        }
+        if let Some(pod) = record
+            .get("id")
+            .and_then(|id| id.as_str())
+            .and_then(|id| id.split('/').nth(1))
+        {
+            if !pods.iter().any(|p| p == pod) {
+                pods.push(pod.to_string());
+            }
+        }
+    }
+
+    pods
+}
+
 /// Extract status message
 fn extract_message(status: &Value) -> Option<String> {
     status
@@ -293,9 +600,50 @@ fn extract_message(status: &Value) -> Option<String> {
         .map(|s| s.to_string())
 }
 
+/// Condition id of a resource named `ns-{topology[..8]}-{condition_id}`
+///
+/// Falls back to the last dash-separated segment when the topology is unknown.
+pub fn condition_id_from_name(name: &str, topology_id: &str) -> String {
+    let prefix = format!("ns-{}-", &topology_id[..8.min(topology_id.len())]);
+    match name.strip_prefix(&prefix) {
+        Some(id) if !topology_id.is_empty() => id.to_string(),
+        _ => name.split('-').next_back().unwrap_or(name).to_string(),
+    }
+}
+
+/// Parse a `Schedule` into the status of the chaos it wraps (phase `Scheduled` or `Paused`)
+fn parse_schedule_status(obj: DynamicObject) -> Option<ChaosStatus> {
+    let spec = obj.data.get("spec")?;
+    let crd_kind = ChaosCrdKind::all()
+        .iter()
+        .find(|k| spec.get("type").and_then(|t| t.as_str()) == Some(&format!("{:?}", k)))?;
+    let paused = obj
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get("experiment.chaos-mesh.org/pause"))
+        .is_some_and(|p| p == "true");
+
+    let chaos = DynamicObject {
+        types: None,
+        metadata: obj.metadata.clone(),
+        data: serde_json::json!({ "spec": spec.get(crd_kind.schedule_field()) }),
+    };
+    let mut status = parse_chaos_status(chaos, crd_kind);
+    status.phase = if paused { "Paused" } else { "Scheduled" }.to_string();
+    Some(status)
+}
+
 /// Parse a DynamicObject into a ChaosStatus
 fn parse_chaos_status(obj: DynamicObject, crd_kind: &ChaosCrdKind) -> ChaosStatus {
     let name = obj.metadata.name.unwrap_or_default();
+    let topology_id = obj
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|l| l.get("networksim.io/topology"))
+        .cloned()
+        .unwrap_or_default();
     let data = obj.data;
 
     let spec = data.get("spec").cloned().unwrap_or(Value::Null);
@@ -306,7 +654,9 @@ fn parse_chaos_status(obj: DynamicObject, crd_kind: &ChaosCrdKind) -> ChaosStatu
         ChaosCrdKind::NetworkChaos => {
             let action = spec.get("action").and_then(|v| v.as_str()).unwrap_or("delay");
             match action {
+                "delay" if spec.pointer("/delay/reorder").is_some() => ChaosType::Reorder,
                 "delay" => ChaosType::Delay,
+                "netem" => ChaosType::FlakyLink,
                 "loss" => ChaosType::Loss,
                 "bandwidth" => ChaosType::Bandwidth,
                 "corrupt" => ChaosType::Corrupt,
@@ -315,10 +665,43 @@ fn parse_chaos_status(obj: DynamicObject, crd_kind: &ChaosCrdKind) -> ChaosStatu
                 _ => ChaosType::Delay,
             }
         }
-        ChaosCrdKind::StressChaos => ChaosType::StressCpu,
-        ChaosCrdKind::PodChaos => ChaosType::PodKill,
-        ChaosCrdKind::IOChaos => ChaosType::IoDelay,
-        ChaosCrdKind::HTTPChaos => ChaosType::HttpAbort,
+        ChaosCrdKind::StressChaos => {
+            let stressors = spec.get("stressors");
+            if stressors.and_then(|s| s.get("cpu")).is_none()
+                && stressors.and_then(|s| s.get("memory")).is_some()
+            {
+                ChaosType::StressMemory
+            } else {
+                ChaosType::StressCpu
+            }
+        }
+        ChaosCrdKind::PodChaos => match spec.get("action").and_then(|v| v.as_str()) {
+            Some("pod-failure") => ChaosType::PodFailure,
+            Some("container-kill") => ChaosType::ContainerKill,
+            _ => ChaosType::PodKill,
+        },
+        ChaosCrdKind::IOChaos => match spec.get("action").and_then(|v| v.as_str()) {
+            Some("fault") => ChaosType::IoFault,
+            Some("mistake") => ChaosType::IoMistake,
+            Some("attrOverride") => ChaosType::IoAttr,
+            _ => ChaosType::IoDelay,
+        },
+        ChaosCrdKind::HTTPChaos => {
+            if spec.get("delay").is_some() {
+                ChaosType::HttpDelay
+            } else if spec.get("replace").is_some() {
+                ChaosType::HttpReplace
+            } else if spec.get("patch").is_some() {
+                ChaosType::HttpPatch
+            } else {
+                ChaosType::HttpAbort
+            }
+        }
+        ChaosCrdKind::DNSChaos => match spec.get("action").and_then(|v| v.as_str()) {
+            Some("random") => ChaosType::DnsRandom,
+            _ => ChaosType::DnsError,
+        },
+        ChaosCrdKind::TimeChaos => ChaosType::ClockSkew,
     };
 
     // Check if it's running
@@ -333,14 +716,32 @@ fn parse_chaos_status(obj: DynamicObject, crd_kind: &ChaosCrdKind) -> ChaosStatu
             && c.get("status").and_then(|s| s.as_str()) == Some("True")
     });
 
-    let phase = if is_running { "Running" } else { "Pending" };
+    // Chaos Mesh keeps the resource once its duration elapses, with the experiment stopped
+    let is_finished = status_obj.pointer("/experiment/desiredPhase").and_then(|p| p.as_str())
+        == Some("Stop");
+
+    let phase = if is_finished {
+        "Finished"
+    } else if is_running {
+        "Running"
+    } else {
+        "Pending"
+    };
 
     ChaosStatus {
-        name: name.clone(),
-        condition_id: name.split('-').next_back().unwrap_or(&name).to_string(),
+        condition_id: condition_id_from_name(&name, &topology_id),
+        topology_id,
+        name,
         chaos_type,
         phase: phase.to_string(),
         target_pods: extract_target_pods(&spec),
+        injected_pods: extract_injected_pods(&status_obj),
+        selection_mode: spec
+            .get("mode")
+            .and_then(|m| m.as_str())
+            .and_then(|m| m.parse().ok())
+            .unwrap_or_default(),
+        matched_pods: extract_record_pods(&status_obj, false).len(),
         message: extract_message(&status_obj),
     }
 }
@@ -367,4 +768,69 @@ mod tests {
         let pods = extract_target_pods(&spec);
         assert_eq!(pods, vec!["source-node", "target-node"]);
     }
+
+    #[test]
+    fn test_extract_injected_pods() {
+        let status = serde_json::json!({
+            "experiment": {
+                "containerRecords": [
+                    {"id": "networksim-sim/ns-abc-node-1/main", "phase": "Injected"},
+                    {"id": "networksim-sim/ns-abc-node-1/app-123", "phase": "Injected"},
+                    {"id": "networksim-sim/app-123-node-1-xyz", "phase": "Not Injected"}
+                ]
+            }
+        });
+
+        let pods = extract_injected_pods(&status);
+        assert_eq!(pods, vec!["ns-abc-node-1"]);
+
+        let matched = extract_record_pods(&status, false);
+        assert_eq!(matched, vec!["ns-abc-node-1", "app-123-node-1-xyz"]);
+    }
+
+    #[test]
+    fn test_condition_id_from_name() {
+        // Scenario conditions use full UUIDs, which contain dashes
+        assert_eq!(
+            condition_id_from_name(
+                "ns-topology-6f1c2d3e-aaaa-bbbb-cccc-1234567890ab",
+                "topology-1234"
+            ),
+            "6f1c2d3e-aaaa-bbbb-cccc-1234567890ab"
+        );
+        assert_eq!(condition_id_from_name("ns-abc-1a2b3c4d", ""), "1a2b3c4d");
+    }
+
+    #[test]
+    fn test_parse_chaos_and_schedule_status() {
+        let metadata = serde_json::json!({
+            "name": "ns-topology-1a2b3c4d",
+            "labels": {"networksim.io/topology": "topology-1234"},
+            "annotations": {"experiment.chaos-mesh.org/pause": "true"}
+        });
+
+        let chaos: DynamicObject = serde_json::from_value(serde_json::json!({
+            "apiVersion": "chaos-mesh.org/v1alpha1",
+            "kind": "NetworkChaos",
+            "metadata": metadata,
+            "spec": {"action": "loss"},
+            "status": {"experiment": {"desiredPhase": "Stop"}}
+        }))
+        .unwrap();
+        let status = parse_chaos_status(chaos, &ChaosCrdKind::NetworkChaos);
+        assert_eq!(status.topology_id, "topology-1234");
+        assert_eq!(status.condition_id, "1a2b3c4d");
+        assert_eq!(status.phase, "Finished");
+
+        let schedule: DynamicObject = serde_json::from_value(serde_json::json!({
+            "apiVersion": "chaos-mesh.org/v1alpha1",
+            "kind": "Schedule",
+            "metadata": metadata,
+            "spec": {"type": "NetworkChaos", "networkChaos": {"action": "loss"}}
+        }))
+        .unwrap();
+        let status = parse_schedule_status(schedule).unwrap();
+        assert_eq!(status.chaos_type, ChaosType::Loss);
+        assert_eq!(status.phase, "Paused");
+    }
 }
@@ -2,11 +2,14 @@
 //!
 //! Creates NetworkChaos CRD resources for different chaos types
 
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::BTreeMap;
 
 use super::types::*;
+use crate::error::{AppError, AppResult};
 
 /// Error when converting non-NetworkChaos types to ChaosAction
 #[derive(Debug, Clone)]
@@ -30,6 +33,8 @@ pub enum ChaosAction {
     Corrupt,
     Duplicate,
     Partition,
+    /// Several impairments combined in one spec
+    Netem,
 }
 
 impl TryFrom<&ChaosType> for ChaosAction {
@@ -43,6 +48,9 @@ impl TryFrom<&ChaosType> for ChaosAction {
             ChaosType::Corrupt => Ok(ChaosAction::Corrupt),
             ChaosType::Duplicate => Ok(ChaosAction::Duplicate),
             ChaosType::Partition => Ok(ChaosAction::Partition),
+            // Reordering is a modifier of the delay action in netem
+            ChaosType::Reorder => Ok(ChaosAction::Delay),
+            ChaosType::FlakyLink => Ok(ChaosAction::Netem),
             // Non-NetworkChaos types don't have a ChaosAction
             other => Err(InvalidChaosActionError(other.clone())),
         }
@@ -62,6 +70,8 @@ pub struct NetworkChaosSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<PodSelector>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_targets: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delay: Option<DelaySpec>,
@@ -73,6 +83,8 @@ pub struct NetworkChaosSpec {
     pub corrupt: Option<CorruptSpec>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duplicate: Option<DuplicateSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<RateSpec>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -94,6 +106,23 @@ pub struct DelaySpec {
     pub jitter: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub correlation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reorder: Option<ReorderSpec>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct ReorderSpec {
+    pub reorder: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation: Option<String>,
+    pub gap: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct RateSpec {
+    pub rate: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -136,11 +165,13 @@ pub fn create_chaos_manifest(
     topology_id: &str,
     source_node_id: &str,
     target_node_id: Option<&str>,
+    external_targets: &[String],
+    selection: &PodSelection,
     chaos_type: &ChaosType,
     direction: &ChaosDirection,
     duration: Option<&str>,
     params: &serde_json::Value,
-) -> serde_json::Value {
+) -> AppResult<serde_json::Value> {
     match chaos_type.crd_kind() {
         ChaosCrdKind::NetworkChaos => create_network_chaos(
             name,
@@ -148,26 +179,124 @@ pub fn create_chaos_manifest(
             topology_id,
             source_node_id,
             target_node_id,
+            external_targets,
+            selection,
             chaos_type,
             direction,
             duration,
             params,
         ),
         ChaosCrdKind::StressChaos => create_stress_chaos(
-            name, namespace, topology_id, source_node_id, duration, params,
+            name,
+            namespace,
+            topology_id,
+            source_node_id,
+            selection,
+            chaos_type,
+            duration,
+            params,
         ),
         ChaosCrdKind::PodChaos => create_pod_chaos(
-            name, namespace, topology_id, source_node_id, duration, params,
+            name,
+            namespace,
+            topology_id,
+            source_node_id,
+            selection,
+            chaos_type,
+            duration,
+            params,
         ),
         ChaosCrdKind::IOChaos => create_io_chaos(
-            name, namespace, topology_id, source_node_id, duration, params,
+            name,
+            namespace,
+            topology_id,
+            source_node_id,
+            selection,
+            chaos_type,
+            duration,
+            params,
         ),
         ChaosCrdKind::HTTPChaos => create_http_chaos(
-            name, namespace, topology_id, source_node_id, duration, params,
+            name,
+            namespace,
+            topology_id,
+            source_node_id,
+            selection,
+            chaos_type,
+            duration,
+            params,
+        ),
+        ChaosCrdKind::TimeChaos => create_time_chaos(
+            name, namespace, topology_id, source_node_id, selection, duration, params,
+        ),
+        ChaosCrdKind::DNSChaos => create_dns_chaos(
+            name,
+            namespace,
+            topology_id,
+            source_node_id,
+            target_node_id,
+            selection,
+            chaos_type,
+            duration,
+            params,
         ),
     }
 }
 
+/// Name of the Chaos Mesh resource of a condition (`ns-{topology[..8]}-{condition_id}`)
+pub fn chaos_resource_name(topology_id: &str, condition_id: &str) -> String {
+    let short_topo = &topology_id[..8.min(topology_id.len())];
+    format!("ns-{}-{}", short_topo, condition_id)
+}
+
+/// Create the chaos manifest of a stored condition, as `ChaosClient::create_chaos` builds it
+pub fn create_condition_manifest(
+    condition: &ChaosCondition,
+    namespace: &str,
+) -> AppResult<serde_json::Value> {
+    create_chaos_manifest(
+        &chaos_resource_name(&condition.topology_id, &condition.id),
+        namespace,
+        &condition.topology_id,
+        &condition.source_node_id,
+        condition.target_node_id.as_deref(),
+        &condition.external_targets,
+        &condition.selection,
+        &condition.chaos_type,
+        &condition.direction,
+        condition.duration.as_deref(),
+        &condition.params,
+    )
+}
+
+/// Wrap a chaos manifest in a `Schedule` that re-creates it on every cron firing
+///
+/// The Schedule keeps the chaos resource name and labels so it can be found and
+/// deleted like any other condition resource.
+pub fn create_schedule_manifest(
+    chaos: &serde_json::Value,
+    crd_kind: &ChaosCrdKind,
+    recurrence: &ChaosRecurrence,
+) -> serde_json::Value {
+    let mut spec = json!({
+        "schedule": recurrence.schedule,
+        // Never stack runs: a firing is skipped while the previous one is still injected
+        "concurrencyPolicy": "Forbid",
+        "type": chaos["kind"],
+    });
+    spec[crd_kind.schedule_field()] = chaos["spec"].clone();
+    if let Some(limit) = recurrence.history_limit {
+        spec["historyLimit"] = json!(limit);
+    }
+
+    json!({
+        "apiVersion": "chaos-mesh.org/v1alpha1",
+        "kind": "Schedule",
+        "metadata": chaos["metadata"].clone(),
+        "spec": spec
+    })
+}
+
 /// Create a NetworkChaos manifest
 #[allow(clippy::too_many_arguments)]
 pub fn create_network_chaos(
@@ -176,11 +305,13 @@ pub fn create_network_chaos(
     topology_id: &str,
     source_node_id: &str,
     target_node_id: Option<&str>,
+    external_targets: &[String],
+    selection: &PodSelection,
     chaos_type: &ChaosType,
     direction: &ChaosDirection,
     duration: Option<&str>,
     params: &serde_json::Value,
-) -> serde_json::Value {
+) -> AppResult<serde_json::Value> {
     // Safety: create_chaos_manifest dispatcher ensures only NetworkChaos types reach here
     let action = match chaos_type {
         ChaosType::Delay => "delay",
@@ -189,12 +320,29 @@ pub fn create_network_chaos(
         ChaosType::Corrupt => "corrupt",
         ChaosType::Duplicate => "duplicate",
         ChaosType::Partition => "partition",
+        ChaosType::Reorder => "delay",
+        ChaosType::FlakyLink => "netem",
         // Non-network types are handled by their own builders via create_chaos_manifest
-        ChaosType::StressCpu | ChaosType::PodKill | ChaosType::IoDelay | ChaosType::HttpAbort => {
-            tracing::error!("Invalid chaos type for NetworkChaos: {:?}. This is a bug - the dispatcher should prevent this.", chaos_type);
-            return json!({
-                "error": format!("Invalid chaos type for NetworkChaos: {:?}", chaos_type)
-            });
+        ChaosType::StressCpu
+        | ChaosType::StressMemory
+        | ChaosType::PodKill
+        | ChaosType::PodFailure
+        | ChaosType::ContainerKill
+        | ChaosType::IoDelay
+        | ChaosType::IoFault
+        | ChaosType::IoMistake
+        | ChaosType::IoAttr
+        | ChaosType::HttpAbort
+        | ChaosType::HttpDelay
+        | ChaosType::HttpReplace
+        | ChaosType::HttpPatch
+        | ChaosType::DnsError
+        | ChaosType::DnsRandom
+        | ChaosType::ClockSkew => {
+            return Err(AppError::internal(&format!(
+                "Invalid chaos type for NetworkChaos: {:?}",
+                chaos_type
+            )));
         }
     };
 
@@ -209,14 +357,13 @@ pub fn create_network_chaos(
     // Build spec based on chaos type
     let mut spec = json!({
         "action": action,
-        "mode": "all",
         "selector": {
             "namespaces": [namespace],
-            "labelSelectors": source_labels,
-            "mode": "all"
+            "labelSelectors": source_labels
         },
         "direction": direction.to_string()
     });
+    apply_selection(&mut spec, selection);
 
     // Add target if specified
     if let Some(target_id) = target_node_id {
@@ -227,7 +374,9 @@ pub fn create_network_chaos(
         );
         target_labels.insert("networksim.io/node".to_string(), target_id.to_string());
 
-        // Chaos Mesh requires target.selector structure
+        // Chaos Mesh requires target.selector structure. The selection mode only limits
+        // the pods the fault is injected into; the peer side is always every pod of the
+        // target node, so the fault covers all of its traffic.
         spec["target"] = json!({
             "selector": {
                 "namespaces": [namespace],
@@ -237,6 +386,11 @@ pub fn create_network_chaos(
         });
     }
 
+    // Traffic to destinations outside the cluster (only honoured with direction "to")
+    if !external_targets.is_empty() {
+        spec["externalTargets"] = json!(external_targets);
+    }
+
     // Add duration if specified
     if let Some(dur) = duration {
         spec["duration"] = json!(dur);
@@ -245,12 +399,7 @@ pub fn create_network_chaos(
     // Add type-specific parameters
     match chaos_type {
         ChaosType::Delay => {
-            let delay_params: DelayParams =
-                serde_json::from_value(params.clone()).unwrap_or_else(|_| DelayParams {
-                    latency: "100ms".to_string(),
-                    jitter: None,
-                    correlation: None,
-                });
+            let delay_params: DelayParams = typed_params(chaos_type, params)?;
             let mut delay = json!({
                 "latency": delay_params.latency
             });
@@ -263,11 +412,7 @@ pub fn create_network_chaos(
             spec["delay"] = delay;
         }
         ChaosType::Loss => {
-            let loss_params: LossParams =
-                serde_json::from_value(params.clone()).unwrap_or_else(|_| LossParams {
-                    loss: "10".to_string(),
-                    correlation: None,
-                });
+            let loss_params: LossParams = typed_params(chaos_type, params)?;
             let mut loss = json!({
                 "loss": loss_params.loss
             });
@@ -277,12 +422,7 @@ pub fn create_network_chaos(
             spec["loss"] = loss;
         }
         ChaosType::Bandwidth => {
-            let bw_params: BandwidthParams =
-                serde_json::from_value(params.clone()).unwrap_or_else(|_| BandwidthParams {
-                    rate: "1mbps".to_string(),
-                    buffer: Some(10000),
-                    limit: Some(10000),
-                });
+            let bw_params: BandwidthParams = typed_params(chaos_type, params)?;
             spec["bandwidth"] = json!({
                 "rate": bw_params.rate,
                 "buffer": bw_params.buffer.unwrap_or(10000),
@@ -290,11 +430,7 @@ pub fn create_network_chaos(
             });
         }
         ChaosType::Corrupt => {
-            let corrupt_params: CorruptParams = serde_json::from_value(params.clone())
-                .unwrap_or_else(|_| CorruptParams {
-                    corrupt: "10".to_string(),
-                    correlation: None,
-                });
+            let corrupt_params: CorruptParams = typed_params(chaos_type, params)?;
             let mut corrupt = json!({
                 "corrupt": corrupt_params.corrupt
             });
@@ -304,11 +440,7 @@ pub fn create_network_chaos(
             spec["corrupt"] = corrupt;
         }
         ChaosType::Duplicate => {
-            let dup_params: DuplicateParams = serde_json::from_value(params.clone())
-                .unwrap_or_else(|_| DuplicateParams {
-                    duplicate: "10".to_string(),
-                    correlation: None,
-                });
+            let dup_params: DuplicateParams = typed_params(chaos_type, params)?;
             let mut dup = json!({
                 "duplicate": dup_params.duplicate
             });
@@ -324,13 +456,71 @@ pub fn create_network_chaos(
                 "loss": "100"
             });
         }
+        ChaosType::Reorder => {
+            let reorder_params: ReorderParams = typed_params(chaos_type, params)?;
+            // netem only reorders packets that are delayed
+            spec["delay"] = json!({
+                "latency": reorder_params.latency.unwrap_or_else(|| "10ms".to_string()),
+                "reorder": reorder_spec(
+                    reorder_params.reorder,
+                    reorder_params.correlation,
+                    reorder_params.gap
+                )
+            });
+        }
+        ChaosType::FlakyLink => {
+            let flaky_params: FlakyLinkParams = typed_params(chaos_type, params)?;
+            let corr = flaky_params.correlation;
+
+            let mut delay = json!({
+                "latency": flaky_params.latency
+            });
+            if let Some(jitter) = flaky_params.jitter {
+                delay["jitter"] = json!(jitter);
+            }
+            if let Some(ref c) = corr {
+                delay["correlation"] = json!(c);
+            }
+            if let Some(reorder) = flaky_params.reorder {
+                delay["reorder"] = reorder_spec(reorder, corr.clone(), None);
+            }
+            spec["delay"] = delay;
+
+            if let Some(loss) = flaky_params.loss {
+                spec["loss"] = with_correlation(json!({ "loss": loss }), &corr);
+            }
+            if let Some(duplicate) = flaky_params.duplicate {
+                spec["duplicate"] = with_correlation(json!({ "duplicate": duplicate }), &corr);
+            }
+            if let Some(corrupt) = flaky_params.corrupt {
+                spec["corrupt"] = with_correlation(json!({ "corrupt": corrupt }), &corr);
+            }
+            if let Some(rate) = flaky_params.rate {
+                spec["rate"] = json!({ "rate": rate });
+            }
+        }
         // Non-NetworkChaos types are handled by their own builders - this branch
         // should never execute due to the early return above
-        ChaosType::StressCpu | ChaosType::PodKill | ChaosType::IoDelay | ChaosType::HttpAbort => {}
+        ChaosType::StressCpu
+        | ChaosType::StressMemory
+        | ChaosType::PodKill
+        | ChaosType::PodFailure
+        | ChaosType::ContainerKill
+        | ChaosType::IoDelay
+        | ChaosType::IoFault
+        | ChaosType::IoMistake
+        | ChaosType::IoAttr
+        | ChaosType::HttpAbort
+        | ChaosType::HttpDelay
+        | ChaosType::HttpReplace
+        | ChaosType::HttpPatch
+        | ChaosType::DnsError
+        | ChaosType::DnsRandom
+        | ChaosType::ClockSkew => {}
     }
 
     // Build the full NetworkChaos resource
-    json!({
+    Ok(json!({
         "apiVersion": "chaos-mesh.org/v1alpha1",
         "kind": "NetworkChaos",
         "metadata": {
@@ -343,27 +533,122 @@ pub fn create_network_chaos(
             }
         },
         "spec": spec
-    })
+    }))
+}
+
+/// Deserialize the params of a chaos type, rejecting invalid or missing fields
+///
+/// Params are checked field by field by `validate_chaos_params` before they are stored,
+/// this only guards against rendering a different fault than the one requested.
+fn typed_params<T: DeserializeOwned>(
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> AppResult<T> {
+    let params = if params.is_null() { json!({}) } else { params.clone() };
+    serde_json::from_value(params)
+        .map_err(|e| AppError::bad_request(&format!("Invalid {} params: {}", chaos_type, e)))
+}
+
+/// Build the netem `reorder` block of a delay spec
+fn reorder_spec(
+    reorder: String,
+    correlation: Option<String>,
+    gap: Option<u32>,
+) -> serde_json::Value {
+    let mut spec = json!({
+        "reorder": reorder,
+        "gap": gap.unwrap_or(0)
+    });
+    if let Some(corr) = correlation {
+        spec["correlation"] = json!(corr);
+    }
+    spec
+}
+
+/// Add a `correlation` field to a netem impairment block when set
+fn with_correlation(
+    mut block: serde_json::Value,
+    correlation: &Option<String>,
+) -> serde_json::Value {
+    if let Some(corr) = correlation {
+        block["correlation"] = json!(corr);
+    }
+    block
+}
+
+/// Set the pod selection mode (and value) of a Chaos Mesh spec
+///
+/// Only the injected pods are narrowed down; NetworkChaos targets keep matching all pods
+fn apply_selection(spec: &mut serde_json::Value, selection: &PodSelection) {
+    spec["mode"] = json!(selection.selection_mode.to_string());
+    if let Some(ref value) = selection.selection_value {
+        spec["value"] = json!(value);
+    }
 }
 
 // ============================================================================
 // New Chaos Type Builders
 // ============================================================================
 
-/// Create a StressChaos manifest for CPU stress
+/// Build the `cpu` stressor of a StressChaos spec
+fn cpu_stressor(workers: Option<u32>, load: Option<u32>) -> serde_json::Value {
+    json!({
+        "workers": workers.unwrap_or(1),
+        "load": load.unwrap_or(80)
+    })
+}
+
+/// Build the `memory` stressor of a StressChaos spec
+fn memory_stressor(workers: Option<u32>, size: Option<String>) -> serde_json::Value {
+    json!({
+        "workers": workers.unwrap_or(1),
+        "size": size.unwrap_or_else(|| "256MB".to_string())
+    })
+}
+
+/// Create a StressChaos manifest for CPU and/or memory stress
+///
+/// The chaos type selects the primary stressor; the other one can be
+/// added through the nested `memory` / `cpu` params.
+#[allow(clippy::too_many_arguments)]
 pub fn create_stress_chaos(
     name: &str,
     namespace: &str,
     topology_id: &str,
     node_id: &str,
+    selection: &PodSelection,
+    chaos_type: &ChaosType,
     duration: Option<&str>,
     params: &serde_json::Value,
-) -> serde_json::Value {
-    let stress_params: StressCpuParams =
-        serde_json::from_value(params.clone()).unwrap_or_default();
-
-    let workers = stress_params.workers.unwrap_or(1);
-    let load = stress_params.load.unwrap_or(80);
+) -> AppResult<serde_json::Value> {
+    let mut stressors = serde_json::Map::new();
+    let container_names = match chaos_type {
+        ChaosType::StressMemory => {
+            let mem_params: StressMemoryParams = typed_params(chaos_type, params)?;
+            stressors.insert(
+                "memory".to_string(),
+                memory_stressor(mem_params.workers, mem_params.size),
+            );
+            if let Some(cpu) = mem_params.cpu {
+                stressors.insert("cpu".to_string(), cpu_stressor(cpu.workers, cpu.load));
+            }
+            mem_params.container_names
+        }
+        _ => {
+            let cpu_params: StressCpuParams = typed_params(chaos_type, params)?;
+            stressors.insert(
+                "cpu".to_string(),
+                cpu_stressor(cpu_params.workers, cpu_params.load),
+            );
+            if let Some(memory) = cpu_params.memory {
+                stressors.insert(
+                    "memory".to_string(),
+                    memory_stressor(memory.workers, memory.size),
+                );
+            }
+            cpu_params.container_names
+        }
+    };
 
     let mut source_labels = BTreeMap::new();
     source_labels.insert(
@@ -373,24 +658,23 @@ pub fn create_stress_chaos(
     source_labels.insert("networksim.io/node".to_string(), node_id.to_string());
 
     let mut spec = json!({
-        "mode": "all",
         "selector": {
             "namespaces": [namespace],
             "labelSelectors": source_labels
         },
-        "stressors": {
-            "cpu": {
-                "workers": workers,
-                "load": load
-            }
-        }
+        "stressors": stressors
     });
+    apply_selection(&mut spec, selection);
+
+    if let Some(containers) = container_names {
+        spec["containerNames"] = json!(containers);
+    }
 
     if let Some(dur) = duration {
         spec["duration"] = json!(dur);
     }
 
-    json!({
+    Ok(json!({
         "apiVersion": "chaos-mesh.org/v1alpha1",
         "kind": "StressChaos",
         "metadata": {
@@ -403,19 +687,81 @@ pub fn create_stress_chaos(
             }
         },
         "spec": spec
-    })
+    }))
 }
 
-/// Create a PodChaos manifest for pod-kill action
+/// Create a TimeChaos manifest for clock skew
+pub fn create_time_chaos(
+    name: &str,
+    namespace: &str,
+    topology_id: &str,
+    node_id: &str,
+    selection: &PodSelection,
+    duration: Option<&str>,
+    params: &serde_json::Value,
+) -> AppResult<serde_json::Value> {
+    let time_params: TimeChaosParams = typed_params(&ChaosType::ClockSkew, params)?;
+
+    let mut source_labels = BTreeMap::new();
+    source_labels.insert(
+        "networksim.io/topology".to_string(),
+        topology_id.to_string(),
+    );
+    source_labels.insert("networksim.io/node".to_string(), node_id.to_string());
+
+    let mut spec = json!({
+        "selector": {
+            "namespaces": [namespace],
+            "labelSelectors": source_labels
+        },
+        "timeOffset": time_params.time_offset,
+        "clockIds": time_params
+            .clock_ids
+            .unwrap_or_else(|| vec!["CLOCK_REALTIME".to_string()])
+    });
+    apply_selection(&mut spec, selection);
+
+    if let Some(containers) = time_params.container_names {
+        spec["containerNames"] = json!(containers);
+    }
+
+    if let Some(dur) = duration {
+        spec["duration"] = json!(dur);
+    }
+
+    Ok(json!({
+        "apiVersion": "chaos-mesh.org/v1alpha1",
+        "kind": "TimeChaos",
+        "metadata": {
+            "name": name,
+            "namespace": namespace,
+            "labels": {
+                "app.kubernetes.io/managed-by": "networksim",
+                "networksim.io/topology": topology_id,
+                "networksim.io/chaos": "true"
+            }
+        },
+        "spec": spec
+    }))
+}
+
+/// Create a PodChaos manifest for pod-kill, pod-failure and container-kill actions
+#[allow(clippy::too_many_arguments)]
 pub fn create_pod_chaos(
     name: &str,
     namespace: &str,
     topology_id: &str,
     node_id: &str,
+    selection: &PodSelection,
+    chaos_type: &ChaosType,
     duration: Option<&str>,
     params: &serde_json::Value,
-) -> serde_json::Value {
-    let pod_params: PodKillParams = serde_json::from_value(params.clone()).unwrap_or_default();
+) -> AppResult<serde_json::Value> {
+    let action = match chaos_type {
+        ChaosType::PodFailure => "pod-failure",
+        ChaosType::ContainerKill => "container-kill",
+        _ => "pod-kill",
+    };
 
     let mut source_labels = BTreeMap::new();
     source_labels.insert(
@@ -425,23 +771,33 @@ pub fn create_pod_chaos(
     source_labels.insert("networksim.io/node".to_string(), node_id.to_string());
 
     let mut spec = json!({
-        "action": "pod-kill",
-        "mode": "all",
+        "action": action,
         "selector": {
             "namespaces": [namespace],
             "labelSelectors": source_labels
         }
     });
+    apply_selection(&mut spec, selection);
 
-    if let Some(grace) = pod_params.grace_period {
-        spec["gracePeriod"] = json!(grace);
+    match chaos_type {
+        ChaosType::ContainerKill => {
+            let kill_params: ContainerKillParams = typed_params(chaos_type, params)?;
+            spec["containerNames"] = json!(kill_params.container_names);
+        }
+        ChaosType::PodKill => {
+            let pod_params: PodKillParams = typed_params(chaos_type, params)?;
+            if let Some(grace) = pod_params.grace_period {
+                spec["gracePeriod"] = json!(grace);
+            }
+        }
+        _ => {}
     }
 
     if let Some(dur) = duration {
         spec["duration"] = json!(dur);
     }
 
-    json!({
+    Ok(json!({
         "apiVersion": "chaos-mesh.org/v1alpha1",
         "kind": "PodChaos",
         "metadata": {
@@ -454,27 +810,21 @@ pub fn create_pod_chaos(
             }
         },
         "spec": spec
-    })
+    }))
 }
 
-/// Create an IOChaos manifest for I/O delay
+/// Create an IOChaos manifest for latency, fault, mistake and attrOverride actions
+#[allow(clippy::too_many_arguments)]
 pub fn create_io_chaos(
     name: &str,
     namespace: &str,
     topology_id: &str,
     node_id: &str,
+    selection: &PodSelection,
+    chaos_type: &ChaosType,
     duration: Option<&str>,
     params: &serde_json::Value,
-) -> serde_json::Value {
-    let io_params: IoDelayParams = serde_json::from_value(params.clone()).unwrap_or_else(|_| {
-        IoDelayParams {
-            delay: "100ms".to_string(),
-            path: None,
-            percent: None,
-            methods: None,
-        }
-    });
-
+) -> AppResult<serde_json::Value> {
     let mut source_labels = BTreeMap::new();
     source_labels.insert(
         "networksim.io/topology".to_string(),
@@ -483,27 +833,118 @@ pub fn create_io_chaos(
     source_labels.insert("networksim.io/node".to_string(), node_id.to_string());
 
     let mut spec = json!({
-        "action": "latency",
-        "mode": "all",
         "selector": {
             "namespaces": [namespace],
             "labelSelectors": source_labels
-        },
-        "delay": io_params.delay,
-        "path": io_params.path.unwrap_or_else(|| "/".to_string()),
-        "percent": io_params.percent.unwrap_or(100),
-        "volumePath": "/",
+        }
     });
+    apply_selection(&mut spec, selection);
+
+    // Fields shared by every IOChaos action
+    let (path, volume_path, percent, methods, container_names) = match chaos_type {
+        ChaosType::IoFault => {
+            let io_params: IoFaultParams = typed_params(chaos_type, params)?;
+            spec["action"] = json!("fault");
+            spec["errno"] = json!(io_params.errno.unwrap_or(5));
+            (
+                io_params.path,
+                io_params.volume_path,
+                io_params.percent,
+                io_params.methods,
+                io_params.container_names,
+            )
+        }
+        ChaosType::IoMistake => {
+            let io_params: IoMistakeParams = typed_params(chaos_type, params)?;
+            spec["action"] = json!("mistake");
+            spec["mistake"] = json!({
+                "filling": io_params.filling.unwrap_or_else(|| "random".to_string()),
+                "maxOccurrences": io_params.max_occurrences.unwrap_or(1),
+                "maxLength": io_params.max_length.unwrap_or(10)
+            });
+            let methods = io_params
+                .methods
+                .or_else(|| Some(vec!["read".to_string(), "write".to_string()]));
+            (
+                io_params.path,
+                io_params.volume_path,
+                io_params.percent,
+                methods,
+                io_params.container_names,
+            )
+        }
+        ChaosType::IoAttr => {
+            let io_params: IoAttrParams = typed_params(chaos_type, params)?;
+            spec["action"] = json!("attrOverride");
+            let mut attr = json!({});
+            if let Some(perm) = io_params.perm {
+                attr["perm"] = json!(perm);
+            }
+            if let Some(size) = io_params.size {
+                attr["size"] = json!(size);
+            }
+            if let Some(uid) = io_params.uid {
+                attr["uid"] = json!(uid);
+            }
+            if let Some(gid) = io_params.gid {
+                attr["gid"] = json!(gid);
+            }
+            if let Some(nlink) = io_params.nlink {
+                attr["nlink"] = json!(nlink);
+            }
+            spec["attr"] = attr;
+            (
+                io_params.path,
+                io_params.volume_path,
+                io_params.percent,
+                None,
+                io_params.container_names,
+            )
+        }
+        _ => {
+            let io_params: IoDelayParams = typed_params(chaos_type, params)?;
+            spec["action"] = json!("latency");
+            spec["delay"] = json!(io_params.delay);
+            (
+                io_params.path,
+                io_params.volume_path,
+                io_params.percent,
+                io_params.methods,
+                io_params.container_names,
+            )
+        }
+    };
+
+    let path = match (path, &volume_path) {
+        (Some(path), _) => path,
+        (None, Some(volume_path)) => default_io_path(volume_path),
+        // Historical io-delay default without a volume: whole filesystem of the pod
+        (None, None) if *chaos_type == ChaosType::IoDelay => "/".to_string(),
+        (None, None) => {
+            return Err(AppError::bad_request(&format!(
+                "Invalid {} params: missing field `volume_path`",
+                chaos_type
+            )))
+        }
+    };
+    let volume_path = volume_path.unwrap_or_else(|| "/".to_string());
+    spec["path"] = json!(path);
+    spec["volumePath"] = json!(volume_path);
+    spec["percent"] = json!(percent.unwrap_or(100));
 
-    if let Some(methods) = io_params.methods {
+    if let Some(methods) = methods {
         spec["methods"] = json!(methods);
     }
 
+    if let Some(containers) = container_names {
+        spec["containerNames"] = json!(containers);
+    }
+
     if let Some(dur) = duration {
         spec["duration"] = json!(dur);
     }
 
-    json!({
+    Ok(json!({
         "apiVersion": "chaos-mesh.org/v1alpha1",
         "kind": "IOChaos",
         "metadata": {
@@ -516,20 +957,26 @@ pub fn create_io_chaos(
             }
         },
         "spec": spec
-    })
+    }))
+}
+
+/// Glob matching every file under a volume mount
+fn default_io_path(volume_path: &str) -> String {
+    format!("{}/**/*", volume_path.trim_end_matches('/'))
 }
 
-/// Create an HTTPChaos manifest for HTTP abort
+/// Create an HTTPChaos manifest for HTTP abort, delay, replace and patch
+#[allow(clippy::too_many_arguments)]
 pub fn create_http_chaos(
     name: &str,
     namespace: &str,
     topology_id: &str,
     node_id: &str,
+    selection: &PodSelection,
+    chaos_type: &ChaosType,
     duration: Option<&str>,
     params: &serde_json::Value,
-) -> serde_json::Value {
-    let http_params: HttpAbortParams = serde_json::from_value(params.clone()).unwrap_or_default();
-
+) -> AppResult<serde_json::Value> {
     let mut source_labels = BTreeMap::new();
     source_labels.insert(
         "networksim.io/topology".to_string(),
@@ -538,35 +985,199 @@ pub fn create_http_chaos(
     source_labels.insert("networksim.io/node".to_string(), node_id.to_string());
 
     let mut spec = json!({
-        "mode": "all",
         "selector": {
             "namespaces": [namespace],
             "labelSelectors": source_labels
         },
-        "target": "Request",
-        "abort": true,
-        "code": http_params.code.unwrap_or(500)
+        "target": "Request"
     });
+    apply_selection(&mut spec, selection);
+
+    match chaos_type {
+        ChaosType::HttpDelay => {
+            let http_params: HttpDelayParams = typed_params(chaos_type, params)?;
+            apply_http_match(
+                &mut spec,
+                http_params.target,
+                http_params.method,
+                http_params.path,
+                http_params.port,
+                http_params.headers,
+            );
+            spec["delay"] = json!(http_params.delay);
+        }
+        ChaosType::HttpReplace => {
+            let http_params: HttpReplaceParams = typed_params(chaos_type, params)?;
+            apply_http_match(
+                &mut spec,
+                http_params.target,
+                http_params.method,
+                http_params.path,
+                http_params.port,
+                None,
+            );
 
-    if let Some(method) = http_params.method {
+            let mut replace = json!({});
+            if let Some(code) = http_params.code {
+                replace["code"] = json!(code);
+            }
+            if let Some(headers) = http_params.headers {
+                replace["headers"] = json!(headers);
+            }
+            if let Some(body) = http_params.body {
+                // Chaos Mesh expects the replacement body base64-encoded
+                replace["body"] = json!(STANDARD.encode(body));
+            }
+            spec["replace"] = replace;
+        }
+        ChaosType::HttpPatch => {
+            let http_params: HttpPatchParams = typed_params(chaos_type, params)?;
+            apply_http_match(
+                &mut spec,
+                http_params.target,
+                http_params.method,
+                http_params.path,
+                http_params.port,
+                None,
+            );
+
+            let mut patch = json!({});
+            if let Some(headers) = http_params.headers {
+                let pairs: Vec<[String; 2]> = headers.into_iter().map(|(k, v)| [k, v]).collect();
+                patch["headers"] = json!(pairs);
+            }
+            if let Some(body) = http_params.body {
+                patch["body"] = json!({
+                    "type": "JSON",
+                    "value": body.to_string()
+                });
+            }
+            spec["patch"] = patch;
+        }
+        _ => {
+            let http_params: HttpAbortParams = typed_params(chaos_type, params)?;
+            apply_http_match(
+                &mut spec,
+                HttpTarget::Request,
+                http_params.method,
+                http_params.path,
+                http_params.port,
+                None,
+            );
+            spec["abort"] = json!(true);
+            spec["code"] = json!(http_params.code.unwrap_or(500));
+        }
+    }
+
+    if let Some(dur) = duration {
+        spec["duration"] = json!(dur);
+    }
+
+    Ok(json!({
+        "apiVersion": "chaos-mesh.org/v1alpha1",
+        "kind": "HTTPChaos",
+        "metadata": {
+            "name": name,
+            "namespace": namespace,
+            "labels": {
+                "app.kubernetes.io/managed-by": "networksim",
+                "networksim.io/topology": topology_id,
+                "networksim.io/chaos": "true"
+            }
+        },
+        "spec": spec
+    }))
+}
+
+/// Set the target and request/response matchers of an HTTPChaos spec
+fn apply_http_match(
+    spec: &mut serde_json::Value,
+    target: HttpTarget,
+    method: Option<String>,
+    path: Option<String>,
+    port: Option<u16>,
+    headers: Option<BTreeMap<String, String>>,
+) {
+    spec["target"] = json!(target.as_chaos_mesh());
+
+    if let Some(method) = method {
         spec["method"] = json!(method);
     }
 
-    if let Some(path) = http_params.path {
+    if let Some(path) = path {
         spec["path"] = json!(path);
     }
 
-    if let Some(port) = http_params.port {
+    if let Some(port) = port {
         spec["port"] = json!(port);
     }
 
+    if let Some(headers) = headers {
+        let field = match target {
+            HttpTarget::Request => "request_headers",
+            HttpTarget::Response => "response_headers",
+        };
+        spec[field] = json!(headers);
+    }
+}
+
+/// Create a DNSChaos manifest for DNS errors or random DNS answers
+#[allow(clippy::too_many_arguments)]
+pub fn create_dns_chaos(
+    name: &str,
+    namespace: &str,
+    topology_id: &str,
+    node_id: &str,
+    target_node_id: Option<&str>,
+    selection: &PodSelection,
+    chaos_type: &ChaosType,
+    duration: Option<&str>,
+    params: &serde_json::Value,
+) -> AppResult<serde_json::Value> {
+    let dns_params: DnsChaosParams = typed_params(chaos_type, params)?;
+
+    let action = dns_params.action.unwrap_or_else(|| match chaos_type {
+        ChaosType::DnsRandom => "random".to_string(),
+        _ => "error".to_string(),
+    });
+
+    // Without explicit patterns, target the service name of the target node
+    let mut patterns = dns_params.patterns;
+    if patterns.is_empty() {
+        if let Some(target_id) = target_node_id {
+            let short_topo = &topology_id[..8.min(topology_id.len())];
+            patterns.push(format!("ns-{}-{}.*", short_topo, target_id).to_lowercase());
+        }
+    }
+
+    let mut source_labels = BTreeMap::new();
+    source_labels.insert(
+        "networksim.io/topology".to_string(),
+        topology_id.to_string(),
+    );
+    source_labels.insert("networksim.io/node".to_string(), node_id.to_string());
+
+    let mut spec = json!({
+        "action": action,
+        "selector": {
+            "namespaces": [namespace],
+            "labelSelectors": source_labels
+        }
+    });
+    apply_selection(&mut spec, selection);
+
+    // Chaos Mesh applies the action to every domain when patterns are omitted
+    if !patterns.is_empty() {
+        spec["patterns"] = json!(patterns);
+    }
+
     if let Some(dur) = duration {
         spec["duration"] = json!(dur);
     }
 
-    json!({
+    Ok(json!({
         "apiVersion": "chaos-mesh.org/v1alpha1",
-        "kind": "HTTPChaos",
+        "kind": "DNSChaos",
         "metadata": {
             "name": name,
             "namespace": namespace,
@@ -577,7 +1188,7 @@ pub fn create_http_chaos(
             }
         },
         "spec": spec
-    })
+    }))
 }
 
 #[cfg(test)]
@@ -592,11 +1203,14 @@ mod tests {
             "topo-123",
             "node-1",
             Some("node-2"),
+            &[],
+            &PodSelection::default(),
             &ChaosType::Delay,
             &ChaosDirection::To,
             Some("60s"),
             &json!({"latency": "200ms", "jitter": "50ms"}),
-        );
+        )
+        .unwrap();
 
         assert_eq!(chaos["kind"], "NetworkChaos");
         assert_eq!(chaos["spec"]["action"], "delay");
@@ -613,11 +1227,14 @@ mod tests {
             "topo-123",
             "node-1",
             None,
+            &[],
+            &PodSelection::default(),
             &ChaosType::Loss,
             &ChaosDirection::Both,
             None,
             &json!({"loss": "25"}),
-        );
+        )
+        .unwrap();
 
         assert_eq!(chaos["spec"]["action"], "loss");
         assert_eq!(chaos["spec"]["loss"]["loss"], "25");
@@ -632,14 +1249,398 @@ mod tests {
             "topo-123",
             "node-1",
             Some("node-2"),
+            &[],
+            &PodSelection::default(),
             &ChaosType::Partition,
             &ChaosDirection::Both,
             None,
             &json!({}),
-        );
+        )
+        .unwrap();
 
         // Partition is implemented as 100% loss
         assert_eq!(chaos["spec"]["action"], "loss");
         assert_eq!(chaos["spec"]["loss"]["loss"], "100");
     }
+
+    /// Builder inputs a test can override; name, namespace and source node are fixed
+    struct ManifestArgs<'a> {
+        topology_id: &'a str,
+        target_node_id: Option<&'a str>,
+        external_targets: Vec<String>,
+        selection: PodSelection,
+        direction: ChaosDirection,
+        duration: Option<&'a str>,
+    }
+
+    impl Default for ManifestArgs<'_> {
+        fn default() -> Self {
+            Self {
+                topology_id: "topo-123",
+                target_node_id: None,
+                external_targets: Vec::new(),
+                selection: PodSelection::default(),
+                direction: ChaosDirection::To,
+                duration: None,
+            }
+        }
+    }
+
+    /// Manifest of a condition on node-1, with `args` overriding the defaults
+    fn manifest_with(
+        chaos_type: ChaosType,
+        params: serde_json::Value,
+        args: ManifestArgs,
+    ) -> AppResult<serde_json::Value> {
+        create_chaos_manifest(
+            "test-chaos",
+            "networksim-sim",
+            args.topology_id,
+            "node-1",
+            args.target_node_id,
+            &args.external_targets,
+            &args.selection,
+            &chaos_type,
+            &args.direction,
+            args.duration,
+            &params,
+        )
+    }
+
+    /// Manifest of a condition on node-1 with default args
+    fn manifest(chaos_type: ChaosType, params: serde_json::Value) -> serde_json::Value {
+        manifest_with(chaos_type, params, ManifestArgs::default()).unwrap()
+    }
+
+    #[test]
+    fn test_create_dns_error_chaos_defaults_to_target_service() {
+        let chaos = manifest_with(
+            ChaosType::DnsError,
+            json!({}),
+            ManifestArgs {
+                topology_id: "topo-12345678",
+                target_node_id: Some("node-2"),
+                duration: Some("30s"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chaos["kind"], "DNSChaos");
+        assert_eq!(chaos["spec"]["action"], "error");
+        assert_eq!(chaos["spec"]["patterns"][0], "ns-topo-123-node-2.*");
+        assert_eq!(chaos["spec"]["duration"], "30s");
+    }
+
+    #[test]
+    fn test_create_dns_random_chaos_with_patterns() {
+        let chaos = manifest(ChaosType::DnsRandom, json!({"patterns": ["example.com", "api.*"]}));
+
+        assert_eq!(chaos["spec"]["action"], "random");
+        assert_eq!(chaos["spec"]["patterns"], json!(["example.com", "api.*"]));
+    }
+
+    #[test]
+    fn test_create_clock_skew_chaos() {
+        let chaos = manifest(
+            ChaosType::ClockSkew,
+            json!({"time_offset": "-5m", "container_names": ["app-abc"]}),
+        );
+
+        assert_eq!(chaos["kind"], "TimeChaos");
+        assert_eq!(chaos["spec"]["timeOffset"], "-5m");
+        assert_eq!(chaos["spec"]["clockIds"], json!(["CLOCK_REALTIME"]));
+        assert_eq!(chaos["spec"]["containerNames"], json!(["app-abc"]));
+    }
+
+    #[test]
+    fn test_create_stress_cpu_chaos_defaults() {
+        let chaos = manifest(ChaosType::StressCpu, json!({}));
+
+        assert_eq!(chaos["kind"], "StressChaos");
+        assert_eq!(chaos["spec"]["stressors"], json!({"cpu": {"workers": 1, "load": 80}}));
+        assert!(chaos["spec"].get("containerNames").is_none());
+    }
+
+    #[test]
+    fn test_create_combined_memory_and_cpu_stress_chaos() {
+        let chaos = manifest(
+            ChaosType::StressMemory,
+            json!({
+                "workers": 2,
+                "size": "512MB",
+                "cpu": {"workers": 1, "load": 50},
+                "container_names": ["app-abc"]
+            }),
+        );
+
+        assert_eq!(chaos["spec"]["stressors"]["memory"], json!({"workers": 2, "size": "512MB"}));
+        assert_eq!(chaos["spec"]["stressors"]["cpu"], json!({"workers": 1, "load": 50}));
+        assert_eq!(chaos["spec"]["containerNames"], json!(["app-abc"]));
+    }
+
+    #[test]
+    fn test_create_container_kill_chaos() {
+        let chaos = manifest(ChaosType::ContainerKill, json!({"container_names": ["app-abc"]}));
+
+        assert_eq!(chaos["kind"], "PodChaos");
+        assert_eq!(chaos["spec"]["action"], "container-kill");
+        assert_eq!(chaos["spec"]["containerNames"], json!(["app-abc"]));
+    }
+
+    #[test]
+    fn test_create_pod_failure_chaos() {
+        let chaos = manifest_with(
+            ChaosType::PodFailure,
+            json!({}),
+            ManifestArgs {
+                duration: Some("30s"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chaos["spec"]["action"], "pod-failure");
+        assert_eq!(chaos["spec"]["duration"], "30s");
+        assert!(chaos["spec"].get("gracePeriod").is_none());
+    }
+
+    #[test]
+    fn test_create_http_delay_chaos() {
+        let chaos = manifest(
+            ChaosType::HttpDelay,
+            json!({
+                "delay": "2s",
+                "target": "response",
+                "port": 8080,
+                "path": "/api/*",
+                "headers": {"x-test": "1"}
+            }),
+        );
+
+        assert_eq!(chaos["kind"], "HTTPChaos");
+        assert_eq!(chaos["spec"]["target"], "Response");
+        assert_eq!(chaos["spec"]["delay"], "2s");
+        assert_eq!(chaos["spec"]["port"], 8080);
+        assert_eq!(chaos["spec"]["response_headers"], json!({"x-test": "1"}));
+        assert!(chaos["spec"].get("abort").is_none());
+    }
+
+    #[test]
+    fn test_create_http_replace_and_patch_chaos() {
+        let replace = manifest(
+            ChaosType::HttpReplace,
+            json!({"target": "response", "code": 503, "body": "down"}),
+        );
+
+        assert_eq!(replace["spec"]["replace"]["code"], 503);
+        assert_eq!(replace["spec"]["replace"]["body"], "ZG93bg==");
+
+        let patch = manifest(
+            ChaosType::HttpPatch,
+            json!({"headers": {"x-chaos": "yes"}, "body": {"degraded": true}}),
+        );
+
+        assert_eq!(patch["spec"]["target"], "Request");
+        assert_eq!(patch["spec"]["patch"]["headers"], json!([["x-chaos", "yes"]]));
+        assert_eq!(patch["spec"]["patch"]["body"]["type"], "JSON");
+        assert_eq!(patch["spec"]["patch"]["body"]["value"], "{\"degraded\":true}");
+    }
+
+    #[test]
+    fn test_create_io_fault_chaos() {
+        let chaos = manifest(
+            ChaosType::IoFault,
+            json!({"errno": 28, "volume_path": "/data", "container_names": ["app-abc"]}),
+        );
+
+        assert_eq!(chaos["kind"], "IOChaos");
+        assert_eq!(chaos["spec"]["action"], "fault");
+        assert_eq!(chaos["spec"]["errno"], 28);
+        assert_eq!(chaos["spec"]["volumePath"], "/data");
+        assert_eq!(chaos["spec"]["path"], "/data/**/*");
+        assert_eq!(chaos["spec"]["containerNames"], json!(["app-abc"]));
+    }
+
+    #[test]
+    fn test_create_io_mistake_and_attr_chaos() {
+        let mistake = manifest(
+            ChaosType::IoMistake,
+            json!({"filling": "zero", "volume_path": "/data/", "percent": 50}),
+        );
+
+        assert_eq!(mistake["spec"]["action"], "mistake");
+        assert_eq!(mistake["spec"]["mistake"]["filling"], "zero");
+        assert_eq!(mistake["spec"]["mistake"]["maxLength"], 10);
+        assert_eq!(mistake["spec"]["path"], "/data/**/*");
+        assert_eq!(mistake["spec"]["percent"], 50);
+
+        let attr = manifest(ChaosType::IoAttr, json!({"perm": 292, "volume_path": "/data"}));
+
+        assert_eq!(attr["spec"]["action"], "attrOverride");
+        assert_eq!(attr["spec"]["attr"], json!({"perm": 292}));
+        assert!(attr["spec"].get("methods").is_none());
+    }
+
+    #[test]
+    fn test_create_io_delay_chaos_paths() {
+        // Files under the mount when a volume is known
+        let chaos = manifest(ChaosType::IoDelay, json!({"delay": "50ms", "volume_path": "/data"}));
+        assert_eq!(chaos["spec"]["action"], "latency");
+        assert_eq!(chaos["spec"]["volumePath"], "/data");
+        assert_eq!(chaos["spec"]["path"], "/data/**/*");
+
+        // Whole filesystem of the pod otherwise
+        let chaos = manifest(ChaosType::IoDelay, json!({"delay": "50ms"}));
+        assert_eq!(chaos["spec"]["volumePath"], "/");
+        assert_eq!(chaos["spec"]["path"], "/");
+    }
+
+    #[test]
+    fn test_builders_reject_invalid_params() {
+        let build = |chaos_type: ChaosType, params: serde_json::Value| {
+            manifest_with(
+                chaos_type,
+                params,
+                ManifestArgs {
+                    target_node_id: Some("node-2"),
+                    ..Default::default()
+                },
+            )
+        };
+
+        // No default latency or loss is substituted
+        assert!(matches!(build(ChaosType::Delay, json!({})), Err(AppError::BadRequest(_))));
+        assert!(build(ChaosType::Loss, json!({"loss": 10})).is_err());
+        assert!(build(ChaosType::ClockSkew, json!(null)).is_err());
+        // Nor a volume for IOChaos kinds other than io-delay
+        assert!(build(ChaosType::IoFault, json!({"errno": 5})).is_err());
+        // Types without required params still accept none
+        assert!(build(ChaosType::Partition, json!(null)).is_ok());
+        assert!(build(ChaosType::StressCpu, json!({})).is_ok());
+    }
+
+    #[test]
+    fn test_create_reorder_chaos() {
+        let chaos = manifest_with(
+            ChaosType::Reorder,
+            json!({"reorder": "30", "gap": 5}),
+            ManifestArgs {
+                target_node_id: Some("node-2"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chaos["spec"]["action"], "delay");
+        assert_eq!(chaos["spec"]["delay"]["latency"], "10ms");
+        assert_eq!(chaos["spec"]["delay"]["reorder"], json!({"reorder": "30", "gap": 5}));
+    }
+
+    #[test]
+    fn test_create_flaky_link_chaos() {
+        let chaos = manifest_with(
+            ChaosType::FlakyLink,
+            json!({
+                "latency": "80ms",
+                "jitter": "20ms",
+                "loss": "2",
+                "duplicate": "1",
+                "reorder": "5",
+                "correlation": "25"
+            }),
+            ManifestArgs {
+                target_node_id: Some("node-2"),
+                direction: ChaosDirection::Both,
+                duration: Some("5m"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let spec = &chaos["spec"];
+        assert_eq!(chaos["kind"], "NetworkChaos");
+        assert_eq!(spec["action"], "netem");
+        assert_eq!(spec["delay"]["latency"], "80ms");
+        assert_eq!(spec["delay"]["jitter"], "20ms");
+        assert_eq!(spec["delay"]["reorder"]["reorder"], "5");
+        assert_eq!(spec["loss"], json!({"loss": "2", "correlation": "25"}));
+        assert_eq!(spec["duplicate"]["duplicate"], "1");
+        assert!(spec.get("corrupt").is_none());
+        assert!(spec.get("rate").is_none());
+        assert_eq!(spec["target"]["selector"]["labelSelectors"]["networksim.io/node"], "node-2");
+    }
+
+    #[test]
+    fn test_create_network_chaos_with_external_targets() {
+        let chaos = manifest_with(
+            ChaosType::Loss,
+            json!({"loss": "50"}),
+            ManifestArgs {
+                external_targets: vec!["10.20.0.0/16".to_string(), "api.example.com".to_string()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chaos["spec"]["externalTargets"], json!(["10.20.0.0/16", "api.example.com"]));
+        assert!(chaos["spec"].get("target").is_none());
+    }
+
+    #[test]
+    fn test_create_chaos_manifest_with_selection_mode() {
+        let chaos = manifest_with(
+            ChaosType::Delay,
+            json!({"latency": "100ms"}),
+            ManifestArgs {
+                target_node_id: Some("node-2"),
+                selection: PodSelection {
+                    selection_mode: SelectionMode::FixedPercent,
+                    selection_value: Some("50".to_string()),
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chaos["spec"]["mode"], "fixed-percent");
+        assert_eq!(chaos["spec"]["value"], "50");
+        // The selector has no mode of its own to conflict with it
+        assert!(chaos["spec"]["selector"].get("mode").is_none());
+        // The target side still selects every pod of the target node
+        assert_eq!(chaos["spec"]["target"]["mode"], "all");
+
+        let default = manifest(ChaosType::PodKill, json!({}));
+        assert_eq!(default["spec"]["mode"], "all");
+        assert!(default["spec"].get("value").is_none());
+    }
+
+    #[test]
+    fn test_create_schedule_manifest() {
+        let chaos = manifest_with(
+            ChaosType::Loss,
+            json!({"loss": "100"}),
+            ManifestArgs {
+                target_node_id: Some("node-2"),
+                duration: Some("5s"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let recurrence = ChaosRecurrence {
+            schedule: Some("*/2 * * * *".to_string()),
+            history_limit: Some(3),
+        };
+
+        let schedule = create_schedule_manifest(&chaos, &ChaosCrdKind::NetworkChaos, &recurrence);
+
+        assert_eq!(schedule["kind"], "Schedule");
+        assert_eq!(schedule["metadata"], chaos["metadata"]);
+        assert_eq!(schedule["spec"]["schedule"], "*/2 * * * *");
+        assert_eq!(schedule["spec"]["historyLimit"], 3);
+        assert_eq!(schedule["spec"]["concurrencyPolicy"], "Forbid");
+        assert_eq!(schedule["spec"]["type"], "NetworkChaos");
+        assert_eq!(schedule["spec"]["networkChaos"], chaos["spec"]);
+        assert_eq!(schedule["spec"]["networkChaos"]["duration"], "5s");
+    }
 }
@@ -0,0 +1,379 @@
+//! Conflict detection between chaos conditions
+//!
+//! Chaos Mesh injects network faults as tc rules on the pods of a node, per peer. Two
+//! conditions configuring the same fault on the same link overwrite each other (two delays
+//! on one pair leave a single delay), so a condition is checked against the active ones
+//! before it is started.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+use super::types::*;
+
+/// Two conditions that cannot be active together
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ChaosConflict {
+    /// Condition being started
+    pub condition_id: String,
+    /// Active condition it conflicts with
+    pub conflicting_id: String,
+    /// Why the faults cannot be combined, and on which link
+    pub reason: String,
+}
+
+/// A fault in effect on a node pair
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct EffectiveFault {
+    pub condition_id: String,
+    pub chaos_type: ChaosType,
+    pub params: serde_json::Value,
+}
+
+/// Faults in effect on the traffic of a node towards a peer
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct NodePairFaults {
+    /// Node whose pods carry the fault
+    pub source_node_id: String,
+    /// Peer node id or external target; `None` for faults on the node itself
+    /// (stress, pod, IO, HTTP, DNS and time chaos)
+    pub target: Option<String>,
+    pub faults: Vec<EffectiveFault>,
+    /// Faults of this pair that overwrite each other
+    pub conflicts: Vec<ChaosConflict>,
+}
+
+/// Other end of a link
+#[derive(Debug, Clone, Copy)]
+enum Peer<'a> {
+    /// Faults on the node itself (non-network chaos)
+    Itself,
+    /// Every peer (no target)
+    All,
+    Node(&'a str),
+    External(&'a [String]),
+}
+
+/// Traffic of the pods of `node` exchanged with `peer`
+#[derive(Debug, Clone, Copy)]
+struct Link<'a> {
+    node: &'a str,
+    peer: Peer<'a>,
+}
+
+impl std::fmt::Display for Link<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.peer {
+            Peer::Itself => write!(f, "{}", self.node),
+            Peer::All => write!(f, "{} → all", self.node),
+            Peer::Node(peer) => write!(f, "{} → {}", self.node, peer),
+            Peer::External(targets) => write!(f, "{} → {}", self.node, targets.join(", ")),
+        }
+    }
+}
+
+/// Links a condition injects faults on, following its direction
+fn links(condition: &ChaosCondition) -> Vec<Link<'_>> {
+    let source = condition.source_node_id.as_str();
+    if condition.chaos_type.crd_kind() != ChaosCrdKind::NetworkChaos {
+        return vec![Link {
+            node: source,
+            peer: Peer::Itself,
+        }];
+    }
+
+    let peer = match &condition.target_node_id {
+        Some(target) => Peer::Node(target),
+        None if !condition.external_targets.is_empty() => {
+            Peer::External(&condition.external_targets)
+        }
+        None => Peer::All,
+    };
+    let to = Link { node: source, peer };
+    // `from` is injected on the target pods, towards the source
+    let from = condition.target_node_id.as_deref().map(|target| Link {
+        node: target,
+        peer: Peer::Node(source),
+    });
+
+    match condition.direction {
+        ChaosDirection::To => vec![to],
+        ChaosDirection::From => vec![from.unwrap_or(to)],
+        ChaosDirection::Both => std::iter::once(to).chain(from).collect(),
+    }
+}
+
+/// Narrowest of two overlapping links, `None` if they do not overlap
+fn overlap<'a>(a: Link<'a>, b: Link<'a>) -> Option<Link<'a>> {
+    if a.node != b.node {
+        return None;
+    }
+    match (a.peer, b.peer) {
+        (Peer::Itself, Peer::Itself) => Some(a),
+        (Peer::Itself, _) | (_, Peer::Itself) => None,
+        (Peer::All, _) => Some(b),
+        (_, Peer::All) => Some(a),
+        (Peer::Node(x), Peer::Node(y)) if x == y => Some(a),
+        (Peer::External(x), Peer::External(y)) if x.iter().any(|t| y.contains(t)) => Some(a),
+        _ => None,
+    }
+}
+
+/// netem/tc setting a network chaos type configures
+fn network_action(chaos_type: &ChaosType) -> &'static str {
+    match chaos_type {
+        ChaosType::Delay | ChaosType::Reorder => "delay",
+        ChaosType::FlakyLink => "netem",
+        ChaosType::Loss => "loss",
+        ChaosType::Corrupt => "corrupt",
+        ChaosType::Duplicate => "duplicate",
+        ChaosType::Bandwidth => "bandwidth",
+        _ => "partition",
+    }
+}
+
+/// Why two faults on the same link cannot be combined, if they cannot
+fn incompatibility(a: &ChaosType, b: &ChaosType) -> Option<String> {
+    let kind = a.crd_kind();
+    if kind != b.crd_kind() {
+        return None;
+    }
+
+    match kind {
+        ChaosCrdKind::NetworkChaos => {
+            let (x, y) = (network_action(a), network_action(b));
+            if x == "partition" || y == "partition" {
+                let other = if x == "partition" { b } else { a };
+                Some(format!("partition drops all traffic, {} has no effect", other))
+            } else if x == y {
+                Some(format!("both configure the {} of", x))
+            } else if x == "netem" || y == "netem" {
+                let other = if x == "netem" { y } else { x };
+                let shaped = matches!(other, "delay" | "loss" | "corrupt" | "duplicate");
+                shaped.then(|| format!("flaky-link overrides the {} of", other))
+            } else {
+                None
+            }
+        }
+        // Stressors add up and pod faults are one-shot
+        ChaosCrdKind::StressChaos | ChaosCrdKind::PodChaos => None,
+        ChaosCrdKind::DNSChaos => Some("both replace the DNS responses of".to_string()),
+        _ if a == b => Some(format!("both inject {} on", a)),
+        _ => None,
+    }
+}
+
+/// Conflicts of `condition` with `active`, the conditions currently applied
+pub fn find_conflicts(condition: &ChaosCondition, active: &[ChaosCondition]) -> Vec<ChaosConflict> {
+    active
+        .iter()
+        .filter(|other| other.id != condition.id && other.topology_id == condition.topology_id)
+        .filter_map(|other| {
+            let reason = incompatibility(&condition.chaos_type, &other.chaos_type)?;
+            let link = links(condition)
+                .into_iter()
+                .flat_map(|a| links(other).into_iter().filter_map(move |b| overlap(a, b)))
+                .next()?;
+            Some(ChaosConflict {
+                condition_id: condition.id.clone(),
+                conflicting_id: other.id.clone(),
+                reason: describe(&reason, link),
+            })
+        })
+        .collect()
+}
+
+/// Partition reasons are complete sentences, the others end with the link
+fn describe(reason: &str, link: Link<'_>) -> String {
+    if reason.starts_with("partition") {
+        format!("{} ({})", reason, link)
+    } else {
+        format!("{} {}", reason, link)
+    }
+}
+
+/// Explanation of the conflicts of a condition, naming the conflicting conditions
+pub fn conflict_message(condition_id: &str, conflicts: &[ChaosConflict]) -> String {
+    let ids: Vec<&str> = conflicts.iter().map(|c| c.conflicting_id.as_str()).collect();
+    let reasons: Vec<&str> = conflicts.iter().map(|c| c.reason.as_str()).collect();
+    format!(
+        "Condition {} conflicts with active condition(s) {}: {}",
+        condition_id,
+        ids.join(", "),
+        reasons.join("; ")
+    )
+}
+
+/// Faults in effect per node pair, with the conflicts among them
+///
+/// Conditions without target apply to every other node of `node_ids`.
+pub fn effective_fault_set(node_ids: &[String], active: &[ChaosCondition]) -> Vec<NodePairFaults> {
+    let mut pairs: BTreeMap<(String, Option<String>), Vec<&ChaosCondition>> = BTreeMap::new();
+
+    for condition in active {
+        for link in links(condition) {
+            let peers: Vec<Option<String>> = match link.peer {
+                Peer::Itself => vec![None],
+                Peer::All => node_ids
+                    .iter()
+                    .filter(|n| n.as_str() != link.node)
+                    .map(|n| Some(n.clone()))
+                    .collect(),
+                Peer::Node(peer) => vec![Some(peer.to_string())],
+                Peer::External(targets) => targets.iter().map(|t| Some(t.clone())).collect(),
+            };
+            for peer in peers {
+                let faults = pairs.entry((link.node.to_string(), peer)).or_default();
+                if !faults.iter().any(|c| c.id == condition.id) {
+                    faults.push(condition);
+                }
+            }
+        }
+    }
+
+    pairs
+        .into_iter()
+        .map(|((source_node_id, target), conditions)| {
+            let link = Link {
+                node: &source_node_id,
+                peer: target.as_deref().map_or(Peer::Itself, Peer::Node),
+            };
+            let mut conflicts = Vec::new();
+            for (i, a) in conditions.iter().enumerate() {
+                for b in &conditions[i + 1..] {
+                    if let Some(reason) = incompatibility(&a.chaos_type, &b.chaos_type) {
+                        conflicts.push(ChaosConflict {
+                            condition_id: a.id.clone(),
+                            conflicting_id: b.id.clone(),
+                            reason: describe(&reason, link),
+                        });
+                    }
+                }
+            }
+
+            NodePairFaults {
+                faults: conditions
+                    .iter()
+                    .map(|c| EffectiveFault {
+                        condition_id: c.id.clone(),
+                        chaos_type: c.chaos_type.clone(),
+                        params: c.params.clone(),
+                    })
+                    .collect(),
+                source_node_id: source_node_id.clone(),
+                target,
+                conflicts,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn condition(
+        id: &str,
+        chaos_type: ChaosType,
+        source: &str,
+        target: Option<&str>,
+        direction: ChaosDirection,
+    ) -> ChaosCondition {
+        let now = Utc::now();
+        ChaosCondition {
+            id: id.to_string(),
+            topology_id: "topology-1".to_string(),
+            source_node_id: source.to_string(),
+            target_node_id: target.map(String::from),
+            external_targets: Vec::new(),
+            selection: PodSelection::default(),
+            recurrence: ChaosRecurrence::default(),
+            chaos_type,
+            direction,
+            duration: None,
+            params: json!({}),
+            status: ChaosConditionStatus::Active,
+            k8s_name: None,
+            started_at: None,
+            ends_at: None,
+            abort_criteria: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        use ChaosDirection::*;
+        let active = vec![
+            condition("d1", ChaosType::Delay, "node-1", Some("node-2"), To),
+            condition("l1", ChaosType::Loss, "node-1", None, To),
+            condition("s1", ChaosType::StressCpu, "node-1", None, To),
+        ];
+
+        // Second delay on the same pair
+        let conflicts =
+            find_conflicts(&condition("d2", ChaosType::Delay, "node-1", Some("node-2"), To), &active);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].conflicting_id, "d1");
+        assert_eq!(conflicts[0].reason, "both configure the delay of node-1 → node-2");
+
+        // Loss towards all peers overlaps any pair of node-1
+        let conflicts =
+            find_conflicts(&condition("l2", ChaosType::Loss, "node-1", Some("node-3"), To), &active);
+        assert_eq!(conflicts[0].conflicting_id, "l1");
+
+        // `from` is injected on node-2 towards node-1: no overlap with d1
+        let reverse = condition("d3", ChaosType::Delay, "node-1", Some("node-2"), From);
+        assert!(find_conflicts(&reverse, &active).is_empty());
+        let both = condition("d4", ChaosType::Delay, "node-2", Some("node-1"), Both);
+        assert_eq!(find_conflicts(&both, &active)[0].conflicting_id, "d1");
+
+        // Compatible faults, stressors add up
+        let corrupt = condition("c1", ChaosType::Corrupt, "node-1", Some("node-2"), To);
+        assert!(find_conflicts(&corrupt, &active).is_empty());
+        let stress = condition("s2", ChaosType::StressCpu, "node-1", None, To);
+        assert!(find_conflicts(&stress, &active).is_empty());
+
+        // Partition masks every other network fault
+        let partition = condition("p1", ChaosType::Partition, "node-1", Some("node-2"), To);
+        let ids: Vec<String> = find_conflicts(&partition, &active)
+            .into_iter()
+            .map(|c| c.conflicting_id)
+            .collect();
+        assert_eq!(ids, vec!["d1", "l1"]);
+        assert!(conflict_message("p1", &find_conflicts(&partition, &active))
+            .starts_with("Condition p1 conflicts with active condition(s) d1, l1: partition"));
+    }
+
+    #[test]
+    fn test_effective_fault_set() {
+        use ChaosDirection::*;
+        let nodes: Vec<String> = ["node-1", "node-2", "node-3"].map(String::from).to_vec();
+        let active = vec![
+            condition("d1", ChaosType::Delay, "node-1", Some("node-2"), To),
+            condition("d2", ChaosType::Delay, "node-1", None, To),
+            condition("s1", ChaosType::StressCpu, "node-3", None, To),
+        ];
+
+        let pairs = effective_fault_set(&nodes, &active);
+        assert_eq!(pairs.len(), 3);
+
+        let pair = |source: &str, target: Option<&str>| {
+            pairs
+                .iter()
+                .find(|p| p.source_node_id == source && p.target.as_deref() == target)
+                .unwrap()
+        };
+        let overwritten = pair("node-1", Some("node-2"));
+        assert_eq!(overwritten.faults.len(), 2);
+        assert_eq!(overwritten.conflicts.len(), 1);
+        assert_eq!(overwritten.conflicts[0].condition_id, "d1");
+        assert_eq!(overwritten.conflicts[0].conflicting_id, "d2");
+
+        assert!(pair("node-1", Some("node-3")).conflicts.is_empty());
+        assert_eq!(pair("node-3", None).faults[0].condition_id, "s1");
+    }
+}
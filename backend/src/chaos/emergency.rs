@@ -0,0 +1,117 @@
+//! Global emergency stop
+//!
+//! `stop_all` and `delete_all` work per topology, from the conditions in the database.
+//! The emergency stop removes every resource applied by NetworkSim, whatever its
+//! topology, namespace or kind and whether or not the database knows it, then pauses
+//! every active condition. Scenario runs are cancelled before the sweep, so none applies
+//! its next step afterwards.
+
+use std::collections::HashSet;
+use tracing::warn;
+
+use super::types::*;
+use crate::api::events::{emit_event, EventSeverity};
+use crate::api::{AppState, Event};
+use crate::error::AppResult;
+use crate::scenarios::cancel_runs;
+
+/// Result of an emergency stop
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+pub struct EmergencyStopReport {
+    /// Every resource found, with what happened to it
+    pub resources: Vec<StoppedResource>,
+    /// Resources that could not be removed, call again to retry
+    pub failed: usize,
+    /// Conditions that were active and are now paused
+    pub paused_conditions: Vec<String>,
+    /// Scenario runs that were cancelled
+    pub cancelled_runs: Vec<String>,
+}
+
+/// Remove all chaos applied by NetworkSim and pause every active condition
+///
+/// Idempotent: once everything is removed, a new call finds nothing to do.
+pub async fn emergency_stop(state: &AppState) -> AppResult<EmergencyStopReport> {
+    warn!("Emergency stop requested");
+
+    let cancelled_runs = cancel_runs(state, None).await?;
+    let mut resources = state.chaos.emergency_stop().await?;
+    let conditions = state.db.list_all_chaos_conditions().await?;
+
+    let known: HashSet<(&str, &str)> = conditions
+        .iter()
+        .map(|c| (c.topology_id.as_str(), c.id.as_str()))
+        .collect();
+    for resource in &mut resources {
+        resource.known = match (&resource.topology_id, &resource.condition_id) {
+            (Some(topology_id), Some(condition_id)) => {
+                known.contains(&(topology_id.as_str(), condition_id.as_str()))
+            }
+            _ => false,
+        };
+    }
+
+    let mut paused_conditions = Vec::new();
+    for condition in &conditions {
+        if condition.status != ChaosConditionStatus::Active {
+            continue;
+        }
+        state
+            .db
+            .update_chaos_condition_status(&condition.id, &ChaosConditionStatus::Paused, None)
+            .await?;
+        let _ = state.event_tx.send(Event::ChaosRemoved {
+            id: condition.id.clone(),
+        });
+        paused_conditions.push(condition.id.clone());
+    }
+
+    let failed = resources
+        .iter()
+        .filter(|r| r.outcome == StopOutcome::Failed)
+        .count();
+    let deleted = resources
+        .iter()
+        .filter(|r| r.outcome == StopOutcome::Deleted)
+        .count();
+
+    let _ = state.event_tx.send(Event::ChaosEmergencyStop {
+        deleted,
+        failed,
+        paused: paused_conditions.clone(),
+    });
+    emit_event(
+        state,
+        None,
+        "chaos",
+        Some("emergency_stop"),
+        if failed == 0 {
+            EventSeverity::Warning
+        } else {
+            EventSeverity::Error
+        },
+        "Emergency stop of all chaos",
+        Some(&format!(
+            "Removed {} resource(s), {} failed, paused {} condition(s), cancelled {} run(s)",
+            deleted,
+            failed,
+            paused_conditions.len(),
+            cancelled_runs.len()
+        )),
+        None,
+        None,
+        Some(serde_json::json!({
+            "resources": resources,
+            "paused_conditions": paused_conditions,
+            "cancelled_runs": cancelled_runs,
+        })),
+    )
+    .await;
+
+    Ok(EmergencyStopReport {
+        resources,
+        failed,
+        paused_conditions,
+        cancelled_runs,
+    })
+}
@@ -0,0 +1,135 @@
+//! Chaos condition expiry
+//!
+//! When a condition's `duration` elapses, Chaos Mesh stops injecting but keeps the
+//! resource, and the condition would read `active` until someone stops it. The expiry
+//! timer removes the resource of every active condition past its `ends_at` and marks the
+//! condition completed, or failed when the resource cannot be removed.
+//!
+//! The timer only reads the active conditions with a duration, and sleeps until the
+//! earliest `ends_at` (at most `EXPIRY_CHECK_INTERVAL_SECS`, so conditions started in the
+//! meantime are picked up).
+
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use super::types::*;
+use crate::api::events::{emit_event, EventSeverity, EventSourceType};
+use crate::api::{AppState, Event};
+use crate::error::AppResult;
+
+/// Longest interval between two expiry checks
+pub const EXPIRY_CHECK_INTERVAL_SECS: u64 = 5;
+
+/// Complete the active conditions whose duration elapsed, returning them
+pub async fn expire_chaos_conditions(state: &AppState) -> AppResult<Vec<ChaosCondition>> {
+    Ok(expire_due(state).await?.0)
+}
+
+/// Expire the due conditions, also returning the next `ends_at` still to come
+async fn expire_due(
+    state: &AppState,
+) -> AppResult<(Vec<ChaosCondition>, Option<DateTime<Utc>>)> {
+    let now = Utc::now();
+    let mut expired = Vec::new();
+    let mut next = None;
+
+    for mut condition in state.db.list_expiring_chaos_conditions().await? {
+        let Some(ends_at) = condition.ends_at else {
+            continue;
+        };
+        if ends_at > now {
+            next = Some(next.map_or(ends_at, |n: DateTime<Utc>| n.min(ends_at)));
+            continue;
+        }
+
+        let error = state
+            .chaos
+            .delete(&condition.topology_id, &condition.id)
+            .await
+            .err();
+        let status = match &error {
+            None => ChaosConditionStatus::Completed,
+            Some(e) => {
+                warn!("Failed to remove expired chaos {}: {}", condition.id, e);
+                ChaosConditionStatus::Failed
+            }
+        };
+
+        state
+            .db
+            .update_chaos_condition_status(&condition.id, &status, None)
+            .await?;
+        condition.status = status;
+        condition.k8s_name = None;
+
+        record_expiry(state, &condition, error.map(|e| e.to_string())).await;
+        expired.push(condition);
+    }
+
+    Ok((expired, next))
+}
+
+async fn record_expiry(state: &AppState, condition: &ChaosCondition, error: Option<String>) {
+    info!("Chaos condition {} {}", condition.id, condition.status);
+
+    let _ = state.event_tx.send(Event::ChaosExpired {
+        id: condition.id.clone(),
+        topology_id: condition.topology_id.clone(),
+        status: condition.status.to_string(),
+    });
+
+    let (severity, title) = match &error {
+        None => (
+            EventSeverity::Info,
+            format!("Chaos condition {} completed", condition.id),
+        ),
+        Some(_) => (
+            EventSeverity::Error,
+            format!("Chaos condition {} failed to stop", condition.id),
+        ),
+    };
+    emit_event(
+        state,
+        Some(&condition.topology_id),
+        "chaos",
+        Some(&condition.status.to_string()),
+        severity,
+        &title,
+        error.as_deref(),
+        Some(EventSourceType::Chaos),
+        Some(&condition.id),
+        Some(serde_json::json!({
+            "chaos_type": condition.chaos_type,
+            "duration": condition.duration,
+            "started_at": condition.started_at,
+            "ends_at": condition.ends_at,
+        })),
+    )
+    .await;
+}
+
+/// Check for expired conditions periodically, forever
+pub async fn start_chaos_expiry_timer(state: AppState) {
+    info!("Starting chaos expiry timer");
+
+    let max_wait = Duration::from_secs(EXPIRY_CHECK_INTERVAL_SECS);
+    loop {
+        let next = match expire_due(&state).await {
+            Ok((expired, next)) => {
+                if !expired.is_empty() {
+                    debug!("{} chaos conditions expired", expired.len());
+                }
+                next
+            }
+            Err(e) => {
+                warn!("Chaos expiry check failed: {}", e);
+                None
+            }
+        };
+        let wait = next
+            .and_then(|ends_at| (ends_at - Utc::now()).to_std().ok())
+            .map_or(max_wait, |until| until.min(max_wait));
+        tokio::time::sleep(wait).await;
+    }
+}
@@ -7,10 +7,44 @@
 //! - Packet corruption
 //! - Network partition
 
+mod backend;
 mod client;
 mod conditions;
+mod conflicts;
+mod emergency;
+mod expiry;
+mod reconciler;
+mod supervisor;
+mod tc;
 mod types;
+mod validation;
 
-pub use client::ChaosClient;
-pub use conditions::{create_network_chaos, ChaosAction};
+pub use backend::{
+    backend_from_config, ChaosBackend, ChaosMeshBackend, InMemoryChaosBackend, TC_BACKEND,
+};
+pub use client::{condition_id_from_name, ChaosClient};
+pub use conditions::{
+    chaos_resource_name, create_condition_manifest, create_network_chaos, create_schedule_manifest,
+    ChaosAction,
+};
+pub use conflicts::{
+    conflict_message, effective_fault_set, find_conflicts, ChaosConflict, EffectiveFault,
+    NodePairFaults,
+};
+pub use emergency::{emergency_stop, EmergencyStopReport};
+pub use expiry::{expire_chaos_conditions, start_chaos_expiry_timer, EXPIRY_CHECK_INTERVAL_SECS};
+pub use reconciler::{
+    reconcile_chaos, start_chaos_reconciler, ChaosCorrection, ChaosCorrectionAction,
+    RECONCILE_INTERVAL_SECS,
+};
+pub use supervisor::{
+    abort_reason, abort_topology, observe, start_chaos_supervisor, supervise_chaos,
+    AbortObservations, SUPERVISOR_INTERVAL_SECS,
+};
+pub use tc::TcBackend;
 pub use types::*;
+pub use validation::{
+    is_valid_cron, is_valid_duration, parse_duration, validate_abort_criteria,
+    validate_chaos_params, validate_duration, validate_external_targets, validate_recurrence,
+    validate_selection, ParamError,
+};
@@ -0,0 +1,245 @@
+//! Chaos state reconciler
+//!
+//! `chaos_conditions.status` drifts from the cluster when a resource expires through its
+//! `duration`, is deleted with kubectl, or is lost when the server restarts mid-scenario.
+//! The reconciler periodically compares the DB with the resources the chaos backend
+//! reports, fixes condition statuses and removes orphaned resources. A correction that
+//! fails is reported with its error and retried on the next pass, without stopping the
+//! others.
+
+use serde::Serialize;
+use std::collections::HashSet;
+use tracing::{debug, info, warn};
+use utoipa::ToSchema;
+
+use super::types::*;
+use crate::api::events::{emit_event, EventSeverity, EventSourceType};
+use crate::api::{AppState, Event};
+use crate::error::AppResult;
+
+/// Interval between two reconciliation passes
+pub const RECONCILE_INTERVAL_SECS: u64 = 30;
+
+/// What the reconciler changed
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChaosCorrectionAction {
+    /// Active condition without resource (deleted out of band): marked paused
+    MarkedPaused,
+    /// Active condition whose resource finished its duration: resource deleted, marked
+    /// completed
+    Expired,
+    /// Pending/paused condition with a running resource: marked active
+    Adopted,
+    /// Resource without condition in the DB: deleted
+    GarbageCollected,
+}
+
+impl std::fmt::Display for ChaosCorrectionAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChaosCorrectionAction::MarkedPaused => write!(f, "marked_paused"),
+            ChaosCorrectionAction::Expired => write!(f, "expired"),
+            ChaosCorrectionAction::Adopted => write!(f, "adopted"),
+            ChaosCorrectionAction::GarbageCollected => write!(f, "garbage_collected"),
+        }
+    }
+}
+
+/// A single correction made by the reconciler
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ChaosCorrection {
+    pub topology_id: String,
+    pub condition_id: String,
+    /// Resource involved, if any
+    pub resource: Option<String>,
+    pub action: ChaosCorrectionAction,
+    pub message: String,
+    /// Why the correction could not be applied, if it failed
+    pub error: Option<String>,
+}
+
+/// Run one reconciliation pass and return the corrections made
+pub async fn reconcile_chaos(state: &AppState) -> AppResult<Vec<ChaosCorrection>> {
+    let conditions = state.db.list_all_chaos_conditions().await?;
+    let resources = state.chaos.list_managed().await?;
+
+    let mut corrections = Vec::new();
+    let mut known = HashSet::new();
+
+    for condition in &conditions {
+        let resource = resources
+            .iter()
+            .find(|r| r.condition_id == condition.id && r.topology_id == condition.topology_id);
+        if let Some(r) = resource {
+            known.insert(r.name.clone());
+        }
+        let finished = resource.is_some_and(|r| r.phase == "Finished");
+        // Stopped recurring conditions keep their Schedule paused
+        let paused = resource.is_some_and(|r| r.phase == "Paused");
+
+        let action = match (&condition.status, resource) {
+            (ChaosConditionStatus::Active, None) => ChaosCorrectionAction::MarkedPaused,
+            (ChaosConditionStatus::Active, Some(_)) if finished => ChaosCorrectionAction::Expired,
+            (_, Some(_)) if finished => ChaosCorrectionAction::GarbageCollected,
+            (ChaosConditionStatus::Pending | ChaosConditionStatus::Paused, Some(_)) if paused => {
+                continue
+            }
+            (ChaosConditionStatus::Pending | ChaosConditionStatus::Paused, Some(_)) => {
+                ChaosCorrectionAction::Adopted
+            }
+            _ => continue,
+        };
+
+        let resource_name = resource.map(|r| r.name.clone());
+        let message = match action {
+            ChaosCorrectionAction::MarkedPaused => {
+                "Chaos resource no longer exists in the cluster".to_string()
+            }
+            ChaosCorrectionAction::Expired => "Chaos duration elapsed".to_string(),
+            ChaosCorrectionAction::Adopted => {
+                format!("Chaos resource is running (status was {})", condition.status)
+            }
+            ChaosCorrectionAction::GarbageCollected => {
+                "Removed finished chaos resource of an inactive condition".to_string()
+            }
+        };
+
+        let result = apply_correction(state, condition, &action, resource_name.as_deref()).await;
+        corrections.push(ChaosCorrection {
+            topology_id: condition.topology_id.clone(),
+            condition_id: condition.id.clone(),
+            resource: resource_name,
+            action,
+            message,
+            error: result.err().map(|e| e.to_string()),
+        });
+    }
+
+    // Resources whose condition was deleted (or never saved)
+    for resource in resources.iter().filter(|r| !known.contains(&r.name)) {
+        if resource.topology_id.is_empty() {
+            continue;
+        }
+        // Conditions created and started after the snapshot above (e.g. by a scenario
+        // step) already own their resource
+        let result = match state.db.get_chaos_condition(&resource.condition_id).await {
+            Ok(Some(c)) if c.topology_id == resource.topology_id => continue,
+            Ok(_) => {
+                state
+                    .chaos
+                    .delete(&resource.topology_id, &resource.condition_id)
+                    .await
+            }
+            Err(e) => Err(e.into()),
+        };
+        corrections.push(ChaosCorrection {
+            topology_id: resource.topology_id.clone(),
+            condition_id: resource.condition_id.clone(),
+            resource: Some(resource.name.clone()),
+            action: ChaosCorrectionAction::GarbageCollected,
+            message: "Removed chaos resource without condition".to_string(),
+            error: result.err().map(|e| e.to_string()),
+        });
+    }
+
+    for correction in &corrections {
+        record_correction(state, correction).await;
+    }
+
+    Ok(corrections)
+}
+
+/// Delete the resource and/or update the condition status for a correction
+async fn apply_correction(
+    state: &AppState,
+    condition: &ChaosCondition,
+    action: &ChaosCorrectionAction,
+    resource_name: Option<&str>,
+) -> AppResult<()> {
+    if matches!(
+        action,
+        ChaosCorrectionAction::Expired | ChaosCorrectionAction::GarbageCollected
+    ) {
+        state.chaos.delete(&condition.topology_id, &condition.id).await?;
+    }
+    let (status, k8s_name) = match action {
+        ChaosCorrectionAction::MarkedPaused => (ChaosConditionStatus::Paused, None),
+        ChaosCorrectionAction::Expired => (ChaosConditionStatus::Completed, None),
+        ChaosCorrectionAction::Adopted => (ChaosConditionStatus::Active, resource_name),
+        ChaosCorrectionAction::GarbageCollected => return Ok(()),
+    };
+    state
+        .db
+        .update_chaos_condition_status(&condition.id, &status, k8s_name)
+        .await?;
+    Ok(())
+}
+
+async fn record_correction(state: &AppState, correction: &ChaosCorrection) {
+    if let Some(error) = &correction.error {
+        warn!(
+            "Chaos reconciler: failed to {} condition {}: {}",
+            correction.action, correction.condition_id, error
+        );
+        // Not applied: retried on the next pass, so no event is recorded
+        return;
+    }
+    info!(
+        "Chaos reconciler: {} condition {} ({})",
+        correction.action, correction.condition_id, correction.message
+    );
+
+    let _ = state.event_tx.send(Event::ChaosReconciled {
+        id: correction.condition_id.clone(),
+        topology_id: correction.topology_id.clone(),
+        action: correction.action.to_string(),
+        message: correction.message.clone(),
+    });
+
+    emit_event(
+        state,
+        Some(&correction.topology_id),
+        "chaos",
+        Some("reconciled"),
+        EventSeverity::Warning,
+        &format!(
+            "Chaos condition {} reconciled: {}",
+            correction.condition_id, correction.action
+        ),
+        Some(&correction.message),
+        Some(EventSourceType::Chaos),
+        Some(&correction.condition_id),
+        Some(serde_json::json!({
+            "action": correction.action,
+            "resource": correction.resource,
+        })),
+    )
+    .await;
+}
+
+/// Reconcile periodically, forever
+pub async fn start_chaos_reconciler(state: AppState) {
+    info!(
+        "Starting chaos reconciler (every {}s)",
+        RECONCILE_INTERVAL_SECS
+    );
+
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(RECONCILE_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        match reconcile_chaos(&state).await {
+            Ok(corrections) if corrections.is_empty() => debug!("Chaos state in sync"),
+            Ok(corrections) => {
+                let failed = corrections.iter().filter(|c| c.error.is_some()).count();
+                info!(
+                    "Chaos reconciler made {} corrections ({} failed)",
+                    corrections.len() - failed,
+                    failed
+                );
+            }
+            Err(e) => warn!("Chaos reconciliation failed: {}", e),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Chaos supervisor
+//!
+//! Conditions and scenarios may declare `AbortCriteria` so chaos can run unattended. The
+//! supervisor evaluates the criteria of active conditions against live metrics, pod
+//! connectivity and application status, and when one trips it stops every chaos resource
+//! of the topology and records why.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+
+use super::types::*;
+use super::validation::parse_duration;
+use crate::api::events::{emit_event, EventSeverity, EventSourceType};
+use crate::api::live_metrics::measure_live_metrics;
+use crate::api::{AppState, Event};
+use crate::error::AppResult;
+use crate::models::AppStatus;
+use crate::scenarios::cancel_runs;
+
+/// Interval between two evaluations of the abort criteria
+pub const SUPERVISOR_INTERVAL_SECS: u64 = 10;
+
+/// Observations the abort criteria are evaluated against
+#[derive(Debug, Default)]
+pub struct AbortObservations {
+    /// `MetricsSummary.linked_blocked`, when measured
+    pub linked_blocked: Option<usize>,
+    /// Applications of the topology in `failed` status
+    pub failed_apps: Vec<String>,
+}
+
+/// Why the criteria trip, if they do
+pub fn abort_reason(
+    criteria: &AbortCriteria,
+    started_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    observations: &AbortObservations,
+) -> Option<String> {
+    if let (Some(max), Some(started_at)) = (criteria.max_duration.as_deref(), started_at) {
+        let elapsed = (now - started_at).to_std().unwrap_or_default();
+        if parse_duration(max).is_some_and(|max| elapsed >= max) {
+            return Some(format!("maximum duration of {} reached", max));
+        }
+    }
+
+    if criteria.abort_on_app_failure && !observations.failed_apps.is_empty() {
+        return Some(format!(
+            "application(s) failed: {}",
+            observations.failed_apps.join(", ")
+        ));
+    }
+
+    match (criteria.max_linked_blocked, observations.linked_blocked) {
+        (Some(max), Some(blocked)) if blocked > max => Some(format!(
+            "{} linked pairs blocked (maximum {})",
+            blocked, max
+        )),
+        _ => None,
+    }
+}
+
+/// Gather what the criteria need: apps only when asked for, connectivity only when needed
+pub async fn observe(
+    state: &AppState,
+    topology_id: &str,
+    criteria: &[&AbortCriteria],
+) -> AbortObservations {
+    let mut observations = AbortObservations::default();
+
+    if criteria.iter().any(|c| c.abort_on_app_failure) {
+        observations.failed_apps = state
+            .db
+            .list_applications(topology_id)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|app| app.status == AppStatus::Failed)
+            .map(|app| app.image_name)
+            .collect();
+    }
+
+    if criteria.iter().any(|c| c.max_linked_blocked.is_some()) {
+        match measure_live_metrics(state, topology_id).await {
+            Ok(snapshot) => observations.linked_blocked = Some(snapshot.summary.linked_blocked),
+            Err(e) => warn!("Supervisor could not measure topology {}: {}", topology_id, e),
+        }
+    }
+
+    observations
+}
+
+/// Stop all chaos of a topology, returning the conditions that were active
+///
+/// The scenario runs of the topology are cancelled first. `source_type`/`source_id` name
+/// what declared the criterion that tripped.
+pub async fn abort_topology(
+    state: &AppState,
+    topology_id: &str,
+    reason: &str,
+    source_type: EventSourceType,
+    source_id: &str,
+) -> AppResult<Vec<String>> {
+    warn!("Aborting chaos on topology {}: {}", topology_id, reason);
+
+    let cancelled_runs = cancel_runs(state, Some(topology_id)).await?;
+    state.chaos.cleanup_topology(topology_id).await?;
+
+    let mut stopped = Vec::new();
+    for condition in state.db.list_chaos_conditions(topology_id).await? {
+        if condition.status != ChaosConditionStatus::Active {
+            continue;
+        }
+        state
+            .db
+            .update_chaos_condition_status(&condition.id, &ChaosConditionStatus::Paused, None)
+            .await?;
+        let _ = state.event_tx.send(Event::ChaosRemoved {
+            id: condition.id.clone(),
+        });
+        stopped.push(condition.id);
+    }
+
+    let _ = state.event_tx.send(Event::ChaosAborted {
+        topology_id: topology_id.to_string(),
+        reason: reason.to_string(),
+        source_id: source_id.to_string(),
+    });
+    emit_event(
+        state,
+        Some(topology_id),
+        "chaos",
+        Some("aborted"),
+        EventSeverity::Error,
+        &format!("Chaos aborted: {}", reason),
+        Some(&format!(
+            "Abort criterion of {} {} tripped, stopped {} condition(s) and {} scenario run(s)",
+            source_type,
+            source_id,
+            stopped.len(),
+            cancelled_runs.len()
+        )),
+        Some(source_type),
+        Some(source_id),
+        Some(serde_json::json!({
+            "reason": reason,
+            "stopped": stopped,
+            "cancelled_runs": cancelled_runs,
+        })),
+    )
+    .await;
+
+    Ok(stopped)
+}
+
+/// Evaluate the abort criteria of every active condition once
+///
+/// Returns the aborted topologies with the reason.
+pub async fn supervise_chaos(state: &AppState) -> AppResult<Vec<(String, String)>> {
+    let mut by_topology: HashMap<String, Vec<ChaosCondition>> = HashMap::new();
+    for condition in state.db.list_all_chaos_conditions().await? {
+        if condition.status == ChaosConditionStatus::Active && condition.abort_criteria.is_some()
+        {
+            by_topology
+                .entry(condition.topology_id.clone())
+                .or_default()
+                .push(condition);
+        }
+    }
+
+    let now = Utc::now();
+    let mut aborted = Vec::new();
+    for (topology_id, conditions) in by_topology {
+        let criteria: Vec<&AbortCriteria> = conditions
+            .iter()
+            .filter_map(|c| c.abort_criteria.as_ref())
+            .collect();
+        let observations = observe(state, &topology_id, &criteria).await;
+
+        let tripped = conditions.iter().find_map(|c| {
+            let criteria = c.abort_criteria.as_ref()?;
+            abort_reason(criteria, c.started_at, now, &observations).map(|r| (c, r))
+        });
+        if let Some((condition, reason)) = tripped {
+            abort_topology(state, &topology_id, &reason, EventSourceType::Chaos, &condition.id)
+                .await?;
+            aborted.push((topology_id, reason));
+        }
+    }
+
+    Ok(aborted)
+}
+
+/// Supervise active conditions periodically, forever
+pub async fn start_chaos_supervisor(state: AppState) {
+    info!(
+        "Starting chaos supervisor (every {}s)",
+        SUPERVISOR_INTERVAL_SECS
+    );
+
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(SUPERVISOR_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        match supervise_chaos(&state).await {
+            Ok(aborted) if aborted.is_empty() => debug!("No abort criteria tripped"),
+            Ok(aborted) => info!("Chaos supervisor aborted {} topologies", aborted.len()),
+            Err(e) => warn!("Chaos supervision failed: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abort_reason() {
+        let now = Utc::now();
+        let started = now - chrono::Duration::seconds(90);
+        let criteria = AbortCriteria {
+            max_linked_blocked: Some(2),
+            abort_on_app_failure: true,
+            max_duration: Some("5m".to_string()),
+        };
+
+        let calm = AbortObservations {
+            linked_blocked: Some(2),
+            failed_apps: Vec::new(),
+        };
+        assert_eq!(abort_reason(&criteria, Some(started), now, &calm), None);
+
+        let blocked = AbortObservations {
+            linked_blocked: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            abort_reason(&criteria, Some(started), now, &blocked).as_deref(),
+            Some("3 linked pairs blocked (maximum 2)")
+        );
+
+        let failed = AbortObservations {
+            failed_apps: vec!["nginx:latest".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            abort_reason(&criteria, Some(started), now, &failed).as_deref(),
+            Some("application(s) failed: nginx:latest")
+        );
+
+        let expired = AbortCriteria {
+            max_duration: Some("1m".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            abort_reason(&expired, Some(started), now, &calm).as_deref(),
+            Some("maximum duration of 1m reached")
+        );
+        // Not measured: the connectivity criterion cannot trip
+        assert_eq!(
+            abort_reason(&criteria, None, now, &AbortObservations::default()),
+            None
+        );
+    }
+}
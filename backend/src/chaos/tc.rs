@@ -0,0 +1,916 @@
+//! Native tc/netem chaos backend
+//!
+//! Applies network conditions without Chaos Mesh by running `tc` in the `netem` sidecar
+//! that node pods get when the backend is selected (`CHAOS_BACKEND=tc`).
+//!
+//! Every pod gets an `htb` root qdisc whose default class lets traffic through untouched.
+//! A condition owns one slot on the pods it affects: an htb class (rate-limited for
+//! bandwidth), a qdisc under it and u32 filters sending the traffic towards the peer
+//! pods (or all traffic without a target node) to that class. The filters use the slot
+//! as priority, so a condition is removed without touching the others.
+//!
+//! The slot of a condition is also recorded as an annotation of each pod it is installed
+//! on, so the applied conditions are rebuilt from the pods after a server restart.
+
+use async_trait::async_trait;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{Api, AttachParams, ListParams, Patch, PatchParams},
+    Client,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{hash_map::Entry, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::AsyncReadExt;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::backend::ChaosBackend;
+use super::conditions::chaos_resource_name;
+use super::types::*;
+use super::validation::{parse_duration, validate_chaos_params};
+use crate::error::{AppError, AppResult};
+use crate::k8s::resources::TC_SIDECAR_CONTAINER;
+
+/// Interface shaped inside the pods
+const DEVICE: &str = "eth0";
+
+/// First slot handed to a condition (class 1:1 is the pass-through default)
+const FIRST_SLOT: u16 = 0x10;
+
+/// Rate of classes that only add netem impairments
+const UNLIMITED_RATE: &str = "10gbit";
+
+/// Prefix of the pod annotations recording a condition slot, followed by the resource name
+const SLOT_ANNOTATION_PREFIX: &str = "tc.networksim.io/";
+
+/// Value of a slot annotation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SlotRecord {
+    slot: u16,
+    condition_id: String,
+    chaos_type: ChaosType,
+}
+
+/// A condition applied by the tc backend
+#[derive(Debug, Clone)]
+struct TcApplied {
+    topology_id: String,
+    slot: u16,
+    /// Distinguishes re-applications of the same condition for the duration timer
+    generation: String,
+    status: ChaosStatus,
+}
+
+/// Pods of one side of a condition and the IPs of the pods they talk to
+#[derive(Debug, Clone, PartialEq)]
+struct TcSide {
+    node_id: String,
+    peer_node_id: Option<String>,
+}
+
+/// Native tc/netem backend
+#[derive(Clone)]
+pub struct TcBackend {
+    namespace: String,
+    applied: Arc<Mutex<HashMap<String, TcApplied>>>,
+    /// Whether `applied` was rebuilt from the pod annotations since the server started
+    loaded: Arc<AtomicBool>,
+}
+
+impl TcBackend {
+    pub fn new(namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            applied: Arc::default(),
+            loaded: Arc::default(),
+        }
+    }
+
+    /// Rebuild the conditions applied by a previous server run from the pod annotations
+    async fn load_applied(&self) -> AppResult<()> {
+        if self.loaded.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        let pods = self.pods_api().await?;
+        let lp = ListParams::default().labels("networksim.io/topology");
+        let found = applied_from_pods(&pods.list(&lp).await?.items);
+
+        let mut applied = self.applied.lock().unwrap();
+        for (name, entry) in found {
+            if let Entry::Vacant(vacant) = applied.entry(name) {
+                info!("Recovered tc condition {} in slot {:x}", vacant.key(), entry.slot);
+                vacant.insert(entry);
+            }
+        }
+        self.loaded.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    async fn pods_api(&self) -> AppResult<Api<Pod>> {
+        let client = Client::try_default()
+            .await
+            .map_err(|e| AppError::internal(&format!("Failed to create K8s client: {}", e)))?;
+        Ok(Api::namespaced(client, &self.namespace))
+    }
+
+    /// Running pods of a node as (name, IP)
+    ///
+    /// With `shaped`, only the pods with the `netem` sidecar tc runs in.
+    async fn node_pods(
+        &self,
+        pods: &Api<Pod>,
+        topology_id: &str,
+        node_id: &str,
+        shaped: bool,
+    ) -> AppResult<Vec<(String, String)>> {
+        let lp = ListParams::default().labels(&format!(
+            "networksim.io/topology={},networksim.io/node={}",
+            topology_id, node_id
+        ));
+
+        Ok(pods
+            .list(&lp)
+            .await?
+            .items
+            .into_iter()
+            .filter(|pod| !shaped || has_tc_sidecar(pod))
+            .filter_map(|pod| {
+                let ip = pod.status.and_then(|s| s.pod_ip)?;
+                Some((pod.metadata.name?, ip))
+            })
+            .collect())
+    }
+
+    /// Lowest slot not used by another applied condition
+    fn free_slot(applied: &HashMap<String, TcApplied>) -> AppResult<u16> {
+        (FIRST_SLOT..u16::MAX)
+            .find(|slot| !applied.values().any(|a| a.slot == *slot))
+            .ok_or_else(|| AppError::internal("No tc slot left"))
+    }
+}
+
+/// Whether a pod has the `netem` sidecar (application pods deployed before the tc backend
+/// was selected do not)
+fn has_tc_sidecar(pod: &Pod) -> bool {
+    pod.spec
+        .as_ref()
+        .is_some_and(|s| s.containers.iter().any(|c| c.name == TC_SIDECAR_CONTAINER))
+}
+
+/// Applied conditions recorded in the slot annotations of topology pods
+fn applied_from_pods(pods: &[Pod]) -> HashMap<String, TcApplied> {
+    let mut applied: HashMap<String, TcApplied> = HashMap::new();
+    for pod in pods.iter().filter(|p| has_tc_sidecar(p)) {
+        let pod_name = pod.metadata.name.clone().unwrap_or_default();
+        let topology_id = pod
+            .metadata
+            .labels
+            .as_ref()
+            .and_then(|l| l.get("networksim.io/topology"))
+            .cloned()
+            .unwrap_or_default();
+        let annotations = pod.metadata.annotations.iter().flatten();
+        for (key, value) in annotations {
+            let Some(name) = key.strip_prefix(SLOT_ANNOTATION_PREFIX) else {
+                continue;
+            };
+            let Ok(record) = serde_json::from_str::<SlotRecord>(value) else {
+                warn!("Ignoring invalid tc annotation {} on pod {}", key, pod_name);
+                continue;
+            };
+            let entry = applied.entry(name.to_string()).or_insert_with(|| TcApplied {
+                topology_id: topology_id.clone(),
+                slot: record.slot,
+                generation: Uuid::new_v4().to_string(),
+                status: ChaosStatus {
+                    name: name.to_string(),
+                    topology_id: topology_id.clone(),
+                    condition_id: record.condition_id.clone(),
+                    chaos_type: record.chaos_type.clone(),
+                    phase: "Running".to_string(),
+                    target_pods: Vec::new(),
+                    injected_pods: Vec::new(),
+                    selection_mode: SelectionMode::All,
+                    matched_pods: 0,
+                    message: None,
+                },
+            });
+            entry.status.target_pods.push(pod_name.clone());
+            entry.status.injected_pods.push(pod_name.clone());
+            entry.status.matched_pods += 1;
+        }
+    }
+    applied
+}
+
+/// Set (or with `None` remove) the slot annotation of a condition on a pod
+async fn annotate_slot(pods: &Api<Pod>, pod: &str, name: &str, record: Option<&SlotRecord>) {
+    let value = record.and_then(|r| serde_json::to_string(r).ok());
+    let patch = json!({
+        "metadata": {
+            "annotations": { format!("{}{}", SLOT_ANNOTATION_PREFIX, name): value }
+        }
+    });
+    if let Err(e) = pods
+        .patch(pod, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        warn!("Failed to record tc slot of {} on pod {}: {}", name, pod, e);
+    }
+}
+
+/// Remove every slot annotation of a pod, once its tc rules are reset
+async fn clear_slots(pods: &Api<Pod>, pod: &Pod) {
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+    let names = pod.metadata.annotations.iter().flatten().filter_map(|(key, _)| {
+        key.strip_prefix(SLOT_ANNOTATION_PREFIX)
+    });
+    for name in names {
+        annotate_slot(pods, &pod_name, name, None).await;
+    }
+}
+
+/// Sides of a condition: the node whose egress is shaped and the peer it sends to
+///
+/// netem only acts on egress, so ingress (`from`) is shaped on the target node.
+fn condition_sides(condition: &ChaosCondition) -> Vec<TcSide> {
+    let source = condition.source_node_id.clone();
+    let Some(target) = condition.target_node_id.clone() else {
+        return vec![TcSide {
+            node_id: source,
+            peer_node_id: None,
+        }];
+    };
+
+    let to = TcSide {
+        node_id: source.clone(),
+        peer_node_id: Some(target.clone()),
+    };
+    let from = TcSide {
+        node_id: target,
+        peer_node_id: Some(source),
+    };
+    match condition.direction {
+        ChaosDirection::To => vec![to],
+        ChaosDirection::From => vec![from],
+        ChaosDirection::Both => vec![to, from],
+    }
+}
+
+fn percent(value: &str) -> String {
+    format!("{}%", value.trim_end_matches('%'))
+}
+
+fn with_correlation(args: String, correlation: &Option<String>) -> String {
+    match correlation {
+        Some(c) => format!("{} {}", args, percent(c)),
+        None => args,
+    }
+}
+
+fn parse<T: serde::de::DeserializeOwned>(params: &Value) -> AppResult<T> {
+    serde_json::from_value(params.clone())
+        .map_err(|e| AppError::bad_request(&format!("Invalid params: {}", e)))
+}
+
+/// htb class rate and leaf qdisc of a condition
+///
+/// Params are rendered into a shell script, so they are validated here rather than
+/// trusting callers to have done it.
+fn class_and_qdisc(chaos_type: &ChaosType, params: &Value) -> AppResult<(String, String)> {
+    validate_chaos_params(chaos_type, params).map_err(|errors| {
+        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        AppError::validation(
+            &format!("Invalid chaos params: {}", messages.join("; ")),
+            json!(errors),
+        )
+    })?;
+    let netem = |args: String| Ok((UNLIMITED_RATE.to_string(), format!("netem {}", args)));
+
+    match chaos_type {
+        ChaosType::Delay => {
+            let p: DelayParams = parse(params)?;
+            let mut args = format!("delay {}", p.latency);
+            if let Some(jitter) = &p.jitter {
+                args = with_correlation(format!("{} {}", args, jitter), &p.correlation);
+            }
+            netem(args)
+        }
+        ChaosType::Loss => {
+            let p: LossParams = parse(params)?;
+            netem(with_correlation(format!("loss {}", percent(&p.loss)), &p.correlation))
+        }
+        ChaosType::Corrupt => {
+            let p: CorruptParams = parse(params)?;
+            netem(with_correlation(
+                format!("corrupt {}", percent(&p.corrupt)),
+                &p.correlation,
+            ))
+        }
+        ChaosType::Duplicate => {
+            let p: DuplicateParams = parse(params)?;
+            netem(with_correlation(
+                format!("duplicate {}", percent(&p.duplicate)),
+                &p.correlation,
+            ))
+        }
+        ChaosType::Partition => netem("loss 100%".to_string()),
+        ChaosType::Bandwidth => {
+            let p: BandwidthParams = parse(params)?;
+            let mut rate = format!("{} ceil {}", p.rate, p.rate);
+            if let Some(buffer) = p.buffer {
+                rate = format!("{} burst {}", rate, buffer);
+            }
+            let qdisc = match p.limit {
+                Some(limit) => format!("bfifo limit {}", limit),
+                None => "pfifo".to_string(),
+            };
+            Ok((rate, qdisc))
+        }
+        other => Err(AppError::bad_request(&format!(
+            "Chaos type '{}' is not supported by the tc backend",
+            other
+        ))),
+    }
+}
+
+/// Shell script installing a condition in `slot`, towards `peer_ips` (all traffic if empty)
+fn apply_script(slot: u16, rate: &str, qdisc: &str, peer_ips: &[String]) -> String {
+    let class = format!("1:{:x}", slot);
+    let mut lines = vec![
+        "set -e".to_string(),
+        format!(
+            "tc qdisc show dev {dev} | grep -q 'htb 1: root' || {{ \
+             tc qdisc replace dev {dev} root handle 1: htb default 1 && \
+             tc class replace dev {dev} parent 1: classid 1:1 htb rate {rate}; }}",
+            dev = DEVICE,
+            rate = UNLIMITED_RATE
+        ),
+        format!(
+            "tc class replace dev {} parent 1: classid {} htb rate {}",
+            DEVICE, class, rate
+        ),
+        format!(
+            "tc qdisc replace dev {} parent {} handle {:x}: {}",
+            DEVICE, class, slot, qdisc
+        ),
+    ];
+
+    let matches: Vec<String> = if peer_ips.is_empty() {
+        vec!["u32 0 0".to_string()]
+    } else {
+        peer_ips.iter().map(|ip| format!("ip dst {}/32", ip)).collect()
+    };
+    for m in matches {
+        lines.push(format!(
+            "tc filter add dev {} parent 1: protocol ip prio {} u32 match {} flowid {}",
+            DEVICE, slot, m, class
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Shell script removing the condition in `slot` (succeeds if it is not installed)
+fn remove_script(slot: u16) -> String {
+    format!(
+        "tc filter del dev {dev} parent 1: protocol ip prio {slot} u32 2>/dev/null; \
+         tc qdisc del dev {dev} parent 1:{slot:x} 2>/dev/null; \
+         tc class del dev {dev} classid 1:{slot:x} 2>/dev/null; true",
+        dev = DEVICE,
+        slot = slot
+    )
+}
+
+/// Run a shell script in the `tc` sidecar of a pod
+async fn exec_script(pods: &Api<Pod>, pod: &str, script: &str) -> AppResult<()> {
+    let ap = AttachParams::default()
+        .container(TC_SIDECAR_CONTAINER)
+        .stdout(false)
+        .stderr(true);
+    let command = vec!["sh".to_string(), "-c".to_string(), script.to_string()];
+
+    let mut attached = pods.exec(pod, command, &ap).await.map_err(|e| {
+        AppError::internal(&format!("Failed to exec tc in pod {}: {}", pod, e))
+    })?;
+
+    let mut stderr = String::new();
+    if let Some(mut reader) = attached.stderr() {
+        let _ = reader.read_to_string(&mut stderr).await;
+    }
+    let status = match attached.take_status() {
+        Some(status) => status.await,
+        None => None,
+    };
+    let _ = attached.join().await;
+
+    match status {
+        Some(s) if s.status.as_deref() != Some("Success") => Err(AppError::internal(&format!(
+            "tc failed in pod {}: {}",
+            pod,
+            if stderr.trim().is_empty() {
+                s.message.unwrap_or_default()
+            } else {
+                stderr.trim().to_string()
+            }
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[async_trait]
+impl ChaosBackend for TcBackend {
+    fn check_supported(&self, condition: &ChaosCondition) -> AppResult<()> {
+        class_and_qdisc(&condition.chaos_type, &condition.params)?;
+
+        if !condition.external_targets.is_empty() {
+            return Err(AppError::bad_request(
+                "external_targets are not supported by the tc backend",
+            ));
+        }
+        if condition.recurrence.schedule.is_some() {
+            return Err(AppError::bad_request(
+                "Recurring conditions are not supported by the tc backend",
+            ));
+        }
+        if condition.selection.selection_mode != SelectionMode::All {
+            return Err(AppError::bad_request(
+                "Selection modes are not supported by the tc backend",
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn create(&self, condition: &ChaosCondition) -> AppResult<String> {
+        self.check_supported(condition)?;
+        let (rate, qdisc) = class_and_qdisc(&condition.chaos_type, &condition.params)?;
+        // Slots of conditions applied before a restart are still taken
+        self.load_applied().await?;
+
+        let name = chaos_resource_name(&condition.topology_id, &condition.id);
+        let generation = Uuid::new_v4().to_string();
+        let slot = {
+            let mut applied = self.applied.lock().unwrap();
+            if applied.contains_key(&name) {
+                return Err(AppError::internal(&format!(
+                    "Failed to create chaos: {} already exists",
+                    name
+                )));
+            }
+            let slot = Self::free_slot(&applied)?;
+            // Reserve the slot while the pods are configured
+            applied.insert(
+                name.clone(),
+                TcApplied {
+                    topology_id: condition.topology_id.clone(),
+                    slot,
+                    generation: generation.clone(),
+                    status: ChaosStatus {
+                        name: name.clone(),
+                        topology_id: condition.topology_id.clone(),
+                        condition_id: condition.id.clone(),
+                        chaos_type: condition.chaos_type.clone(),
+                        phase: "Pending".to_string(),
+                        target_pods: Vec::new(),
+                        injected_pods: Vec::new(),
+                        selection_mode: SelectionMode::All,
+                        matched_pods: 0,
+                        message: None,
+                    },
+                },
+            );
+            slot
+        };
+
+        info!(
+            "Applying {} with tc in slot {:x} for condition {}",
+            condition.chaos_type, slot, condition.id
+        );
+
+        let result = async {
+            let pods = self.pods_api().await?;
+            let mut target_pods = Vec::new();
+            let mut injected_pods = Vec::new();
+            let mut errors = Vec::new();
+
+            for side in condition_sides(condition) {
+                let peer_ips: Vec<String> = match &side.peer_node_id {
+                    Some(peer) => self
+                        .node_pods(&pods, &condition.topology_id, peer, false)
+                        .await?
+                        .into_iter()
+                        .map(|(_, ip)| ip)
+                        .collect(),
+                    None => Vec::new(),
+                };
+                if side.peer_node_id.is_some() && peer_ips.is_empty() {
+                    errors.push(format!("no running pod for node {:?}", side.peer_node_id));
+                    continue;
+                }
+
+                let script = apply_script(slot, &rate, &qdisc, &peer_ips);
+                let record = SlotRecord {
+                    slot,
+                    condition_id: condition.id.clone(),
+                    chaos_type: condition.chaos_type.clone(),
+                };
+                for (pod, _) in self
+                    .node_pods(&pods, &condition.topology_id, &side.node_id, true)
+                    .await?
+                {
+                    target_pods.push(pod.clone());
+                    match exec_script(&pods, &pod, &script).await {
+                        Ok(()) => {
+                            annotate_slot(&pods, &pod, &name, Some(&record)).await;
+                            injected_pods.push(pod);
+                        }
+                        Err(e) => errors.push(e.to_string()),
+                    }
+                }
+            }
+
+            if injected_pods.is_empty() {
+                // Roll back partially configured pods
+                for pod in &target_pods {
+                    let _ = exec_script(&pods, pod, &remove_script(slot)).await;
+                }
+                return Err(AppError::internal(&format!(
+                    "Failed to create chaos: {}",
+                    if errors.is_empty() {
+                        "no running pod matches the condition".to_string()
+                    } else {
+                        errors.join("; ")
+                    }
+                )));
+            }
+
+            Ok((target_pods, injected_pods, errors))
+        }
+        .await;
+
+        let mut applied = self.applied.lock().unwrap();
+        let (target_pods, injected_pods, errors) = match result {
+            Ok(r) => r,
+            Err(e) => {
+                applied.remove(&name);
+                return Err(e);
+            }
+        };
+        if let Some(entry) = applied.get_mut(&name) {
+            entry.status.phase = "Running".to_string();
+            entry.status.matched_pods = target_pods.len();
+            entry.status.target_pods = target_pods;
+            entry.status.injected_pods = injected_pods;
+            entry.status.message = (!errors.is_empty()).then(|| errors.join("; "));
+        }
+        drop(applied);
+
+        // tc has no notion of duration: lift the condition when it elapses
+        if let Some(duration) = condition.duration.as_deref().and_then(parse_duration) {
+            let backend = self.clone();
+            let (topology_id, condition_id) = (condition.topology_id.clone(), condition.id.clone());
+            let name = name.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(duration).await;
+                let current = backend
+                    .applied
+                    .lock()
+                    .unwrap()
+                    .get(&name)
+                    .map(|a| a.generation.clone());
+                if current.as_deref() == Some(generation.as_str()) {
+                    if let Err(e) = backend.delete(&topology_id, &condition_id).await {
+                        warn!("Failed to lift expired tc condition {}: {}", condition_id, e);
+                    }
+                }
+            });
+        }
+
+        Ok(name)
+    }
+
+    async fn delete(&self, topology_id: &str, condition_id: &str) -> AppResult<()> {
+        self.load_applied().await?;
+        let name = chaos_resource_name(topology_id, condition_id);
+        let Some(entry) = self.applied.lock().unwrap().get(&name).cloned() else {
+            return Ok(());
+        };
+
+        let pods = self.pods_api().await?;
+        let script = remove_script(entry.slot);
+        for pod in &entry.status.injected_pods {
+            match exec_script(&pods, pod, &script).await {
+                Ok(()) => annotate_slot(&pods, pod, &name, None).await,
+                Err(e) => {
+                    warn!("Failed to remove tc condition {} from {}: {}", condition_id, pod, e)
+                }
+            }
+        }
+
+        self.applied.lock().unwrap().remove(&name);
+        info!("Removed tc condition: {}", name);
+        Ok(())
+    }
+
+    async fn list(&self, topology_id: &str) -> AppResult<Vec<ChaosStatus>> {
+        self.load_applied().await?;
+        let mut statuses: Vec<ChaosStatus> = self
+            .applied
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|a| a.topology_id == topology_id)
+            .map(|a| a.status.clone())
+            .collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(statuses)
+    }
+
+    async fn list_managed(&self) -> AppResult<Vec<ChaosStatus>> {
+        self.load_applied().await?;
+        let mut statuses: Vec<ChaosStatus> = self
+            .applied
+            .lock()
+            .unwrap()
+            .values()
+            .map(|a| a.status.clone())
+            .collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(statuses)
+    }
+
+    async fn cleanup_topology(&self, topology_id: &str) -> AppResult<()> {
+        info!("Resetting tc on all pods of topology {}", topology_id);
+
+        // Also clears rules left behind by a previous server run
+        let pods = self.pods_api().await?;
+        let lp = ListParams::default().labels(&format!("networksim.io/topology={}", topology_id));
+        let reset = format!("tc qdisc del dev {} root 2>/dev/null; true", DEVICE);
+        for pod in pods.list(&lp).await?.items {
+            if !has_tc_sidecar(&pod) {
+                continue;
+            }
+            let name = pod.metadata.name.clone().unwrap_or_default();
+            match exec_script(&pods, &name, &reset).await {
+                Ok(()) => clear_slots(&pods, &pod).await,
+                Err(e) => warn!("Failed to reset tc in pod {}: {}", name, e),
+            }
+        }
+
+        self.applied
+            .lock()
+            .unwrap()
+            .retain(|_, a| a.topology_id != topology_id);
+        Ok(())
+    }
+
+    async fn emergency_stop(&self) -> AppResult<Vec<StoppedResource>> {
+        warn!("Emergency stop: resetting tc on all topology pods");
+
+        // Reset every topology pod, not only those of known conditions, so rules left
+        // behind by a previous server run go too
+        let pods = self.pods_api().await?;
+        let lp = ListParams::default().labels("networksim.io/topology");
+        let reset = format!("tc qdisc del dev {} root 2>/dev/null; true", DEVICE);
+        let items = pods.list(&lp).await?.items;
+        // Conditions of a previous server run are only known from the pod annotations
+        let mut applied: Vec<TcApplied> = self.applied.lock().unwrap().values().cloned().collect();
+        for (name, entry) in applied_from_pods(&items) {
+            if !applied.iter().any(|a| a.status.name == name) {
+                applied.push(entry);
+            }
+        }
+
+        let mut stopped = Vec::new();
+        for pod in items.into_iter().filter(has_tc_sidecar) {
+            let name = pod.metadata.name.clone().unwrap_or_default();
+            let topology_id = pod
+                .metadata
+                .labels
+                .as_ref()
+                .and_then(|l| l.get("networksim.io/topology"))
+                .cloned();
+            let error = exec_script(&pods, &name, &reset)
+                .await
+                .err()
+                .map(|e| e.to_string());
+            if error.is_none() {
+                clear_slots(&pods, &pod).await;
+            }
+            // Pods carrying a known condition are reported once per condition
+            let mut conditions: Vec<Option<String>> = applied
+                .iter()
+                .filter(|a| a.status.injected_pods.contains(&name))
+                .map(|a| Some(a.status.condition_id.clone()))
+                .collect();
+            if conditions.is_empty() {
+                conditions.push(None);
+            }
+            for condition_id in conditions {
+                stopped.push(StoppedResource {
+                    kind: "tc".to_string(),
+                    namespace: Some(self.namespace.clone()),
+                    name: name.clone(),
+                    topology_id: topology_id.clone(),
+                    condition_id,
+                    outcome: match error {
+                        None => StopOutcome::Deleted,
+                        Some(_) => StopOutcome::Failed,
+                    },
+                    error: error.clone(),
+                    known: false,
+                });
+            }
+        }
+
+        self.applied.lock().unwrap().clear();
+        Ok(stopped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use k8s_openapi::api::core::v1::{Container, PodSpec};
+    use serde_json::json;
+
+    fn condition(chaos_type: ChaosType, direction: ChaosDirection, params: Value) -> ChaosCondition {
+        let now = Utc::now();
+        ChaosCondition {
+            id: "c1".to_string(),
+            topology_id: "topology-1234".to_string(),
+            source_node_id: "node-1".to_string(),
+            target_node_id: Some("node-2".to_string()),
+            external_targets: Vec::new(),
+            selection: PodSelection::default(),
+            recurrence: ChaosRecurrence::default(),
+            chaos_type,
+            direction,
+            duration: Some("30s".to_string()),
+            params,
+            status: ChaosConditionStatus::Pending,
+            k8s_name: None,
+            started_at: None,
+            ends_at: None,
+            abort_criteria: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_class_and_qdisc() {
+        let (rate, qdisc) = class_and_qdisc(
+            &ChaosType::Delay,
+            &json!({"latency": "100ms", "jitter": "10ms", "correlation": "25"}),
+        )
+        .unwrap();
+        assert_eq!(rate, UNLIMITED_RATE);
+        assert_eq!(qdisc, "netem delay 100ms 10ms 25%");
+
+        let (_, qdisc) = class_and_qdisc(&ChaosType::Loss, &json!({"loss": "50"})).unwrap();
+        assert_eq!(qdisc, "netem loss 50%");
+
+        let (_, qdisc) = class_and_qdisc(&ChaosType::Partition, &json!({})).unwrap();
+        assert_eq!(qdisc, "netem loss 100%");
+
+        let (rate, qdisc) = class_and_qdisc(
+            &ChaosType::Bandwidth,
+            &json!({"rate": "1mbps", "limit": 20000}),
+        )
+        .unwrap();
+        assert_eq!(rate, "1mbps ceil 1mbps");
+        assert_eq!(qdisc, "bfifo limit 20000");
+
+        assert!(class_and_qdisc(&ChaosType::StressCpu, &json!({})).is_err());
+
+        // Nothing unvalidated reaches the shell script
+        for (chaos_type, params) in [
+            (ChaosType::Delay, json!({"latency": "100ms; reboot"})),
+            (ChaosType::Delay, json!({"latency": "100ms", "jitter": "$(id)"})),
+            (ChaosType::Loss, json!({"loss": "50 && true"})),
+            (ChaosType::Bandwidth, json!({"rate": "1mbps`id`"})),
+        ] {
+            assert!(class_and_qdisc(&chaos_type, &params).is_err(), "{}", params);
+        }
+    }
+
+    #[test]
+    fn test_condition_sides() {
+        let to = condition(ChaosType::Loss, ChaosDirection::To, json!({"loss": "50"}));
+        assert_eq!(
+            condition_sides(&to),
+            vec![TcSide {
+                node_id: "node-1".to_string(),
+                peer_node_id: Some("node-2".to_string()),
+            }]
+        );
+
+        let from = condition(ChaosType::Loss, ChaosDirection::From, json!({"loss": "50"}));
+        assert_eq!(condition_sides(&from)[0].node_id, "node-2");
+
+        let both = condition(ChaosType::Loss, ChaosDirection::Both, json!({"loss": "50"}));
+        assert_eq!(condition_sides(&both).len(), 2);
+
+        let mut all = to.clone();
+        all.target_node_id = None;
+        assert_eq!(condition_sides(&all)[0].peer_node_id, None);
+    }
+
+    #[test]
+    fn test_tc_scripts() {
+        let script = apply_script(0x10, UNLIMITED_RATE, "netem loss 50%", &["10.0.0.7".to_string()]);
+        assert!(script.contains("tc class replace dev eth0 parent 1: classid 1:10 htb rate 10gbit"));
+        assert!(script.contains("tc qdisc replace dev eth0 parent 1:10 handle 10: netem loss 50%"));
+        assert!(script.contains("prio 16 u32 match ip dst 10.0.0.7/32 flowid 1:10"));
+
+        let script = apply_script(0x11, UNLIMITED_RATE, "netem loss 50%", &[]);
+        assert!(script.contains("u32 match u32 0 0 flowid 1:11"));
+
+        let script = remove_script(0x10);
+        assert!(script.contains("prio 16"));
+        assert!(script.contains("classid 1:10"));
+    }
+
+    #[test]
+    fn test_tc_backend_rejects_unsupported_conditions() {
+        let backend = TcBackend::new("networksim-sim");
+
+        let supported = condition(ChaosType::Delay, ChaosDirection::To, json!({"latency": "10ms"}));
+        assert!(backend.check_supported(&supported).is_ok());
+
+        let stress = condition(ChaosType::StressCpu, ChaosDirection::To, json!({}));
+        assert!(backend.check_supported(&stress).is_err());
+
+        let mut external = supported.clone();
+        external.external_targets = vec!["8.8.8.8".to_string()];
+        assert!(backend.check_supported(&external).is_err());
+
+        let mut scheduled = supported;
+        scheduled.recurrence.schedule = Some("@every 2m".to_string());
+        assert!(backend.check_supported(&scheduled).is_err());
+    }
+
+    fn pod(name: &str, sidecar: bool, annotations: &[(&str, &str)]) -> Pod {
+        let mut containers = vec![Container {
+            name: "app".to_string(),
+            ..Default::default()
+        }];
+        if sidecar {
+            containers.push(Container {
+                name: TC_SIDECAR_CONTAINER.to_string(),
+                ..Default::default()
+            });
+        }
+        let mut pod = Pod::default();
+        pod.metadata.name = Some(name.to_string());
+        pod.metadata.labels = Some(
+            [("networksim.io/topology".to_string(), "topology-1234".to_string())].into(),
+        );
+        pod.metadata.annotations = Some(
+            annotations
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        );
+        pod.spec = Some(PodSpec {
+            containers,
+            ..Default::default()
+        });
+        pod
+    }
+
+    #[test]
+    fn test_applied_from_pods() {
+        let key = format!("{}ns-topology-c1", SLOT_ANNOTATION_PREFIX);
+        let record = serde_json::to_string(&SlotRecord {
+            slot: 0x12,
+            condition_id: "c1".to_string(),
+            chaos_type: ChaosType::Delay,
+        })
+        .unwrap();
+        let pods = vec![
+            pod("node-1-a", true, &[(&key, &record)]),
+            pod("node-1-b", true, &[(&key, &record), ("other", "x")]),
+            // Annotations of pods without the sidecar are not tc state
+            pod("node-1-c", false, &[(&key, &record)]),
+            pod("node-2-a", true, &[(&format!("{}broken", SLOT_ANNOTATION_PREFIX), "{")]),
+        ];
+
+        let applied = applied_from_pods(&pods);
+        assert_eq!(applied.len(), 1);
+        let entry = &applied["ns-topology-c1"];
+        assert_eq!(entry.slot, 0x12);
+        assert_eq!(entry.topology_id, "topology-1234");
+        assert_eq!(entry.status.condition_id, "c1");
+        assert_eq!(entry.status.chaos_type, ChaosType::Delay);
+        assert_eq!(entry.status.injected_pods, vec!["node-1-a", "node-1-b"]);
+        assert_eq!(entry.status.matched_pods, 2);
+    }
+}
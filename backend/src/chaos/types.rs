@@ -2,8 +2,11 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 
+use super::validation::parse_duration;
+
 /// Types of chaos conditions that can be applied
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -21,19 +24,60 @@ pub enum ChaosType {
     Duplicate,
     /// Network partition (complete disconnect)
     Partition,
+    /// Packet reordering (NetworkChaos delay with reorder)
+    Reorder,
+    /// Degraded link: delay+jitter, loss, duplicate and reorder in one NetworkChaos
+    #[serde(rename = "flaky-link")]
+    FlakyLink,
     // ---- New chaos types ----
     /// CPU stress (StressChaos)
     #[serde(rename = "stress-cpu")]
     StressCpu,
+    /// Memory stress (StressChaos)
+    #[serde(rename = "stress-memory")]
+    StressMemory,
     /// Pod kill (PodChaos)
     #[serde(rename = "pod-kill")]
     PodKill,
+    /// Pod failure - pod unavailable for the duration (PodChaos)
+    #[serde(rename = "pod-failure")]
+    PodFailure,
+    /// Kill a named container inside the node's pods (PodChaos)
+    #[serde(rename = "container-kill")]
+    ContainerKill,
     /// I/O delay (IOChaos)
     #[serde(rename = "io-delay")]
     IoDelay,
+    /// I/O errors with a given errno (IOChaos)
+    #[serde(rename = "io-fault")]
+    IoFault,
+    /// Corrupted reads/writes (IOChaos)
+    #[serde(rename = "io-mistake")]
+    IoMistake,
+    /// File attribute override (IOChaos)
+    #[serde(rename = "io-attr")]
+    IoAttr,
     /// HTTP abort (HTTPChaos)
     #[serde(rename = "http-abort")]
     HttpAbort,
+    /// HTTP latency (HTTPChaos)
+    #[serde(rename = "http-delay")]
+    HttpDelay,
+    /// Replace parts of HTTP requests/responses (HTTPChaos)
+    #[serde(rename = "http-replace")]
+    HttpReplace,
+    /// Patch headers/body of HTTP requests/responses (HTTPChaos)
+    #[serde(rename = "http-patch")]
+    HttpPatch,
+    /// DNS resolution failure (DNSChaos)
+    #[serde(rename = "dns-error")]
+    DnsError,
+    /// Random DNS answers (DNSChaos)
+    #[serde(rename = "dns-random")]
+    DnsRandom,
+    /// Clock skew (TimeChaos)
+    #[serde(rename = "clock-skew")]
+    ClockSkew,
 }
 
 impl std::fmt::Display for ChaosType {
@@ -45,10 +89,24 @@ impl std::fmt::Display for ChaosType {
             ChaosType::Corrupt => write!(f, "corrupt"),
             ChaosType::Duplicate => write!(f, "duplicate"),
             ChaosType::Partition => write!(f, "partition"),
+            ChaosType::Reorder => write!(f, "reorder"),
+            ChaosType::FlakyLink => write!(f, "flaky-link"),
             ChaosType::StressCpu => write!(f, "stress-cpu"),
+            ChaosType::StressMemory => write!(f, "stress-memory"),
             ChaosType::PodKill => write!(f, "pod-kill"),
+            ChaosType::PodFailure => write!(f, "pod-failure"),
+            ChaosType::ContainerKill => write!(f, "container-kill"),
             ChaosType::IoDelay => write!(f, "io-delay"),
+            ChaosType::IoFault => write!(f, "io-fault"),
+            ChaosType::IoMistake => write!(f, "io-mistake"),
+            ChaosType::IoAttr => write!(f, "io-attr"),
             ChaosType::HttpAbort => write!(f, "http-abort"),
+            ChaosType::HttpDelay => write!(f, "http-delay"),
+            ChaosType::HttpReplace => write!(f, "http-replace"),
+            ChaosType::HttpPatch => write!(f, "http-patch"),
+            ChaosType::DnsError => write!(f, "dns-error"),
+            ChaosType::DnsRandom => write!(f, "dns-random"),
+            ChaosType::ClockSkew => write!(f, "clock-skew"),
         }
     }
 }
@@ -61,6 +119,36 @@ pub enum ChaosCrdKind {
     PodChaos,
     IOChaos,
     HTTPChaos,
+    DNSChaos,
+    TimeChaos,
+}
+
+impl ChaosCrdKind {
+    /// All CRD kinds managed by NetworkSim
+    pub fn all() -> &'static [ChaosCrdKind] {
+        &[
+            ChaosCrdKind::NetworkChaos,
+            ChaosCrdKind::StressChaos,
+            ChaosCrdKind::PodChaos,
+            ChaosCrdKind::IOChaos,
+            ChaosCrdKind::HTTPChaos,
+            ChaosCrdKind::DNSChaos,
+            ChaosCrdKind::TimeChaos,
+        ]
+    }
+
+    /// Field holding the embedded chaos spec in a Chaos Mesh `Schedule`
+    pub fn schedule_field(&self) -> &'static str {
+        match self {
+            ChaosCrdKind::NetworkChaos => "networkChaos",
+            ChaosCrdKind::StressChaos => "stressChaos",
+            ChaosCrdKind::PodChaos => "podChaos",
+            ChaosCrdKind::IOChaos => "ioChaos",
+            ChaosCrdKind::HTTPChaos => "httpChaos",
+            ChaosCrdKind::DNSChaos => "dnsChaos",
+            ChaosCrdKind::TimeChaos => "timeChaos",
+        }
+    }
 }
 
 impl ChaosType {
@@ -72,11 +160,22 @@ impl ChaosType {
             | ChaosType::Bandwidth
             | ChaosType::Corrupt
             | ChaosType::Duplicate
-            | ChaosType::Partition => ChaosCrdKind::NetworkChaos,
-            ChaosType::StressCpu => ChaosCrdKind::StressChaos,
-            ChaosType::PodKill => ChaosCrdKind::PodChaos,
-            ChaosType::IoDelay => ChaosCrdKind::IOChaos,
-            ChaosType::HttpAbort => ChaosCrdKind::HTTPChaos,
+            | ChaosType::Partition
+            | ChaosType::Reorder
+            | ChaosType::FlakyLink => ChaosCrdKind::NetworkChaos,
+            ChaosType::StressCpu | ChaosType::StressMemory => ChaosCrdKind::StressChaos,
+            ChaosType::PodKill | ChaosType::PodFailure | ChaosType::ContainerKill => {
+                ChaosCrdKind::PodChaos
+            }
+            ChaosType::IoDelay | ChaosType::IoFault | ChaosType::IoMistake | ChaosType::IoAttr => {
+                ChaosCrdKind::IOChaos
+            }
+            ChaosType::HttpAbort
+            | ChaosType::HttpDelay
+            | ChaosType::HttpReplace
+            | ChaosType::HttpPatch => ChaosCrdKind::HTTPChaos,
+            ChaosType::DnsError | ChaosType::DnsRandom => ChaosCrdKind::DNSChaos,
+            ChaosType::ClockSkew => ChaosCrdKind::TimeChaos,
         }
     }
 
@@ -147,8 +246,74 @@ pub struct DuplicateParams {
     pub correlation: Option<String>,
 }
 
+/// Parameters for packet reordering
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct ReorderParams {
+    /// Percentage of packets sent immediately, ahead of the delayed ones (e.g., "25")
+    pub reorder: String,
+    /// Correlation percentage
+    #[serde(default)]
+    pub correlation: Option<String>,
+    /// Reorder every Nth packet instead of randomly
+    #[serde(default)]
+    pub gap: Option<u32>,
+    /// Delay applied to the packets that are not reordered (default: "10ms")
+    #[serde(default)]
+    pub latency: Option<String>,
+}
+
+/// Parameters for a flaky link (several netem impairments at once)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct FlakyLinkParams {
+    /// Base latency (e.g., "80ms")
+    pub latency: String,
+    /// Random jitter around the latency (e.g., "20ms")
+    #[serde(default)]
+    pub jitter: Option<String>,
+    /// Loss percentage (e.g., "2")
+    #[serde(default)]
+    pub loss: Option<String>,
+    /// Duplication percentage (e.g., "1")
+    #[serde(default)]
+    pub duplicate: Option<String>,
+    /// Reorder percentage (e.g., "5")
+    #[serde(default)]
+    pub reorder: Option<String>,
+    /// Corruption percentage
+    #[serde(default)]
+    pub corrupt: Option<String>,
+    /// Rate limit (e.g., "2mbps")
+    #[serde(default)]
+    pub rate: Option<String>,
+    /// Correlation percentage applied to every impairment
+    #[serde(default)]
+    pub correlation: Option<String>,
+}
+
 // ---- New chaos type parameters ----
 
+/// CPU stressor settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct CpuStressor {
+    /// Number of CPU stress workers
+    #[serde(default)]
+    pub workers: Option<u32>,
+    /// CPU load percentage (0-100)
+    #[serde(default)]
+    pub load: Option<u32>,
+}
+
+/// Memory stressor settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct MemoryStressor {
+    /// Number of memory stress workers
+    #[serde(default)]
+    pub workers: Option<u32>,
+    /// Memory to allocate per worker (e.g., "256MB", "1GB", "50%")
+    #[serde(default)]
+    pub size: Option<String>,
+}
+
 /// Parameters for CPU stress (StressChaos)
 #[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 pub struct StressCpuParams {
@@ -158,6 +323,29 @@ pub struct StressCpuParams {
     /// CPU load percentage (0-100)
     #[serde(default)]
     pub load: Option<u32>,
+    /// Optional memory stressor applied by the same StressChaos
+    #[serde(default)]
+    pub memory: Option<MemoryStressor>,
+    /// Only stress these containers of the node's pods (default: all containers)
+    #[serde(default)]
+    pub container_names: Option<Vec<String>>,
+}
+
+/// Parameters for memory stress (StressChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct StressMemoryParams {
+    /// Number of memory stress workers
+    #[serde(default)]
+    pub workers: Option<u32>,
+    /// Memory to allocate per worker (e.g., "256MB", "1GB", "50%")
+    #[serde(default)]
+    pub size: Option<String>,
+    /// Optional CPU stressor applied by the same StressChaos
+    #[serde(default)]
+    pub cpu: Option<CpuStressor>,
+    /// Only stress these containers of the node's pods (default: all containers)
+    #[serde(default)]
+    pub container_names: Option<Vec<String>>,
 }
 
 /// Parameters for pod kill (PodChaos)
@@ -168,6 +356,20 @@ pub struct PodKillParams {
     pub grace_period: Option<i64>,
 }
 
+/// Parameters for pod failure (PodChaos)
+///
+/// The pod stays unavailable for the condition's duration; there is nothing
+/// else to configure.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct PodFailureParams {}
+
+/// Parameters for container kill (PodChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct ContainerKillParams {
+    /// Containers to kill (application containers are named `app-<app id>`)
+    pub container_names: Vec<String>,
+}
+
 /// Parameters for I/O delay (IOChaos)
 #[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 pub struct IoDelayParams {
@@ -182,6 +384,96 @@ pub struct IoDelayParams {
     /// I/O methods to affect (read, write, etc.)
     #[serde(default)]
     pub methods: Option<Vec<String>>,
+    /// Mount point of the volume to inject into (default: first volume of the node's apps)
+    #[serde(default)]
+    pub volume_path: Option<String>,
+    /// Containers that mount the volume (default: the app that owns it)
+    #[serde(default)]
+    pub container_names: Option<Vec<String>>,
+}
+
+/// Parameters for I/O fault (IOChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct IoFaultParams {
+    /// errno returned by failed operations (default: 5, EIO)
+    #[serde(default)]
+    pub errno: Option<u32>,
+    /// Files to affect (default: everything under `volume_path`)
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Percentage of operations to fail (0-100)
+    #[serde(default)]
+    pub percent: Option<u32>,
+    /// I/O methods to affect (read, write, etc.)
+    #[serde(default)]
+    pub methods: Option<Vec<String>>,
+    /// Mount point of the volume to inject into (default: first volume of the node's apps)
+    #[serde(default)]
+    pub volume_path: Option<String>,
+    /// Containers that mount the volume (default: the app that owns it)
+    #[serde(default)]
+    pub container_names: Option<Vec<String>>,
+}
+
+/// Parameters for I/O mistake (IOChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct IoMistakeParams {
+    /// How to fill corrupted bytes: "zero" or "random" (default: "random")
+    #[serde(default)]
+    pub filling: Option<String>,
+    /// Maximum number of corrupted ranges per operation (default: 1)
+    #[serde(default)]
+    pub max_occurrences: Option<u32>,
+    /// Maximum length in bytes of each corrupted range (default: 10)
+    #[serde(default)]
+    pub max_length: Option<u32>,
+    /// Files to affect (default: everything under `volume_path`)
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Percentage of operations to corrupt (0-100)
+    #[serde(default)]
+    pub percent: Option<u32>,
+    /// I/O methods to affect (default: read and write)
+    #[serde(default)]
+    pub methods: Option<Vec<String>>,
+    /// Mount point of the volume to inject into (default: first volume of the node's apps)
+    #[serde(default)]
+    pub volume_path: Option<String>,
+    /// Containers that mount the volume (default: the app that owns it)
+    #[serde(default)]
+    pub container_names: Option<Vec<String>>,
+}
+
+/// Parameters for I/O attribute override (IOChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct IoAttrParams {
+    /// File permissions to report (decimal, e.g. 292 for 0o444)
+    #[serde(default)]
+    pub perm: Option<u16>,
+    /// File size in bytes to report
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// Owner uid to report
+    #[serde(default)]
+    pub uid: Option<u32>,
+    /// Owner gid to report
+    #[serde(default)]
+    pub gid: Option<u32>,
+    /// Hard link count to report
+    #[serde(default)]
+    pub nlink: Option<u32>,
+    /// Files to affect (default: everything under `volume_path`)
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Percentage of operations to affect (0-100)
+    #[serde(default)]
+    pub percent: Option<u32>,
+    /// Mount point of the volume to inject into (default: first volume of the node's apps)
+    #[serde(default)]
+    pub volume_path: Option<String>,
+    /// Containers that mount the volume (default: the app that owns it)
+    #[serde(default)]
+    pub container_names: Option<Vec<String>>,
 }
 
 /// Parameters for HTTP abort (HTTPChaos)
@@ -201,6 +493,124 @@ pub struct HttpAbortParams {
     pub port: Option<u16>,
 }
 
+/// Which side of an HTTP exchange HTTPChaos acts on
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HttpTarget {
+    /// Intercept requests received by the node
+    #[default]
+    Request,
+    /// Intercept responses sent by the node
+    Response,
+}
+
+impl HttpTarget {
+    /// Value of the HTTPChaos `target` field
+    pub fn as_chaos_mesh(&self) -> &'static str {
+        match self {
+            HttpTarget::Request => "Request",
+            HttpTarget::Response => "Response",
+        }
+    }
+}
+
+/// Parameters for HTTP delay (HTTPChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct HttpDelayParams {
+    /// Latency to add (e.g., "500ms", "2s")
+    pub delay: String,
+    /// Intercept requests or responses (default: request)
+    #[serde(default)]
+    pub target: HttpTarget,
+    /// HTTP method to match (GET, POST, etc.)
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Path pattern to match (e.g., "/api/*")
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Port to intercept
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Only match requests/responses carrying these headers
+    #[serde(default)]
+    pub headers: Option<BTreeMap<String, String>>,
+}
+
+/// Parameters for HTTP replace (HTTPChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct HttpReplaceParams {
+    /// Intercept requests or responses (default: request)
+    #[serde(default)]
+    pub target: HttpTarget,
+    /// HTTP method to match (GET, POST, etc.)
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Path pattern to match (e.g., "/api/*")
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Port to intercept
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Status code to return instead (response target only)
+    #[serde(default)]
+    pub code: Option<u16>,
+    /// Header values to overwrite
+    #[serde(default)]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// Body to send instead of the original one
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// Parameters for HTTP patch (HTTPChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct HttpPatchParams {
+    /// Intercept requests or responses (default: request)
+    #[serde(default)]
+    pub target: HttpTarget,
+    /// HTTP method to match (GET, POST, etc.)
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Path pattern to match (e.g., "/api/*")
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Port to intercept
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Headers to append
+    #[serde(default)]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// JSON object merged into the body (JSON merge patch)
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub body: Option<serde_json::Value>,
+}
+
+/// Parameters for DNS chaos (DNSChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct DnsChaosParams {
+    /// Domain patterns to affect (e.g., "google.com", "chaos-mesh.*").
+    /// Defaults to the target node's service name when a target is set
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// DNS action ("error" or "random"). Defaults to the one implied by the chaos type
+    #[serde(default)]
+    pub action: Option<String>,
+}
+
+/// Parameters for clock skew (TimeChaos)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct TimeChaosParams {
+    /// Offset applied to the clock (e.g., "-10m", "1h30m", "500ms")
+    pub time_offset: String,
+    /// Clocks to skew (e.g., "CLOCK_REALTIME", "CLOCK_MONOTONIC"). Default: CLOCK_REALTIME
+    #[serde(default)]
+    pub clock_ids: Option<Vec<String>>,
+    /// Only skew these containers of the node's pods (default: all containers)
+    #[serde(default)]
+    pub container_names: Option<Vec<String>>,
+}
+
 /// Union of all chaos parameters
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(untagged)]
@@ -212,11 +622,24 @@ pub enum ChaosParams {
     Bandwidth(BandwidthParams),
     Corrupt(CorruptParams),
     Duplicate(DuplicateParams),
+    Reorder(ReorderParams),
+    FlakyLink(FlakyLinkParams),
     // New chaos type params
     StressCpu(StressCpuParams),
+    StressMemory(StressMemoryParams),
     PodKill(PodKillParams),
+    ContainerKill(ContainerKillParams),
     IoDelay(IoDelayParams),
+    IoFault(IoFaultParams),
+    IoMistake(IoMistakeParams),
+    IoAttr(IoAttrParams),
     HttpAbort(HttpAbortParams),
+    HttpDelay(HttpDelayParams),
+    HttpReplace(HttpReplaceParams),
+    HttpPatch(HttpPatchParams),
+    DnsChaos(DnsChaosParams),
+    ClockSkew(TimeChaosParams),
+    PodFailure(PodFailureParams),
     /// Empty for partition and other types without params
     #[default]
     None,
@@ -245,6 +668,71 @@ impl std::fmt::Display for ChaosDirection {
     }
 }
 
+/// How many of the matched pods Chaos Mesh injects the fault into
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SelectionMode {
+    /// Every matched pod
+    #[default]
+    All,
+    /// One random pod
+    One,
+    /// A fixed number of random pods (`selection_value` = count)
+    Fixed,
+    /// A fixed percentage of the pods (`selection_value` = 1-100)
+    FixedPercent,
+    /// A random percentage of the pods, up to `selection_value` (1-100)
+    RandomMaxPercent,
+}
+
+impl std::fmt::Display for SelectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectionMode::All => write!(f, "all"),
+            SelectionMode::One => write!(f, "one"),
+            SelectionMode::Fixed => write!(f, "fixed"),
+            SelectionMode::FixedPercent => write!(f, "fixed-percent"),
+            SelectionMode::RandomMaxPercent => write!(f, "random-max-percent"),
+        }
+    }
+}
+
+impl std::str::FromStr for SelectionMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(SelectionMode::All),
+            "one" => Ok(SelectionMode::One),
+            "fixed" => Ok(SelectionMode::Fixed),
+            "fixed-percent" => Ok(SelectionMode::FixedPercent),
+            "random-max-percent" => Ok(SelectionMode::RandomMaxPercent),
+            _ => Err(format!("Unknown selection mode: {}", s)),
+        }
+    }
+}
+
+/// Blast radius of a chaos condition within the source node's pods
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, ToSchema)]
+pub struct PodSelection {
+    /// Which of the node's pods to inject into (default: all)
+    #[serde(default)]
+    pub selection_mode: SelectionMode,
+    /// Pod count for `fixed`, percentage for `fixed-percent` and `random-max-percent`
+    #[serde(default)]
+    pub selection_value: Option<String>,
+}
+
+/// Cron recurrence of a chaos condition (wrapped in a Chaos Mesh `Schedule`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, ToSchema)]
+pub struct ChaosRecurrence {
+    /// Cron expression (e.g. "*/2 * * * *" or "@every 2m") - if not set, runs once
+    #[serde(default)]
+    pub schedule: Option<String>,
+    /// Number of finished runs kept by the Schedule
+    #[serde(default)]
+    pub history_limit: Option<u32>,
+}
+
 /// Request to create a chaos condition
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateChaosRequest {
@@ -255,6 +743,15 @@ pub struct CreateChaosRequest {
     /// Target node ID (optional - if not set, applies to all traffic)
     #[serde(default)]
     pub target_node_id: Option<String>,
+    /// IPs, CIDRs or domains outside the topology to target (NetworkChaos only)
+    #[serde(default)]
+    pub external_targets: Option<Vec<String>>,
+    /// Pod selection mode and value (default: all pods of the node)
+    #[serde(flatten)]
+    pub selection: PodSelection,
+    /// Cron schedule and history limit (default: one-shot)
+    #[serde(flatten)]
+    pub recurrence: ChaosRecurrence,
     /// Type of chaos to apply
     pub chaos_type: ChaosType,
     /// Direction of traffic to affect
@@ -265,6 +762,9 @@ pub struct CreateChaosRequest {
     pub duration: Option<String>,
     /// Parameters specific to the chaos type
     pub params: serde_json::Value,
+    /// Stop all chaos of the topology when one of these trips
+    #[serde(default)]
+    pub abort_criteria: Option<AbortCriteria>,
 }
 
 /// Request to update a chaos condition (only editable fields)
@@ -273,11 +773,55 @@ pub struct UpdateChaosRequest {
     /// Direction of traffic to affect
     #[serde(default)]
     pub direction: ChaosDirection,
+    /// Replace the external targets (omit to keep the current ones)
+    #[serde(default)]
+    pub external_targets: Option<Vec<String>>,
+    /// Replace the pod selection mode (omit to keep the current selection)
+    #[serde(default)]
+    pub selection_mode: Option<SelectionMode>,
+    /// Value for the new selection mode
+    #[serde(default)]
+    pub selection_value: Option<String>,
+    /// Replace the cron schedule (omit to keep it, empty string to make it one-shot)
+    #[serde(default)]
+    pub schedule: Option<String>,
+    /// History limit for the new schedule
+    #[serde(default)]
+    pub history_limit: Option<u32>,
     /// Duration (e.g., "60s", "5m") - if not set, runs until deleted
     #[serde(default)]
     pub duration: Option<String>,
     /// Parameters specific to the chaos type
     pub params: serde_json::Value,
+    /// Replace the abort criteria (omit to keep the current ones, `null` to remove them)
+    #[serde(default, deserialize_with = "deserialize_some")]
+    #[schema(value_type = Option<AbortCriteria>)]
+    pub abort_criteria: Option<Option<AbortCriteria>>,
+}
+
+/// Safety guardrails: when one trips, the chaos supervisor stops all chaos of the topology
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AbortCriteria {
+    /// Abort when more linked pairs than this are blocked (`MetricsSummary.linked_blocked`)
+    #[serde(default)]
+    pub max_linked_blocked: Option<usize>,
+    /// Abort when an application of the topology goes `failed`
+    #[serde(default)]
+    pub abort_on_app_failure: bool,
+    /// Abort this long after the start (e.g. "10m"), whatever the duration
+    #[serde(default)]
+    #[schema(example = "10m")]
+    pub max_duration: Option<String>,
+}
+
+/// Deserialize a present field as `Some`, so an explicit `null` is told apart from an
+/// omitted field (with `#[serde(default)]` on an `Option<Option<T>>`)
+pub fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
 }
 
 /// Status of a chaos condition in the system
@@ -291,6 +835,10 @@ pub enum ChaosConditionStatus {
     Active,
     /// Paused (removed from K8s but saved in DB)
     Paused,
+    /// Duration elapsed, removed from K8s
+    Completed,
+    /// Could not be injected or removed
+    Failed,
 }
 
 impl std::fmt::Display for ChaosConditionStatus {
@@ -299,6 +847,8 @@ impl std::fmt::Display for ChaosConditionStatus {
             ChaosConditionStatus::Pending => write!(f, "pending"),
             ChaosConditionStatus::Active => write!(f, "active"),
             ChaosConditionStatus::Paused => write!(f, "paused"),
+            ChaosConditionStatus::Completed => write!(f, "completed"),
+            ChaosConditionStatus::Failed => write!(f, "failed"),
         }
     }
 }
@@ -310,6 +860,8 @@ impl std::str::FromStr for ChaosConditionStatus {
             "pending" => Ok(ChaosConditionStatus::Pending),
             "active" => Ok(ChaosConditionStatus::Active),
             "paused" => Ok(ChaosConditionStatus::Paused),
+            "completed" => Ok(ChaosConditionStatus::Completed),
+            "failed" => Ok(ChaosConditionStatus::Failed),
             _ => Err(format!("Unknown status: {}", s)),
         }
     }
@@ -326,6 +878,15 @@ pub struct ChaosCondition {
     pub source_node_id: String,
     /// Target node ID (if specific)
     pub target_node_id: Option<String>,
+    /// IPs, CIDRs or domains outside the topology
+    #[serde(default)]
+    pub external_targets: Vec<String>,
+    /// Pod selection mode and value
+    #[serde(flatten)]
+    pub selection: PodSelection,
+    /// Cron schedule and history limit
+    #[serde(flatten)]
+    pub recurrence: ChaosRecurrence,
     /// Type of chaos
     pub chaos_type: ChaosType,
     /// Direction
@@ -334,26 +895,61 @@ pub struct ChaosCondition {
     pub duration: Option<String>,
     /// Parameters
     pub params: serde_json::Value,
+    /// Safety guardrails evaluated by the chaos supervisor while active
+    #[serde(default)]
+    pub abort_criteria: Option<AbortCriteria>,
     /// Kubernetes resource name (when active)
     #[serde(default)]
     pub k8s_name: Option<String>,
-    /// Current status: pending, active, paused
+    /// Current status: pending, active, paused, completed, failed
     #[serde(default)]
     pub status: ChaosConditionStatus,
     /// When the chaos was started (activated) - for countdown timer
     #[serde(default)]
     pub started_at: Option<DateTime<Utc>>,
+    /// When an active condition is expected to end (`started_at + duration`)
+    #[serde(default)]
+    pub ends_at: Option<DateTime<Utc>>,
     /// When created
     pub created_at: DateTime<Utc>,
     /// When last updated
     pub updated_at: DateTime<Utc>,
 }
 
+impl ChaosCondition {
+    /// Expected end of the condition: `started_at + duration`
+    ///
+    /// `None` when not started, without duration, or for recurring conditions, whose
+    /// duration applies to each run of the schedule.
+    pub fn expected_end(&self) -> Option<DateTime<Utc>> {
+        if self.recurrence.schedule.is_some() {
+            return None;
+        }
+        let duration = parse_duration(self.duration.as_deref()?)?;
+        Some(self.started_at? + chrono::Duration::from_std(duration).ok()?)
+    }
+}
+
+/// Injection state of one pod/container reported by Chaos Mesh (`containerRecords`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PodInjection {
+    pub pod: String,
+    /// Container, for container-level records (`namespace/pod/container`)
+    pub container: Option<String>,
+    /// Record phase (`Injected`, `Not Injected`)
+    pub phase: String,
+    /// Error of the last operation, if it failed
+    pub error: Option<String>,
+}
+
 /// Status of a chaos condition from Kubernetes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChaosStatus {
     /// K8s resource name
     pub name: String,
+    /// Topology of the resource (`networksim.io/topology` label)
+    #[serde(default)]
+    pub topology_id: String,
     /// Condition ID extracted from name
     pub condition_id: String,
     /// Type of chaos
@@ -362,6 +958,46 @@ pub struct ChaosStatus {
     pub phase: String,
     /// Target pods affected
     pub target_pods: Vec<String>,
+    /// Pods where Chaos Mesh reports the fault as injected
+    #[serde(default)]
+    pub injected_pods: Vec<String>,
+    /// Pod selection mode of the resource
+    #[serde(default)]
+    pub selection_mode: SelectionMode,
+    /// Number of pods matched by the selector after applying the selection mode
+    #[serde(default)]
+    pub matched_pods: usize,
     /// Status message
     pub message: Option<String>,
 }
+
+/// What happened to a resource removed by an emergency stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StopOutcome {
+    Deleted,
+    /// Removed meanwhile, e.g. a chaos garbage collected with its Schedule
+    AlreadyGone,
+    Failed,
+}
+
+/// A resource removed by an emergency stop
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StoppedResource {
+    /// CRD kind (`NetworkChaos`, `Schedule`...), `tc` for the rules of a pod
+    #[schema(example = "NetworkChaos")]
+    pub kind: String,
+    /// Namespace of the resource, when the backend works across namespaces
+    pub namespace: Option<String>,
+    /// Resource name, or pod name for tc rules
+    pub name: String,
+    /// `networksim.io/topology` label
+    pub topology_id: Option<String>,
+    /// Condition id, when the name follows `ns-{topology}-{condition}`
+    pub condition_id: Option<String>,
+    pub outcome: StopOutcome,
+    pub error: Option<String>,
+    /// Whether a condition of the database matches the resource
+    #[serde(default)]
+    pub known: bool,
+}
@@ -0,0 +1,1269 @@
+//! Server-side validation of chaos parameters
+//!
+//! Builders reject params they cannot render with a single error; anything
+//! user-supplied is checked here first, reporting every invalid field, before it is
+//! stored or applied.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::types::*;
+
+/// A single invalid field in chaos params
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamError {
+    /// Path of the offending field (e.g., "params.method")
+    pub field: String,
+    /// Human-readable reason
+    pub message: String,
+}
+
+impl ParamError {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// HTTP methods accepted by HTTPChaos matchers
+const HTTP_METHODS: &[&str] = &[
+    "GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS", "CONNECT", "TRACE",
+];
+
+/// Clocks that TimeChaos can skew
+const CLOCK_IDS: &[&str] = &[
+    "CLOCK_REALTIME",
+    "CLOCK_MONOTONIC",
+    "CLOCK_PROCESS_CPUTIME_ID",
+    "CLOCK_THREAD_CPUTIME_ID",
+    "CLOCK_MONOTONIC_RAW",
+    "CLOCK_REALTIME_COARSE",
+    "CLOCK_MONOTONIC_COARSE",
+    "CLOCK_BOOTTIME",
+    "CLOCK_REALTIME_ALARM",
+    "CLOCK_BOOTTIME_ALARM",
+];
+
+/// Params of chaos types that take none (partition)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NoParams {}
+
+/// Predefined cron schedules accepted by Chaos Mesh
+const CRON_DESCRIPTORS: &[&str] = &[
+    "@yearly", "@annually", "@monthly", "@weekly", "@daily", "@midnight", "@hourly",
+];
+
+/// Filesystem methods accepted by IOChaos
+const IO_METHODS: &[&str] = &[
+    "lookup",
+    "forget",
+    "getattr",
+    "setattr",
+    "readlink",
+    "mknod",
+    "mkdir",
+    "unlink",
+    "rmdir",
+    "symlink",
+    "rename",
+    "link",
+    "open",
+    "read",
+    "write",
+    "statfs",
+    "release",
+    "fsync",
+    "setxattr",
+    "getxattr",
+    "listxattr",
+    "removexattr",
+    "flush",
+    "opendir",
+    "readdir",
+    "releasedir",
+    "fsyncdir",
+    "access",
+    "create",
+    "getlk",
+    "setlk",
+    "bmap",
+];
+
+/// Validate the params of a chaos type, collecting every invalid field
+pub fn validate_chaos_params(
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> Result<(), Vec<ParamError>> {
+    match chaos_type.crd_kind() {
+        ChaosCrdKind::NetworkChaos => validate_network_params(chaos_type, params),
+        ChaosCrdKind::StressChaos => validate_stress_params(chaos_type, params),
+        ChaosCrdKind::PodChaos => validate_pod_params(chaos_type, params),
+        ChaosCrdKind::HTTPChaos => validate_http_params(chaos_type, params),
+        ChaosCrdKind::IOChaos => validate_io_params(chaos_type, params),
+        ChaosCrdKind::DNSChaos => validate_dns_params(params),
+        ChaosCrdKind::TimeChaos => validate_time_params(params),
+    }
+}
+
+/// Validate external targets: each must be an IP address, a CIDR or a domain name
+pub fn validate_external_targets(targets: &[String]) -> Result<(), Vec<ParamError>> {
+    let errors: Vec<ParamError> = targets
+        .iter()
+        .enumerate()
+        .filter(|(_, target)| !is_valid_external_target(target))
+        .map(|(i, target)| {
+            ParamError::new(
+                &format!("external_targets[{}]", i),
+                format!("'{}' is not an IP address, CIDR or domain name", target),
+            )
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the abort criteria of a condition or scenario
+pub fn validate_abort_criteria(criteria: &AbortCriteria) -> Result<(), Vec<ParamError>> {
+    match criteria.max_duration.as_deref() {
+        Some(max) if parse_duration(max).is_none() => Err(vec![ParamError::new(
+            "abort_criteria.max_duration",
+            format!("'{}' is not a valid duration (e.g. 30s, 5m, 1h)", max),
+        )]),
+        _ => Ok(()),
+    }
+}
+
+/// Validate the duration of a condition
+///
+/// Without a parseable duration a condition never expires, and Chaos Mesh rejects
+/// the manifest only when it is applied.
+pub fn validate_duration(duration: Option<&str>) -> Result<(), Vec<ParamError>> {
+    match duration {
+        Some(d) if parse_duration(d).is_none() => Err(vec![ParamError::new(
+            "duration",
+            format!("'{}' is not a valid duration (e.g. 30s, 5m, 1h)", d),
+        )]),
+        _ => Ok(()),
+    }
+}
+
+/// Validate the Chaos Mesh `mode`/`value` pair of a pod selection
+pub fn validate_selection(selection: &PodSelection) -> Result<(), Vec<ParamError>> {
+    let value = selection.selection_value.as_deref();
+    let error = match selection.selection_mode {
+        SelectionMode::All | SelectionMode::One => value.map(|_| {
+            format!("must be omitted for mode '{}'", selection.selection_mode)
+        }),
+        SelectionMode::Fixed => match value.map(|v| v.parse::<u32>()) {
+            Some(Ok(n)) if n > 0 => None,
+            _ => Some("must be a positive pod count for mode 'fixed'".to_string()),
+        },
+        SelectionMode::FixedPercent | SelectionMode::RandomMaxPercent => {
+            match value.map(|v| v.parse::<u32>()) {
+                Some(Ok(n)) if (1..=100).contains(&n) => None,
+                _ => Some(format!(
+                    "must be an integer percentage between 1 and 100 for mode '{}'",
+                    selection.selection_mode
+                )),
+            }
+        }
+    };
+
+    match error {
+        Some(message) => Err(vec![ParamError::new("selection_value", message)]),
+        None => Ok(()),
+    }
+}
+
+/// Validate the cron schedule of a recurring condition
+///
+/// Every run must end before the next firing, so scheduled conditions need a
+/// duration unless the chaos type is instantaneous (pod-kill, container-kill).
+pub fn validate_recurrence(
+    chaos_type: &ChaosType,
+    duration: Option<&str>,
+    recurrence: &ChaosRecurrence,
+) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+
+    match recurrence.schedule {
+        Some(ref schedule) => {
+            if !is_valid_cron(schedule) {
+                errors.push(ParamError::new(
+                    "schedule",
+                    format!("'{}' is not a cron expression or @every interval", schedule),
+                ));
+            }
+            let instantaneous = matches!(chaos_type, ChaosType::PodKill | ChaosType::ContainerKill);
+            if duration.is_none() && !instantaneous {
+                errors.push(ParamError::new(
+                    "duration",
+                    format!("is required for scheduled {} chaos", chaos_type),
+                ));
+            }
+            if recurrence.history_limit == Some(0) {
+                errors.push(ParamError::new("history_limit", "must be at least 1"));
+            }
+        }
+        None => {
+            if recurrence.history_limit.is_some() {
+                errors.push(ParamError::new("history_limit", "requires a schedule"));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns true for 5-field cron expressions, descriptors and `@every <duration>`
+pub fn is_valid_cron(expr: &str) -> bool {
+    let expr = expr.trim();
+    if let Some(interval) = expr.strip_prefix("@every ") {
+        return is_valid_duration(interval.trim());
+    }
+    if expr.starts_with('@') {
+        return CRON_DESCRIPTORS.contains(&expr);
+    }
+
+    let fields: Vec<&str> = expr.split_whitespace().collect();
+    fields.len() == 5
+        && fields.iter().all(|field| {
+            field
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '*' | ',' | '/' | '-' | '?'))
+        })
+}
+
+fn is_valid_external_target(target: &str) -> bool {
+    if target.parse::<std::net::IpAddr>().is_ok() {
+        return true;
+    }
+
+    if let Some((addr, prefix)) = target.split_once('/') {
+        let max_prefix = match addr.parse::<std::net::IpAddr>() {
+            Ok(std::net::IpAddr::V4(_)) => 32,
+            Ok(std::net::IpAddr::V6(_)) => 128,
+            Err(_) => return false,
+        };
+        return prefix.parse::<u8>().is_ok_and(|p| p <= max_prefix);
+    }
+
+    // Domain name: dot-separated labels of letters, digits and inner hyphens
+    target.len() <= 253
+        && target.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        // All-numeric names are malformed IPs, not domains
+        && !target.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
+/// Returns true for Go-style durations as accepted by Chaos Mesh (e.g., "100ms", "1m30s")
+pub fn is_valid_duration(value: &str) -> bool {
+    let mut rest = value.strip_prefix('-').unwrap_or(value);
+    if rest.is_empty() {
+        return false;
+    }
+
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        if number_len == 0 || rest[..number_len].parse::<f64>().is_err() {
+            return false;
+        }
+        rest = &rest[number_len..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        if !matches!(
+            &rest[..unit_len],
+            "ns" | "us" | "µs" | "ms" | "s" | "m" | "h"
+        ) {
+            return false;
+        }
+        rest = &rest[unit_len..];
+    }
+
+    true
+}
+
+/// Parse a Go-style duration (e.g., "1m30s"); negative durations give `None`
+pub fn parse_duration(value: &str) -> Option<std::time::Duration> {
+    if value.starts_with('-') || !is_valid_duration(value) {
+        return None;
+    }
+
+    let mut seconds = 0.0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_len].parse().ok()?;
+        rest = &rest[number_len..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let unit_seconds = match &rest[..unit_len] {
+            "ns" => 1e-9,
+            "us" | "µs" => 1e-6,
+            "ms" => 1e-3,
+            "s" => 1.0,
+            "m" => 60.0,
+            _ => 3600.0,
+        };
+        seconds += number * unit_seconds;
+        rest = &rest[unit_len..];
+    }
+
+    Some(std::time::Duration::from_secs_f64(seconds))
+}
+
+/// Returns true for netem rates as accepted by Chaos Mesh (e.g., "1mbps", "512kbit")
+pub fn is_valid_rate(value: &str) -> bool {
+    let number_len = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    number_len > 0
+        && matches!(
+            value[number_len..].to_ascii_lowercase().as_str(),
+            "bps" | "kbps" | "mbps" | "gbps" | "tbps" | "bit" | "kbit" | "mbit" | "gbit" | "tbit"
+        )
+}
+
+/// Returns true for memory sizes accepted by the StressChaos memory stressor (e.g., "256MB", "50%")
+pub fn is_valid_memory_size(value: &str) -> bool {
+    if let Some(percent) = value.strip_suffix('%') {
+        return percent.parse::<u32>().is_ok_and(|p| (1..=100).contains(&p));
+    }
+
+    let number_len = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    number_len > 0
+        && value[..number_len].parse::<u64>().is_ok_and(|n| n > 0)
+        && matches!(
+            value[number_len..].to_ascii_uppercase().as_str(),
+            "B" | "KB" | "MB" | "GB" | "TB" | "KIB" | "MIB" | "GIB" | "TIB"
+        )
+}
+
+/// Validate the params of a NetworkChaos type
+pub fn validate_network_params(
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+
+    match chaos_type {
+        ChaosType::Delay => {
+            let p: DelayParams = parse_params(params)?;
+            check_latency(&mut errors, "params.latency", &Some(p.latency));
+            check_latency(&mut errors, "params.jitter", &p.jitter);
+            check_percentage(&mut errors, "params.correlation", &p.correlation);
+        }
+        ChaosType::Loss => {
+            let p: LossParams = parse_params(params)?;
+            check_percentage(&mut errors, "params.loss", &Some(p.loss));
+            check_percentage(&mut errors, "params.correlation", &p.correlation);
+        }
+        ChaosType::Bandwidth => {
+            let p: BandwidthParams = parse_params(params)?;
+            check_rate(&mut errors, &Some(p.rate));
+            if p.buffer == Some(0) {
+                errors.push(ParamError::new("params.buffer", "must be at least 1 byte"));
+            }
+            if p.limit == Some(0) {
+                errors.push(ParamError::new("params.limit", "must be at least 1 byte"));
+            }
+        }
+        ChaosType::Corrupt => {
+            let p: CorruptParams = parse_params(params)?;
+            check_percentage(&mut errors, "params.corrupt", &Some(p.corrupt));
+            check_percentage(&mut errors, "params.correlation", &p.correlation);
+        }
+        ChaosType::Duplicate => {
+            let p: DuplicateParams = parse_params(params)?;
+            check_percentage(&mut errors, "params.duplicate", &Some(p.duplicate));
+            check_percentage(&mut errors, "params.correlation", &p.correlation);
+        }
+        ChaosType::Partition => {
+            let _: NoParams = parse_params(params)?;
+        }
+        ChaosType::Reorder => {
+            let p: ReorderParams = parse_params(params)?;
+            check_percentage(&mut errors, "params.reorder", &Some(p.reorder));
+            check_percentage(&mut errors, "params.correlation", &p.correlation);
+            check_latency(&mut errors, "params.latency", &p.latency);
+        }
+        ChaosType::FlakyLink => {
+            let p: FlakyLinkParams = parse_params(params)?;
+            check_latency(&mut errors, "params.latency", &Some(p.latency));
+            check_latency(&mut errors, "params.jitter", &p.jitter);
+            check_percentage(&mut errors, "params.loss", &p.loss);
+            check_percentage(&mut errors, "params.duplicate", &p.duplicate);
+            check_percentage(&mut errors, "params.reorder", &p.reorder);
+            check_percentage(&mut errors, "params.corrupt", &p.corrupt);
+            check_percentage(&mut errors, "params.correlation", &p.correlation);
+            check_rate(&mut errors, &p.rate);
+        }
+        _ => {}
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the params of a StressChaos type (stress-cpu, stress-memory)
+pub fn validate_stress_params(
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+
+    match chaos_type {
+        ChaosType::StressCpu => {
+            let p: StressCpuParams = parse_params(params)?;
+            check_cpu_stressor(&mut errors, "params", p.workers, p.load);
+            if let Some(memory) = p.memory {
+                check_memory_stressor(&mut errors, "params.memory", memory.workers, &memory.size);
+            }
+        }
+        ChaosType::StressMemory => {
+            let p: StressMemoryParams = parse_params(params)?;
+            check_memory_stressor(&mut errors, "params", p.workers, &p.size);
+            if let Some(cpu) = p.cpu {
+                check_cpu_stressor(&mut errors, "params.cpu", cpu.workers, cpu.load);
+            }
+        }
+        _ => {}
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the params of a PodChaos type (pod-kill, pod-failure, container-kill)
+pub fn validate_pod_params(
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+
+    match chaos_type {
+        ChaosType::PodKill => {
+            let p: PodKillParams = parse_params(params)?;
+            if p.grace_period.is_some_and(|g| g < 0) {
+                errors.push(ParamError::new(
+                    "params.grace_period",
+                    "must be 0 or more seconds",
+                ));
+            }
+        }
+        ChaosType::PodFailure => {
+            let _: PodFailureParams = parse_params(params)?;
+        }
+        ChaosType::ContainerKill => {
+            let p: ContainerKillParams = parse_params(params)?;
+            if p.container_names.is_empty() {
+                errors.push(ParamError::new(
+                    "params.container_names",
+                    "must name at least one container",
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the params of a DNSChaos type (dns-error, dns-random)
+pub fn validate_dns_params(params: &serde_json::Value) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+    let p: DnsChaosParams = parse_params(params)?;
+
+    for pattern in &p.patterns {
+        let valid = !pattern.is_empty()
+            && pattern
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '*' | '?'));
+        if !valid {
+            errors.push(ParamError::new(
+                "params.patterns",
+                format!("'{}' is not a domain pattern (e.g., \"api.*\")", pattern),
+            ));
+        }
+    }
+
+    if let Some(ref action) = p.action {
+        if action != "error" && action != "random" {
+            errors.push(ParamError::new(
+                "params.action",
+                "must be \"error\" or \"random\"",
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the params of a TimeChaos type (clock-skew)
+pub fn validate_time_params(params: &serde_json::Value) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+    let p: TimeChaosParams = parse_params(params)?;
+
+    // Unlike latencies, clock offsets may be negative
+    if !is_valid_duration(&p.time_offset) {
+        errors.push(ParamError::new(
+            "params.time_offset",
+            format!(
+                "'{}' is not a valid offset (e.g., \"-10m\", \"1h30m\")",
+                p.time_offset
+            ),
+        ));
+    }
+
+    for clock_id in p.clock_ids.iter().flatten() {
+        if !CLOCK_IDS.contains(&clock_id.as_str()) {
+            errors.push(ParamError::new(
+                "params.clock_ids",
+                format!("'{}' is not one of {}", clock_id, CLOCK_IDS.join(", ")),
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the params of an HTTPChaos type (http-abort, http-delay, http-replace, http-patch)
+pub fn validate_http_params(
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+
+    match chaos_type {
+        ChaosType::HttpAbort => {
+            let p: HttpAbortParams = parse_params(params)?;
+            check_http_match(&mut errors, &p.method, &p.path, p.port, &None);
+            if let Some(code) = p.code {
+                check_status_code(&mut errors, code);
+            }
+        }
+        ChaosType::HttpDelay => {
+            let p: HttpDelayParams = parse_params(params)?;
+            check_http_match(&mut errors, &p.method, &p.path, p.port, &p.headers);
+            if !is_valid_duration(&p.delay) || p.delay.starts_with('-') {
+                errors.push(ParamError::new(
+                    "params.delay",
+                    format!(
+                        "'{}' is not a valid duration (e.g., \"500ms\", \"2s\")",
+                        p.delay
+                    ),
+                ));
+            }
+        }
+        ChaosType::HttpReplace => {
+            let p: HttpReplaceParams = parse_params(params)?;
+            check_http_match(&mut errors, &p.method, &p.path, p.port, &None);
+            check_headers(&mut errors, &p.headers);
+            if p.code.is_none() && p.headers.is_none() && p.body.is_none() {
+                errors.push(ParamError::new(
+                    "params",
+                    "http-replace needs at least one of code, headers or body",
+                ));
+            }
+            if let Some(code) = p.code {
+                check_status_code(&mut errors, code);
+                if p.target != HttpTarget::Response {
+                    errors.push(ParamError::new(
+                        "params.code",
+                        "a status code can only be replaced on the response target",
+                    ));
+                }
+            }
+        }
+        ChaosType::HttpPatch => {
+            let p: HttpPatchParams = parse_params(params)?;
+            check_http_match(&mut errors, &p.method, &p.path, p.port, &None);
+            check_headers(&mut errors, &p.headers);
+            if p.headers.is_none() && p.body.is_none() {
+                errors.push(ParamError::new(
+                    "params",
+                    "http-patch needs at least one of headers or body",
+                ));
+            }
+            if let Some(ref body) = p.body {
+                if !body.is_object() {
+                    errors.push(ParamError::new(
+                        "params.body",
+                        "must be a JSON object (merged into the body)",
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the params of an IOChaos type (io-delay, io-fault, io-mistake, io-attr)
+pub fn validate_io_params(
+    chaos_type: &ChaosType,
+    params: &serde_json::Value,
+) -> Result<(), Vec<ParamError>> {
+    let mut errors = Vec::new();
+
+    match chaos_type {
+        ChaosType::IoDelay => {
+            let p: IoDelayParams = parse_params(params)?;
+            check_io_target(&mut errors, &p.path, &p.volume_path, p.percent, &p.methods);
+            if !is_valid_duration(&p.delay) || p.delay.starts_with('-') {
+                errors.push(ParamError::new(
+                    "params.delay",
+                    format!("'{}' is not a valid duration (e.g., \"100ms\")", p.delay),
+                ));
+            }
+        }
+        ChaosType::IoFault => {
+            let p: IoFaultParams = parse_params(params)?;
+            check_io_target(&mut errors, &p.path, &p.volume_path, p.percent, &p.methods);
+            if let Some(errno) = p.errno {
+                if !(1..=133).contains(&errno) {
+                    errors.push(ParamError::new(
+                        "params.errno",
+                        "must be a Linux errno between 1 and 133 (e.g., 5 for EIO)",
+                    ));
+                }
+            }
+        }
+        ChaosType::IoMistake => {
+            let p: IoMistakeParams = parse_params(params)?;
+            check_io_target(&mut errors, &p.path, &p.volume_path, p.percent, &p.methods);
+            if let Some(ref filling) = p.filling {
+                if filling != "zero" && filling != "random" {
+                    errors.push(ParamError::new(
+                        "params.filling",
+                        "must be \"zero\" or \"random\"",
+                    ));
+                }
+            }
+            if p.max_occurrences == Some(0) {
+                errors.push(ParamError::new(
+                    "params.max_occurrences",
+                    "must be at least 1",
+                ));
+            }
+            if p.max_length == Some(0) {
+                errors.push(ParamError::new("params.max_length", "must be at least 1"));
+            }
+        }
+        ChaosType::IoAttr => {
+            let p: IoAttrParams = parse_params(params)?;
+            check_io_target(&mut errors, &p.path, &p.volume_path, p.percent, &None);
+            if p.perm.is_none()
+                && p.size.is_none()
+                && p.uid.is_none()
+                && p.gid.is_none()
+                && p.nlink.is_none()
+            {
+                errors.push(ParamError::new(
+                    "params",
+                    "io-attr needs at least one of perm, size, uid, gid or nlink",
+                ));
+            }
+            if let Some(perm) = p.perm {
+                if perm > 0o7777 {
+                    errors.push(ParamError::new(
+                        "params.perm",
+                        "must be a permission mode up to 0o7777 (4095)",
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Deserialize params into their typed struct, reporting serde errors as a field error
+///
+/// Unknown keys are rejected too: serde would ignore a misspelled field and the
+/// builder would then apply the field's default instead of the requested value.
+fn parse_params<T: DeserializeOwned + Serialize>(
+    params: &serde_json::Value,
+) -> Result<T, Vec<ParamError>> {
+    let params = match params {
+        serde_json::Value::Null => serde_json::Value::Object(Default::default()),
+        serde_json::Value::Object(_) => params.clone(),
+        _ => return Err(vec![ParamError::new("params", "must be a JSON object")]),
+    };
+
+    let parsed: T = serde_json::from_value(params.clone()).map_err(|e| {
+        let message = e.to_string();
+        // serde reports "missing field `latency`"
+        match message
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'))
+        {
+            Some(field) => vec![ParamError::new(&format!("params.{}", field), "is required")],
+            None => vec![ParamError::new("params", message)],
+        }
+    })?;
+
+    // Every field of the params structs is serialized, so its keys are the known ones
+    let known = serde_json::to_value(&parsed).unwrap_or_default();
+    let unknown: Vec<ParamError> = params
+        .as_object()
+        .into_iter()
+        .flat_map(|object| object.keys())
+        .filter(|key| known.get(key.as_str()).is_none())
+        .map(|key| ParamError::new(&format!("params.{}", key), "unknown field"))
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(unknown)
+    }
+}
+
+fn check_http_match(
+    errors: &mut Vec<ParamError>,
+    method: &Option<String>,
+    path: &Option<String>,
+    port: Option<u16>,
+    headers: &Option<BTreeMap<String, String>>,
+) {
+    if let Some(method) = method {
+        if !HTTP_METHODS.contains(&method.as_str()) {
+            errors.push(ParamError::new(
+                "params.method",
+                format!("'{}' is not one of {}", method, HTTP_METHODS.join(", ")),
+            ));
+        }
+    }
+
+    if let Some(path) = path {
+        if !(path.starts_with('/') || path == "*") {
+            errors.push(ParamError::new(
+                "params.path",
+                "must start with '/' or be '*'",
+            ));
+        }
+    }
+
+    if port == Some(0) {
+        errors.push(ParamError::new(
+            "params.port",
+            "must be between 1 and 65535",
+        ));
+    }
+
+    check_headers(errors, headers);
+}
+
+fn check_percentage(errors: &mut Vec<ParamError>, field: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        if !value
+            .parse::<f64>()
+            .is_ok_and(|v| (0.0..=100.0).contains(&v))
+        {
+            errors.push(ParamError::new(
+                field,
+                format!("'{}' is not a percentage between 0 and 100", value),
+            ));
+        }
+    }
+}
+
+fn check_rate(errors: &mut Vec<ParamError>, value: &Option<String>) {
+    if let Some(rate) = value {
+        if !is_valid_rate(rate) {
+            errors.push(ParamError::new(
+                "params.rate",
+                format!("'{}' is not a valid rate (e.g., \"2mbps\", \"100kbps\")", rate),
+            ));
+        }
+    }
+}
+
+fn check_cpu_stressor(
+    errors: &mut Vec<ParamError>,
+    prefix: &str,
+    workers: Option<u32>,
+    load: Option<u32>,
+) {
+    check_workers(errors, prefix, workers);
+    if load.is_some_and(|l| l > 100) {
+        errors.push(ParamError::new(
+            &format!("{}.load", prefix),
+            "must be a CPU load percentage between 0 and 100",
+        ));
+    }
+}
+
+fn check_memory_stressor(
+    errors: &mut Vec<ParamError>,
+    prefix: &str,
+    workers: Option<u32>,
+    size: &Option<String>,
+) {
+    check_workers(errors, prefix, workers);
+    if let Some(size) = size {
+        if !is_valid_memory_size(size) {
+            errors.push(ParamError::new(
+                &format!("{}.size", prefix),
+                format!("'{}' is not a memory size (e.g., \"256MB\", \"50%\")", size),
+            ));
+        }
+    }
+}
+
+fn check_workers(errors: &mut Vec<ParamError>, prefix: &str, workers: Option<u32>) {
+    if workers.is_some_and(|w| !(1..=64).contains(&w)) {
+        errors.push(ParamError::new(
+            &format!("{}.workers", prefix),
+            "must be between 1 and 64",
+        ));
+    }
+}
+
+fn check_latency(errors: &mut Vec<ParamError>, field: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        if !is_valid_duration(value) || value.starts_with('-') {
+            errors.push(ParamError::new(
+                field,
+                format!("'{}' is not a valid duration (e.g., \"100ms\")", value),
+            ));
+        }
+    }
+}
+
+fn check_io_target(
+    errors: &mut Vec<ParamError>,
+    path: &Option<String>,
+    volume_path: &Option<String>,
+    percent: Option<u32>,
+    methods: &Option<Vec<String>>,
+) {
+    if let Some(path) = path {
+        if !path.starts_with('/') {
+            errors.push(ParamError::new("params.path", "must be an absolute path"));
+        }
+    }
+
+    if let Some(volume_path) = volume_path {
+        if !volume_path.starts_with('/') {
+            errors.push(ParamError::new(
+                "params.volume_path",
+                "must be an absolute path",
+            ));
+        }
+    }
+
+    if let Some(percent) = percent {
+        if percent > 100 {
+            errors.push(ParamError::new(
+                "params.percent",
+                "must be between 0 and 100",
+            ));
+        }
+    }
+
+    if let Some(methods) = methods {
+        for method in methods {
+            if !IO_METHODS.contains(&method.as_str()) {
+                errors.push(ParamError::new(
+                    "params.methods",
+                    format!(
+                        "'{}' is not a filesystem method (e.g., read, write)",
+                        method
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn check_headers(errors: &mut Vec<ParamError>, headers: &Option<BTreeMap<String, String>>) {
+    let Some(headers) = headers else {
+        return;
+    };
+
+    for name in headers.keys() {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c));
+        if !valid {
+            errors.push(ParamError::new(
+                "params.headers",
+                format!("'{}' is not a valid header name", name),
+            ));
+        }
+    }
+}
+
+fn check_status_code(errors: &mut Vec<ParamError>, code: u16) {
+    if !(100..=599).contains(&code) {
+        errors.push(ParamError::new(
+            "params.code",
+            "must be between 100 and 599",
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_is_valid_duration() {
+        assert!(is_valid_duration("100ms"));
+        assert!(is_valid_duration("1m30s"));
+        assert!(is_valid_duration("1.5h"));
+        assert!(is_valid_duration("-10m"));
+        assert!(!is_valid_duration(""));
+        assert!(!is_valid_duration("10"));
+        assert!(!is_valid_duration("5 s"));
+        assert!(!is_valid_duration("ms"));
+        assert!(!is_valid_duration("10days"));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        use std::time::Duration;
+
+        assert_eq!(parse_duration("100ms"), Some(Duration::from_millis(100)));
+        assert_eq!(parse_duration("1m30s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("1.5h"), Some(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("-10m"), None);
+        assert_eq!(parse_duration("10days"), None);
+    }
+
+    #[test]
+    fn test_validate_http_delay() {
+        assert!(validate_http_params(
+            &ChaosType::HttpDelay,
+            &json!({"delay": "500ms", "method": "GET", "path": "/api/*"})
+        )
+        .is_ok());
+
+        let errors = validate_http_params(
+            &ChaosType::HttpDelay,
+            &json!({"delay": "soon", "method": "FETCH", "port": 0}),
+        )
+        .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["params.method", "params.port", "params.delay"]);
+
+        // Missing delay is a deserialization error
+        assert!(validate_http_params(&ChaosType::HttpDelay, &json!({})).is_err());
+    }
+
+    #[test]
+    fn test_validate_http_replace_and_patch() {
+        assert!(validate_http_params(
+            &ChaosType::HttpReplace,
+            &json!({"target": "response", "code": 503})
+        )
+        .is_ok());
+        assert!(validate_http_params(&ChaosType::HttpReplace, &json!({"code": 503})).is_err());
+        assert!(validate_http_params(&ChaosType::HttpReplace, &json!({})).is_err());
+
+        assert!(validate_http_params(
+            &ChaosType::HttpPatch,
+            &json!({"headers": {"X-Chaos": "1"}, "body": {"a": 1}})
+        )
+        .is_ok());
+        assert!(validate_http_params(&ChaosType::HttpPatch, &json!({"body": [1, 2]})).is_err());
+        assert!(validate_http_params(
+            &ChaosType::HttpPatch,
+            &json!({"headers": {"bad header": "1"}})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_io_params() {
+        assert!(validate_chaos_params(
+            &ChaosType::IoFault,
+            &json!({"errno": 28, "volume_path": "/data", "methods": ["write"]})
+        )
+        .is_ok());
+        assert!(validate_chaos_params(&ChaosType::IoFault, &json!({"errno": 0})).is_err());
+        assert!(validate_chaos_params(
+            &ChaosType::IoMistake,
+            &json!({"filling": "garbage", "percent": 150})
+        )
+        .is_err());
+        assert!(validate_chaos_params(&ChaosType::IoAttr, &json!({})).is_err());
+        assert!(validate_chaos_params(
+            &ChaosType::IoDelay,
+            &json!({"delay": "100ms", "methods": ["READ"]})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_network_composites() {
+        assert!(validate_chaos_params(
+            &ChaosType::FlakyLink,
+            &json!({"latency": "80ms", "jitter": "20ms", "loss": "2", "rate": "2mbps"})
+        )
+        .is_ok());
+
+        let errors = validate_chaos_params(
+            &ChaosType::FlakyLink,
+            &json!({"latency": "80", "loss": "120", "rate": "fast"}),
+        )
+        .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["params.latency", "params.loss", "params.rate"]);
+
+        assert!(validate_chaos_params(&ChaosType::Reorder, &json!({"reorder": "25"})).is_ok());
+        assert!(validate_chaos_params(&ChaosType::Reorder, &json!({"reorder": "x"})).is_err());
+    }
+
+    #[test]
+    fn test_validate_network_params() {
+        assert!(validate_chaos_params(
+            &ChaosType::Delay,
+            &json!({"latency": "100ms", "jitter": "20ms", "correlation": "25"})
+        )
+        .is_ok());
+        assert!(validate_chaos_params(&ChaosType::Bandwidth, &json!({"rate": "100kbps"})).is_ok());
+        assert!(validate_chaos_params(&ChaosType::Partition, &json!({})).is_ok());
+        assert!(validate_chaos_params(&ChaosType::Partition, &serde_json::Value::Null).is_ok());
+
+        let errors =
+            validate_chaos_params(&ChaosType::Delay, &json!({"latency": "100", "jitter": "-5ms"}))
+                .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["params.latency", "params.jitter"]);
+
+        let errors =
+            validate_chaos_params(&ChaosType::Bandwidth, &json!({"rate": "1mb", "buffer": 0}))
+                .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["params.rate", "params.buffer"]);
+
+        assert!(validate_chaos_params(&ChaosType::Loss, &json!({"loss": "25%"})).is_err());
+        assert!(validate_chaos_params(&ChaosType::Corrupt, &json!({"corrupt": "-1"})).is_err());
+    }
+
+    #[test]
+    fn test_parse_params_rejects_typos() {
+        let errors = validate_chaos_params(&ChaosType::Loss, &json!({"los": "25"})).unwrap_err();
+        assert_eq!(errors[0].field, "params.loss");
+        assert_eq!(errors[0].message, "is required");
+
+        let errors =
+            validate_chaos_params(&ChaosType::Loss, &json!({"loss": "25", "corelation": "10"}))
+                .unwrap_err();
+        assert_eq!(errors[0].field, "params.corelation");
+        assert_eq!(errors[0].message, "unknown field");
+
+        let errors = validate_chaos_params(&ChaosType::Partition, &json!({"loss": "25"}))
+            .unwrap_err();
+        assert_eq!(errors[0].field, "params.loss");
+
+        let errors = validate_chaos_params(&ChaosType::Loss, &json!(["25"])).unwrap_err();
+        assert_eq!(errors[0].field, "params");
+    }
+
+    #[test]
+    fn test_validate_stress_pod_dns_time_params() {
+        assert!(is_valid_memory_size("256MB"));
+        assert!(is_valid_memory_size("1GiB"));
+        assert!(is_valid_memory_size("50%"));
+        assert!(!is_valid_memory_size("256"));
+        assert!(!is_valid_memory_size("150%"));
+
+        assert!(validate_chaos_params(&ChaosType::StressCpu, &json!({"load": 80, "workers": 2}))
+            .is_ok());
+        let errors = validate_chaos_params(
+            &ChaosType::StressMemory,
+            &json!({"workers": 0, "size": "lots", "cpu": {"load": 101}}),
+        )
+        .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["params.workers", "params.size", "params.cpu.load"]
+        );
+
+        assert!(validate_chaos_params(&ChaosType::PodKill, &json!({"grace_period": 30})).is_ok());
+        assert!(validate_chaos_params(&ChaosType::PodKill, &json!({"grace_period": -1})).is_err());
+        assert!(
+            validate_chaos_params(&ChaosType::ContainerKill, &json!({"container_names": []}))
+                .is_err()
+        );
+
+        assert!(validate_chaos_params(
+            &ChaosType::DnsError,
+            &json!({"patterns": ["api.*"], "action": "error"})
+        )
+        .is_ok());
+        assert!(
+            validate_chaos_params(&ChaosType::DnsRandom, &json!({"action": "nxdomain"})).is_err()
+        );
+
+        assert!(validate_chaos_params(&ChaosType::ClockSkew, &json!({"time_offset": "-10m"}))
+            .is_ok());
+        let errors = validate_chaos_params(
+            &ChaosType::ClockSkew,
+            &json!({"time_offset": "-10m", "clock_ids": ["CLOCK_WALL"]}),
+        )
+        .unwrap_err();
+        assert_eq!(errors[0].field, "params.clock_ids");
+    }
+
+    #[test]
+    fn test_validate_external_targets() {
+        let valid: Vec<String> = [
+            "10.0.0.5",
+            "10.0.0.0/24",
+            "2001:db8::/32",
+            "api.example.com",
+            "db",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        assert!(validate_external_targets(&valid).is_ok());
+
+        let invalid: Vec<String> = ["10.0.0.0/33", "300.1.1.1", "-bad.example.com", "a b"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let errors = validate_external_targets(&invalid).unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert_eq!(errors[0].field, "external_targets[0]");
+    }
+
+    #[test]
+    fn test_validate_selection() {
+        let selection = |mode: SelectionMode, value: Option<&str>| PodSelection {
+            selection_mode: mode,
+            selection_value: value.map(String::from),
+        };
+
+        assert!(validate_selection(&PodSelection::default()).is_ok());
+        assert!(validate_selection(&selection(SelectionMode::Fixed, Some("2"))).is_ok());
+        assert!(validate_selection(&selection(SelectionMode::FixedPercent, Some("50"))).is_ok());
+
+        for invalid in [
+            selection(SelectionMode::All, Some("1")),
+            selection(SelectionMode::Fixed, None),
+            selection(SelectionMode::Fixed, Some("0")),
+            selection(SelectionMode::FixedPercent, Some("150")),
+            selection(SelectionMode::RandomMaxPercent, Some("50%")),
+        ] {
+            let errors = validate_selection(&invalid).unwrap_err();
+            assert_eq!(errors[0].field, "selection_value");
+        }
+    }
+
+    #[test]
+    fn test_validate_duration() {
+        assert!(validate_duration(None).is_ok());
+        assert!(validate_duration(Some("1m30s")).is_ok());
+
+        for invalid in ["30", "5 minutes", "-10s"] {
+            let errors = validate_duration(Some(invalid)).unwrap_err();
+            assert_eq!(errors[0].field, "duration");
+        }
+    }
+
+    #[test]
+    fn test_validate_recurrence() {
+        let recurrence = |schedule: Option<&str>, history_limit: Option<u32>| ChaosRecurrence {
+            schedule: schedule.map(String::from),
+            history_limit,
+        };
+
+        assert!(is_valid_cron("*/2 * * * *"));
+        assert!(is_valid_cron("0 9 * * MON-FRI"));
+        assert!(is_valid_cron("@every 2m"));
+        assert!(is_valid_cron("@hourly"));
+        assert!(!is_valid_cron("* * * *"));
+        assert!(!is_valid_cron("@every two minutes"));
+        assert!(!is_valid_cron("@sometimes"));
+
+        let loss = ChaosType::Loss;
+        assert!(validate_recurrence(&loss, None, &ChaosRecurrence::default()).is_ok());
+        assert!(validate_recurrence(&loss, Some("5s"), &recurrence(Some("@every 2m"), Some(5)))
+            .is_ok());
+        assert!(
+            validate_recurrence(&ChaosType::PodKill, None, &recurrence(Some("@hourly"), None))
+                .is_ok()
+        );
+
+        let errors = validate_recurrence(&loss, None, &recurrence(Some("@every 2m"), None))
+            .unwrap_err();
+        assert_eq!(errors[0].field, "duration");
+
+        let errors = validate_recurrence(&loss, Some("5s"), &recurrence(Some("bad"), Some(0)))
+            .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["schedule", "history_limit"]);
+
+        let errors = validate_recurrence(&loss, None, &recurrence(None, Some(2))).unwrap_err();
+        assert_eq!(errors[0].field, "history_limit");
+    }
+}
@@ -1,6 +1,9 @@
 use anyhow::Result;
 use serde::Deserialize;
 
+/// Accepted `chaos_backend` values
+pub const CHAOS_BACKENDS: &[&str] = &["chaos-mesh", "tc", "memory"];
+
 #[derive(Debug, Clone, Deserialize)]
 #[allow(dead_code)]
 pub struct Config {
@@ -21,6 +24,11 @@ pub struct Config {
 
     #[serde(default = "default_helm_namespace")]
     pub helm_namespace: Option<String>,
+
+    /// Chaos backend: `chaos-mesh`, `tc` (netem sidecar, no Chaos Mesh) or `memory`
+    /// (no cluster, for tests and demos)
+    #[serde(default = "default_chaos_backend")]
+    pub chaos_backend: String,
 }
 
 fn default_port() -> u16 {
@@ -48,6 +56,10 @@ fn default_helm_namespace() -> Option<String> {
     Some(default_k8s_namespace_sim())
 }
 
+fn default_chaos_backend() -> String {
+    "chaos-mesh".to_string()
+}
+
 impl Config {
     pub fn load() -> Result<Self> {
         // Load .env file if it exists
@@ -60,9 +72,22 @@ impl Config {
         let settings: Config = config
             .try_deserialize()
             .unwrap_or_else(|_| Config::default());
+        settings.validate()?;
 
         Ok(settings)
     }
+
+    /// Reject settings that would otherwise silently fall back to a default
+    pub fn validate(&self) -> Result<()> {
+        if !CHAOS_BACKENDS.contains(&self.chaos_backend.as_str()) {
+            anyhow::bail!(
+                "Unknown chaos backend '{}' (expected one of: {})",
+                self.chaos_backend,
+                CHAOS_BACKENDS.join(", ")
+            );
+        }
+        Ok(())
+    }
 }
 
 impl Default for Config {
@@ -74,6 +99,7 @@ impl Default for Config {
             k8s_namespace_system: default_k8s_namespace_system(),
             k8s_namespace_sim: default_k8s_namespace_sim(),
             helm_namespace: default_helm_namespace(),
+            chaos_backend: default_chaos_backend(),
         }
     }
 }
@@ -89,5 +115,18 @@ mod tests {
         assert_eq!(config.database_url, "sqlite://networksim.db");
         assert_eq!(config.k8s_namespace_system, "networksim-system");
         assert_eq!(config.k8s_namespace_sim, "networksim-sim");
+        assert_eq!(config.chaos_backend, "chaos-mesh");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_unknown_chaos_backend() {
+        for backend in ["tcc", "TC", ""] {
+            let config = Config {
+                chaos_backend: backend.to_string(),
+                ..Config::default()
+            };
+            assert!(config.validate().is_err(), "backend {:?}", backend);
+        }
     }
 }
@@ -2,8 +2,11 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 use sqlx::{sqlite::SqlitePoolOptions, FromRow, Pool, Sqlite};
 
-use crate::chaos::{ChaosCondition, ChaosConditionStatus, ChaosDirection, ChaosType};
-use crate::models::{Application, Topology};
+use crate::chaos::{
+    AbortCriteria, ChaosCondition, ChaosConditionStatus, ChaosDirection, ChaosRecurrence,
+    ChaosType, PodSelection,
+};
+use crate::models::{Application, ScenarioRun, Topology};
 
 pub type DbPool = Pool<Sqlite>;
 
@@ -28,10 +31,16 @@ struct ChaosConditionRow {
     topology_id: String,
     source_node_id: String,
     target_node_id: Option<String>,
+    external_targets: Option<String>,
+    selection_mode: String,
+    selection_value: Option<String>,
+    schedule: Option<String>,
+    history_limit: Option<i64>,
     chaos_type: String,
     direction: String,
     duration: Option<String>,
     params: String,
+    abort_criteria: Option<String>,
     status: String,
     k8s_name: Option<String>,
     started_at: Option<String>,
@@ -39,6 +48,25 @@ struct ChaosConditionRow {
     updated_at: String,
 }
 
+#[derive(FromRow)]
+struct ScenarioRunRow {
+    id: String,
+    scenario_id: String,
+    topology_id: String,
+    status: String,
+    force: bool,
+    restart_policy: String,
+    total_duration: i64,
+    abort_criteria: Option<String>,
+    steps: String,
+    error: Option<String>,
+    time_shift: f64,
+    paused_at: Option<f64>,
+    started_at: String,
+    finished_at: Option<String>,
+    updated_at: String,
+}
+
 #[derive(FromRow)]
 struct ApplicationRow {
     id: String,
@@ -136,20 +164,27 @@ impl Database {
     ) -> Result<(), sqlx::Error> {
         let now = Utc::now().to_rfc3339();
         let started_at = condition.started_at.map(|dt| dt.to_rfc3339());
+        let external_targets = external_targets_json(&condition.external_targets);
         sqlx::query(
             r#"
-            INSERT INTO chaos_conditions (id, topology_id, source_node_id, target_node_id, chaos_type, direction, duration, params, status, k8s_name, started_at, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO chaos_conditions (id, topology_id, source_node_id, target_node_id, external_targets, selection_mode, selection_value, schedule, history_limit, chaos_type, direction, duration, params, abort_criteria, status, k8s_name, started_at, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&condition.id)
         .bind(&condition.topology_id)
         .bind(&condition.source_node_id)
         .bind(&condition.target_node_id)
+        .bind(&external_targets)
+        .bind(condition.selection.selection_mode.to_string())
+        .bind(&condition.selection.selection_value)
+        .bind(&condition.recurrence.schedule)
+        .bind(condition.recurrence.history_limit)
         .bind(condition.chaos_type.to_string())
         .bind(condition.direction.to_string())
         .bind(&condition.duration)
         .bind(condition.params.to_string())
+        .bind(abort_criteria_json(&condition.abort_criteria))
         .bind(condition.status.to_string())
         .bind(&condition.k8s_name)
         .bind(&started_at)
@@ -167,7 +202,7 @@ impl Database {
         id: &str,
     ) -> Result<Option<ChaosCondition>, sqlx::Error> {
         let row: Option<ChaosConditionRow> = sqlx::query_as(
-            "SELECT id, topology_id, source_node_id, target_node_id, chaos_type, direction, duration, params, status, k8s_name, started_at, created_at, updated_at FROM chaos_conditions WHERE id = ?",
+            "SELECT id, topology_id, source_node_id, target_node_id, external_targets, selection_mode, selection_value, schedule, history_limit, chaos_type, direction, duration, params, abort_criteria, status, k8s_name, started_at, created_at, updated_at FROM chaos_conditions WHERE id = ?",
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -182,7 +217,7 @@ impl Database {
         topology_id: &str,
     ) -> Result<Vec<ChaosCondition>, sqlx::Error> {
         let rows: Vec<ChaosConditionRow> = sqlx::query_as(
-            "SELECT id, topology_id, source_node_id, target_node_id, chaos_type, direction, duration, params, status, k8s_name, started_at, created_at, updated_at FROM chaos_conditions WHERE topology_id = ? ORDER BY created_at",
+            "SELECT id, topology_id, source_node_id, target_node_id, external_targets, selection_mode, selection_value, schedule, history_limit, chaos_type, direction, duration, params, abort_criteria, status, k8s_name, started_at, created_at, updated_at FROM chaos_conditions WHERE topology_id = ? ORDER BY created_at",
         )
         .bind(topology_id)
         .fetch_all(&self.pool)
@@ -191,8 +226,34 @@ impl Database {
         rows.into_iter().map(Self::row_to_chaos_condition).collect()
     }
 
+    /// List the chaos conditions of every topology
+    pub async fn list_all_chaos_conditions(&self) -> Result<Vec<ChaosCondition>, sqlx::Error> {
+        let rows: Vec<ChaosConditionRow> = sqlx::query_as(
+            "SELECT id, topology_id, source_node_id, target_node_id, external_targets, selection_mode, selection_value, schedule, history_limit, chaos_type, direction, duration, params, abort_criteria, status, k8s_name, started_at, created_at, updated_at FROM chaos_conditions ORDER BY created_at",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_chaos_condition).collect()
+    }
+
+    /// List the active chaos conditions with a duration, the ones that can expire
+    pub async fn list_expiring_chaos_conditions(
+        &self,
+    ) -> Result<Vec<ChaosCondition>, sqlx::Error> {
+        let rows: Vec<ChaosConditionRow> = sqlx::query_as(
+            "SELECT id, topology_id, source_node_id, target_node_id, external_targets, selection_mode, selection_value, schedule, history_limit, chaos_type, direction, duration, params, abort_criteria, status, k8s_name, started_at, created_at, updated_at FROM chaos_conditions WHERE status = ? AND duration IS NOT NULL AND schedule IS NULL ORDER BY started_at",
+        )
+        .bind(ChaosConditionStatus::Active.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_chaos_condition).collect()
+    }
+
     /// Update chaos condition status and k8s_name
-    /// Sets started_at when status becomes Active, clears it otherwise
+    /// Sets started_at when status becomes Active, keeps it when the condition completes
+    /// or fails, clears it otherwise
     pub async fn update_chaos_condition_status(
         &self,
         id: &str,
@@ -206,12 +267,17 @@ impl Database {
         } else {
             None
         };
+        let keep_started_at = matches!(
+            status,
+            ChaosConditionStatus::Completed | ChaosConditionStatus::Failed
+        );
 
         sqlx::query(
-            "UPDATE chaos_conditions SET status = ?, k8s_name = ?, started_at = ?, updated_at = ? WHERE id = ?",
+            "UPDATE chaos_conditions SET status = ?, k8s_name = ?, started_at = CASE WHEN ? THEN started_at ELSE ? END, updated_at = ? WHERE id = ?",
         )
         .bind(status.to_string())
         .bind(k8s_name)
+        .bind(keep_started_at)
         .bind(&started_at)
         .bind(&now)
         .bind(id)
@@ -229,14 +295,26 @@ impl Database {
         sqlx::query(
             "UPDATE chaos_conditions SET 
                 direction = ?, 
+                external_targets = ?, 
+                selection_mode = ?, 
+                selection_value = ?, 
+                schedule = ?, 
+                history_limit = ?, 
                 duration = ?, 
                 params = ?, 
+                abort_criteria = ?, 
                 updated_at = ? 
              WHERE id = ?",
         )
         .bind(condition.direction.to_string())
+        .bind(external_targets_json(&condition.external_targets))
+        .bind(condition.selection.selection_mode.to_string())
+        .bind(&condition.selection.selection_value)
+        .bind(&condition.recurrence.schedule)
+        .bind(condition.recurrence.history_limit)
         .bind(&condition.duration)
         .bind(serde_json::to_string(&condition.params).unwrap_or_default())
+        .bind(abort_criteria_json(&condition.abort_criteria))
         .bind(condition.updated_at.to_rfc3339())
         .bind(&condition.id)
         .execute(&self.pool)
@@ -423,6 +501,121 @@ impl Database {
         Ok(result.rows_affected())
     }
 
+    // ==================== Scenario Runs ====================
+
+    /// Create a scenario run
+    pub async fn create_scenario_run(&self, run: &ScenarioRun) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO scenario_runs (id, scenario_id, topology_id, status, force, restart_policy, total_duration, abort_criteria, steps, error, time_shift, paused_at, started_at, finished_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&run.id)
+        .bind(&run.scenario_id)
+        .bind(&run.topology_id)
+        .bind(run.status.to_string())
+        .bind(run.force)
+        .bind(run.restart_policy.to_string())
+        .bind(run.total_duration)
+        .bind(abort_criteria_json(&run.abort_criteria))
+        .bind(sqlx::types::Json(&run.steps))
+        .bind(&run.error)
+        .bind(run.time_shift)
+        .bind(run.paused_at)
+        .bind(run.started_at.to_rfc3339())
+        .bind(run.finished_at.map(|dt| dt.to_rfc3339()))
+        .bind(run.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get a scenario run by ID
+    pub async fn get_scenario_run(&self, id: &str) -> Result<Option<ScenarioRun>, sqlx::Error> {
+        let row: Option<ScenarioRunRow> = sqlx::query_as(
+            "SELECT id, scenario_id, topology_id, status, force, restart_policy, total_duration, abort_criteria, steps, error, time_shift, paused_at, started_at, finished_at, updated_at FROM scenario_runs WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(Self::row_to_scenario_run).transpose()
+    }
+
+    /// List the runs of a scenario, most recent first
+    pub async fn list_scenario_runs(
+        &self,
+        scenario_id: &str,
+    ) -> Result<Vec<ScenarioRun>, sqlx::Error> {
+        let rows: Vec<ScenarioRunRow> = sqlx::query_as(
+            "SELECT id, scenario_id, topology_id, status, force, restart_policy, total_duration, abort_criteria, steps, error, time_shift, paused_at, started_at, finished_at, updated_at FROM scenario_runs WHERE scenario_id = ? ORDER BY started_at DESC",
+        )
+        .bind(scenario_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_scenario_run).collect()
+    }
+
+    /// List the runs of every scenario not finished yet: `running` or `paused`
+    pub async fn list_running_scenario_runs(&self) -> Result<Vec<ScenarioRun>, sqlx::Error> {
+        let rows: Vec<ScenarioRunRow> = sqlx::query_as(
+            "SELECT id, scenario_id, topology_id, status, force, restart_policy, total_duration, abort_criteria, steps, error, time_shift, paused_at, started_at, finished_at, updated_at FROM scenario_runs WHERE status IN ('running', 'paused') ORDER BY started_at",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_scenario_run).collect()
+    }
+
+    /// Save the status and steps of a scenario run
+    pub async fn update_scenario_run(&self, run: &ScenarioRun) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE scenario_runs SET status = ?, steps = ?, error = ?, time_shift = ?, paused_at = ?, finished_at = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(run.status.to_string())
+        .bind(sqlx::types::Json(&run.steps))
+        .bind(&run.error)
+        .bind(run.time_shift)
+        .bind(run.paused_at)
+        .bind(run.finished_at.map(|dt| dt.to_rfc3339()))
+        .bind(run.updated_at.to_rfc3339())
+        .bind(&run.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn row_to_scenario_run(row: ScenarioRunRow) -> Result<ScenarioRun, sqlx::Error> {
+        Ok(ScenarioRun {
+            id: row.id,
+            scenario_id: row.scenario_id,
+            topology_id: row.topology_id,
+            status: row.status.parse().unwrap_or_default(),
+            force: row.force,
+            restart_policy: row.restart_policy.parse().unwrap_or_default(),
+            total_duration: row.total_duration,
+            abort_criteria: row.abort_criteria.and_then(|s| serde_json::from_str(&s).ok()),
+            steps: serde_json::from_str(&row.steps)
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            error: row.error,
+            time_shift: row.time_shift,
+            paused_at: row.paused_at,
+            started_at: row
+                .started_at
+                .parse::<DateTime<Utc>>()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            finished_at: row.finished_at.and_then(|s| s.parse::<DateTime<Utc>>().ok()),
+            updated_at: row
+                .updated_at
+                .parse::<DateTime<Utc>>()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+        })
+    }
+
     /// Helper to convert row to ChaosCondition
     fn row_to_chaos_condition(row: ChaosConditionRow) -> Result<ChaosCondition, sqlx::Error> {
         let chaos_type = match row.chaos_type.as_str() {
@@ -431,11 +624,25 @@ impl Database {
             "bandwidth" => ChaosType::Bandwidth,
             "corrupt" => ChaosType::Corrupt,
             "duplicate" => ChaosType::Duplicate,
+            "reorder" => ChaosType::Reorder,
+            "flaky-link" => ChaosType::FlakyLink,
             "partition" => ChaosType::Partition,
             "stress-cpu" => ChaosType::StressCpu,
+            "stress-memory" => ChaosType::StressMemory,
             "pod-kill" => ChaosType::PodKill,
+            "pod-failure" => ChaosType::PodFailure,
+            "container-kill" => ChaosType::ContainerKill,
             "io-delay" => ChaosType::IoDelay,
+            "io-fault" => ChaosType::IoFault,
+            "io-mistake" => ChaosType::IoMistake,
+            "io-attr" => ChaosType::IoAttr,
             "http-abort" => ChaosType::HttpAbort,
+            "http-delay" => ChaosType::HttpDelay,
+            "http-replace" => ChaosType::HttpReplace,
+            "http-patch" => ChaosType::HttpPatch,
+            "dns-error" => ChaosType::DnsError,
+            "dns-random" => ChaosType::DnsRandom,
+            "clock-skew" => ChaosType::ClockSkew,
             _ => ChaosType::Delay,
         };
 
@@ -457,18 +664,34 @@ impl Database {
         // Parse started_at if present
         let started_at = row.started_at.and_then(|s| s.parse::<DateTime<Utc>>().ok());
 
-        Ok(ChaosCondition {
+        let external_targets: Vec<String> = row
+            .external_targets
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let mut condition = ChaosCondition {
             id: row.id,
             topology_id: row.topology_id,
             source_node_id: row.source_node_id,
             target_node_id: row.target_node_id,
+            external_targets,
+            selection: PodSelection {
+                selection_mode: row.selection_mode.parse().unwrap_or_default(),
+                selection_value: row.selection_value,
+            },
+            recurrence: ChaosRecurrence {
+                schedule: row.schedule,
+                history_limit: row.history_limit.map(|limit| limit as u32),
+            },
             chaos_type,
             direction,
             duration: row.duration,
             params,
+            abort_criteria: row.abort_criteria.and_then(|s| serde_json::from_str(&s).ok()),
             status,
             k8s_name: row.k8s_name,
             started_at,
+            ends_at: None,
             created_at: row
                 .created_at
                 .parse::<DateTime<Utc>>()
@@ -477,7 +700,10 @@ impl Database {
                 .updated_at
                 .parse::<DateTime<Utc>>()
                 .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-        })
+        };
+        condition.ends_at = condition.expected_end();
+
+        Ok(condition)
     }
 
     /// Helper to convert row to Application
@@ -532,6 +758,20 @@ impl Database {
     }
 }
 
+/// Serialize external targets for storage (NULL when there are none)
+fn external_targets_json(targets: &[String]) -> Option<String> {
+    if targets.is_empty() {
+        None
+    } else {
+        serde_json::to_string(targets).ok()
+    }
+}
+
+/// Serialize abort criteria for storage
+fn abort_criteria_json(criteria: &Option<AbortCriteria>) -> Option<String> {
+    criteria.as_ref().and_then(|c| serde_json::to_string(c).ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,8 @@ use axum::{
 use serde_json::json;
 use thiserror::Error;
 
+use crate::api::response::ApiResponse;
+
 #[derive(Error, Debug)]
 pub enum AppError {
     #[error("Not found: {0}")]
@@ -14,13 +16,19 @@ pub enum AppError {
     #[error("Bad request: {0}")]
     BadRequest(String),
 
-    #[allow(dead_code)]
     #[error("Conflict: {0}")]
     Conflict(String),
 
     #[error("Internal error: {0}")]
     Internal(String),
 
+    /// Invalid input with field-level details
+    #[error("Validation error: {message}")]
+    Validation {
+        message: String,
+        details: serde_json::Value,
+    },
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
@@ -43,15 +51,26 @@ impl AppError {
     pub fn internal(msg: &str) -> Self {
         AppError::Internal(msg.to_string())
     }
+
+    pub fn validation(msg: &str, details: serde_json::Value) -> Self {
+        AppError::Validation {
+            message: msg.to_string(),
+            details,
+        }
+    }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match &self {
-            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
-            AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
-            AppError::Conflict(msg) => (StatusCode::CONFLICT, msg.clone()),
-            AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.clone()),
+        let (status, error_message) = match self {
+            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            AppError::Conflict(msg) => (StatusCode::CONFLICT, msg),
+            AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+            AppError::Validation { message, details } => {
+                return ApiResponse::error_with_details("VALIDATION_ERROR", message, details)
+                    .into_response();
+            }
             AppError::Database(e) => {
                 tracing::error!("Database error: {:?}", e);
                 (
@@ -10,7 +10,7 @@ use tracing::{info, instrument, warn};
 
 use super::client::K8sClient;
 use super::resources::{
-    create_network_policy, create_pod_spec, create_service, get_connected_nodes,
+    add_tc_sidecar, create_network_policy, create_pod_spec, create_service, get_connected_nodes,
 };
 use crate::models::{Node, Topology};
 
@@ -75,12 +75,22 @@ pub struct NodeStatusInfo {
 /// Manages the deployment of topologies to Kubernetes
 pub struct DeploymentManager {
     k8s: K8sClient,
+    tc_sidecar: bool,
 }
 
 impl DeploymentManager {
     /// Create a new deployment manager
     pub fn new(k8s: K8sClient) -> Self {
-        Self { k8s }
+        Self {
+            k8s,
+            tc_sidecar: false,
+        }
+    }
+
+    /// Deploy node pods with the `tc` sidecar required by the native chaos backend
+    pub fn with_tc_sidecar(mut self, enabled: bool) -> Self {
+        self.tc_sidecar = enabled;
+        self
     }
 
     /// Deploy a topology to Kubernetes
@@ -150,7 +160,10 @@ impl DeploymentManager {
         let pod_name = format!("ns-{}-{}", short_id, node.id).to_lowercase();
 
         // Create the pod
-        let pod_spec = create_pod_spec(topology_id, node);
+        let mut pod_spec = create_pod_spec(topology_id, node);
+        if self.tc_sidecar {
+            add_tc_sidecar(&mut pod_spec);
+        }
         let pod = self
             .k8s
             .create_pod(&pod_spec)
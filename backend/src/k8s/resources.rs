@@ -6,8 +6,8 @@
 
 use k8s_openapi::api::apps::v1::Deployment;
 use k8s_openapi::api::core::v1::{
-    Container, ContainerPort, EnvVar, Pod, PodSpec, ResourceRequirements, Service, ServicePort,
-    ServiceSpec,
+    Capabilities, Container, ContainerPort, EnvVar, Pod, PodSpec, ResourceRequirements,
+    SecurityContext, Service, ServicePort, ServiceSpec,
 };
 use k8s_openapi::api::networking::v1::{
     NetworkPolicy, NetworkPolicyEgressRule, NetworkPolicyIngressRule, NetworkPolicyPeer,
@@ -23,6 +23,15 @@ use crate::models::{Node, NodeConfig, Application};
 /// Default container image for simulation nodes
 pub const DEFAULT_NODE_IMAGE: &str = "public.ecr.aws/docker/library/alpine:3.18";
 
+/// Name of the base container of every node pod
+pub const NODE_MAIN_CONTAINER: &str = "main";
+
+/// Sidecar running `tc` for the native (non Chaos Mesh) chaos backend
+pub const TC_SIDECAR_CONTAINER: &str = "netem";
+
+/// Image of the `tc` sidecar (ships iproute2)
+pub const TC_SIDECAR_IMAGE: &str = "nicolaka/netshoot:v0.13";
+
 /// Create labels for a topology resource
 pub fn topology_labels(topology_id: &str, node_id: &str) -> BTreeMap<String, String> {
     [
@@ -73,7 +82,7 @@ pub fn create_pod_spec(topology_id: &str, node: &Node) -> Pod {
         },
         spec: Some(PodSpec {
             containers: vec![Container {
-                name: "main".to_string(),
+                name: NODE_MAIN_CONTAINER.to_string(),
                 image: Some(image),
                 image_pull_policy: Some("IfNotPresent".to_string()),
                 // Keep the container running with a sleep command
@@ -102,6 +111,50 @@ pub fn create_pod_spec(topology_id: &str, node: &Node) -> Pod {
     }
 }
 
+/// Add the `tc` sidecar used by the native chaos backend to a node pod
+///
+/// Containers of a pod share its network namespace, so `NET_ADMIN` on the sidecar
+/// is enough to shape the traffic of the whole pod.
+pub fn add_tc_sidecar(pod: &mut Pod) {
+    if let Some(spec) = pod.spec.as_mut() {
+        spec.containers.push(tc_sidecar_container());
+    }
+}
+
+/// Add the `tc` sidecar to the pods of an application Deployment
+///
+/// Application pods carry the labels of their node, so the tc backend shapes them too.
+pub fn add_tc_sidecar_to_deployment(deployment: &mut Deployment) {
+    if let Some(spec) = deployment
+        .spec
+        .as_mut()
+        .and_then(|s| s.template.spec.as_mut())
+    {
+        spec.containers.push(tc_sidecar_container());
+    }
+}
+
+fn tc_sidecar_container() -> Container {
+    Container {
+        name: TC_SIDECAR_CONTAINER.to_string(),
+        image: Some(TC_SIDECAR_IMAGE.to_string()),
+        image_pull_policy: Some("IfNotPresent".to_string()),
+        command: Some(vec!["/bin/sh".to_string()]),
+        args: Some(vec![
+            "-c".to_string(),
+            "trap 'exit 0' TERM; while true; do sleep 1; done".to_string(),
+        ]),
+        security_context: Some(SecurityContext {
+            capabilities: Some(Capabilities {
+                add: Some(vec!["NET_ADMIN".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
 /// Build resource requirements from node config
 fn build_resource_requirements(config: &NodeConfig) -> ResourceRequirements {
     let mut limits = BTreeMap::new();
@@ -366,6 +419,45 @@ mod tests {
         assert_eq!(spec.containers[0].image, Some("nginx:latest".to_string()));
     }
 
+    #[test]
+    fn test_add_tc_sidecar() {
+        let node = create_test_node();
+        let mut pod = create_pod_spec("topo-123", &node);
+        add_tc_sidecar(&mut pod);
+
+        let spec = pod.spec.unwrap();
+        assert_eq!(spec.containers.len(), 2);
+        assert_eq!(spec.containers[1].name, TC_SIDECAR_CONTAINER);
+        let capabilities = spec.containers[1]
+            .security_context
+            .as_ref()
+            .and_then(|s| s.capabilities.as_ref())
+            .and_then(|c| c.add.clone());
+        assert_eq!(capabilities, Some(vec!["NET_ADMIN".to_string()]));
+    }
+
+    #[test]
+    fn test_add_tc_sidecar_to_deployment() {
+        let app = Application {
+            id: uuid::Uuid::new_v4(),
+            topology_id: uuid::Uuid::new_v4(),
+            node_selector: vec!["node-1".to_string()],
+            image_name: "nginx:latest".to_string(),
+            namespace: "networksim-sim".to_string(),
+            values: None,
+            status: crate::models::AppStatus::Pending,
+            release_name: "nginx".to_string(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+        let mut deployment = create_application_deployment(&app, "node-1", "topo-123");
+        add_tc_sidecar_to_deployment(&mut deployment);
+
+        let spec = deployment.spec.unwrap().template.spec.unwrap();
+        assert_eq!(spec.containers.len(), 2);
+        assert_eq!(spec.containers[1].name, TC_SIDECAR_CONTAINER);
+    }
+
     #[test]
     fn test_create_service() {
         let node = create_test_node();
@@ -458,7 +550,7 @@ pub fn create_pod_spec_with_applications(topology_id: &str, node: &Node, applica
 
     // Create main container
     let mut containers = vec![Container {
-        name: "main".to_string(),
+        name: NODE_MAIN_CONTAINER.to_string(),
         image: Some(image),
         image_pull_policy: Some("IfNotPresent".to_string()),
         // Keep the container running with a sleep command
@@ -597,6 +689,16 @@ fn parse_volumes_from_app(app: &Application) -> (Vec<k8s_openapi::api::core::v1:
     (volumes, volume_mounts)
 }
 
+/// Volume mounts of an application's container, as configured in its values
+pub fn application_volume_mounts(app: &Application) -> Vec<k8s_openapi::api::core::v1::VolumeMount> {
+    parse_volumes_from_app(app).1
+}
+
+/// Name of the container that runs an application (stable across pod and Deployment specs)
+pub fn application_container_name(app: &Application) -> String {
+    format!("app-{}", app.id.simple())
+}
+
 /// Create a container spec for an application
 pub fn create_application_container(app: &Application) -> Container {
     create_application_container_with_mounts(app, Vec::new())
@@ -755,7 +857,7 @@ pub fn create_application_container_with_mounts(app: &Application, volume_mounts
     }
 
     Container {
-        name: format!("app-{}", app.id.simple()),
+        name: application_container_name(app),
         image: Some(image),
         image_pull_policy: Some("IfNotPresent".to_string()),
         command,
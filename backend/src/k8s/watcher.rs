@@ -1,6 +1,8 @@
 //! Kubernetes Pod Watcher
 //!
-//! Watches pod events and broadcasts them via WebSocket
+//! Watches pod and chaos events and broadcasts them via WebSocket
+
+use std::collections::HashMap;
 
 use futures::StreamExt;
 use k8s_openapi::api::core::v1::Pod;
@@ -12,7 +14,9 @@ use kube::{
 use tokio::sync::broadcast;
 use tracing::{error, info, warn};
 
-use crate::api::Event;
+use crate::api::events::{emit_event, EventSeverity, EventSourceType};
+use crate::api::{AppState, Event};
+use crate::chaos::{condition_id_from_name, ChaosClient, ChaosCrdKind, PodInjection};
 
 /// Start watching pods in the networksim-sim namespace
 pub async fn start_pod_watcher(event_tx: broadcast::Sender<Event>) {
@@ -103,9 +107,13 @@ fn handle_pod_event(event_tx: &broadcast::Sender<Event>, pod: &Pod, event_type:
     });
 }
 
-/// Start watching NetworkChaos resources
-pub async fn start_chaos_watcher(event_tx: broadcast::Sender<Event>) {
-    info!("Starting NetworkChaos watcher");
+/// Start watching every Chaos Mesh CRD kind created by NetworkSim
+///
+/// Besides `chaos:applied`/`chaos:removed`, reports the injection phases from the
+/// resource status (`chaos:injected`, `chaos:injection_failed`, `chaos:recovered`) and
+/// records them in the events table.
+pub async fn start_chaos_watcher(state: AppState) {
+    info!("Starting chaos watchers");
 
     let client = match Client::try_default().await {
         Ok(c) => c,
@@ -118,26 +126,27 @@ pub async fn start_chaos_watcher(event_tx: broadcast::Sender<Event>) {
         }
     };
 
-    use kube::api::{Api, DynamicObject};
-    use kube::discovery::ApiResource;
+    let watchers = ChaosCrdKind::all()
+        .iter()
+        .map(|kind| watch_chaos_kind(client.clone(), state.clone(), kind.clone()));
+    futures::future::join_all(watchers).await;
+}
 
-    let ar = ApiResource {
-        group: "chaos-mesh.org".to_string(),
-        version: "v1alpha1".to_string(),
-        api_version: "chaos-mesh.org/v1alpha1".to_string(),
-        kind: "NetworkChaos".to_string(),
-        plural: "networkchaos".to_string(),
-    };
+/// Watch the resources of one CRD kind
+async fn watch_chaos_kind(client: Client, state: AppState, kind: ChaosCrdKind) {
+    use kube::api::{Api, DynamicObject};
 
-    let chaos_api: Api<DynamicObject> = Api::namespaced_with(client, "networksim-sim", &ar);
+    let ar = ChaosClient::api_resource_for_kind(&kind);
+    let chaos_api: Api<DynamicObject> =
+        Api::namespaced_with(client, &state.config.k8s_namespace_sim, &ar);
 
     // Check if chaos-mesh CRD exists before starting watcher
     match chaos_api.list(&Default::default()).await {
         Ok(_) => {
-            info!("Chaos-mesh detected, starting NetworkChaos watcher");
+            info!("Chaos-mesh detected, starting {} watcher", ar.kind);
         }
         Err(e) => {
-            warn!("Chaos-mesh not available ({}), NetworkChaos watcher disabled", e);
+            warn!("Chaos-mesh not available ({}), {} watcher disabled", e, ar.kind);
             return;
         }
     }
@@ -147,47 +156,431 @@ pub async fn start_chaos_watcher(event_tx: broadcast::Sender<Event>) {
 
     let mut chaos_stream = watcher::watcher(chaos_api, watcher_config).boxed();
 
-    info!("NetworkChaos watcher started");
+    // Last injection phase seen per resource, so status updates are reported once
+    let mut last_phase: HashMap<String, InjectionPhase> = HashMap::new();
 
     while let Some(event) = chaos_stream.next().await {
         match event {
             Ok(WatchEvent::Applied(chaos)) => {
                 let name = chaos.metadata.name.clone().unwrap_or_default();
                 let labels = chaos.metadata.labels.clone().unwrap_or_default();
-                let topology_id = labels.get("networksim.io/topology").cloned();
+                let topology_id = labels
+                    .get("networksim.io/topology")
+                    .cloned()
+                    .unwrap_or_default();
+                let condition_id = condition_id_from_name(&name, &topology_id);
+                let report = injection_report(&chaos.data);
+
+                let previous = last_phase.insert(name.clone(), report.phase.clone());
+                if previous.is_none() {
+                    info!("{} applied: {} (topology: {})", ar.kind, name, topology_id);
+                    let _ = state.event_tx.send(Event::ChaosApplied {
+                        id: condition_id.clone(),
+                        target: topology_id.clone(),
+                    });
+                }
+                if previous.as_ref() == Some(&report.phase) {
+                    continue;
+                }
 
-                info!(
-                    "NetworkChaos applied: {} (topology: {:?})",
-                    name, topology_id
-                );
+                report_injection(&state, &ar.kind, &name, &topology_id, &condition_id, report)
+                    .await;
+            }
+            Ok(WatchEvent::Deleted(chaos)) => {
+                let name = chaos.metadata.name.clone().unwrap_or_default();
+                info!("{} deleted: {}", ar.kind, name);
+                last_phase.remove(&name);
+
+                let topology_id = chaos
+                    .metadata
+                    .labels
+                    .as_ref()
+                    .and_then(|l| l.get("networksim.io/topology"))
+                    .cloned()
+                    .unwrap_or_default();
+                let condition_id = condition_id_from_name(&name, &topology_id);
+
+                let _ = state.event_tx.send(Event::ChaosRemoved { id: condition_id });
+            }
+            Ok(WatchEvent::Restarted(items)) => {
+                info!("{} watcher restarted, {} items found", ar.kind, items.len());
+                // Phases reached before the restart are history, only report new ones
+                last_phase = items
+                    .iter()
+                    .map(|chaos| {
+                        let name = chaos.metadata.name.clone().unwrap_or_default();
+                        (name, injection_report(&chaos.data).phase)
+                    })
+                    .collect();
+            }
+            Err(e) => {
+                error!("{} watcher error: {}", ar.kind, e);
+            }
+        }
+    }
 
-                // Extract condition ID from name (ns-{topo}-{condition_id})
-                let condition_id = name.split('-').next_back().unwrap_or(&name).to_string();
+    warn!("{} watcher stream ended", ar.kind);
+}
 
-                let _ = event_tx.send(Event::ChaosApplied {
-                    id: condition_id,
-                    target: topology_id.unwrap_or_default(),
+/// Broadcast and persist a change of injection phase
+async fn report_injection(
+    state: &AppState,
+    kind: &str,
+    name: &str,
+    topology_id: &str,
+    condition_id: &str,
+    report: InjectionReport,
+) {
+    let (subtype, severity, title) = match report.phase {
+        InjectionPhase::Pending => return,
+        InjectionPhase::Injected => (
+            "injected",
+            EventSeverity::Success,
+            format!("{} {} injected into {} pod(s)", kind, name, report.pods.len()),
+        ),
+        InjectionPhase::Failed => (
+            "injection_failed",
+            EventSeverity::Error,
+            format!("{} {} failed to inject", kind, name),
+        ),
+        InjectionPhase::Recovered => (
+            "recovered",
+            EventSeverity::Info,
+            format!("{} {} recovered", kind, name),
+        ),
+    };
+    info!("{}", title);
+
+    let (id, topology, kind_name, pods) = (
+        condition_id.to_string(),
+        topology_id.to_string(),
+        kind.to_string(),
+        report.pods.clone(),
+    );
+    let event = match report.phase {
+        InjectionPhase::Failed => Event::ChaosInjectionFailed {
+            id,
+            topology_id: topology,
+            kind: kind_name,
+            pods,
+            message: report.message.clone().unwrap_or_default(),
+        },
+        InjectionPhase::Recovered => Event::ChaosRecovered {
+            id,
+            topology_id: topology,
+            kind: kind_name,
+            pods,
+        },
+        _ => Event::ChaosInjected {
+            id,
+            topology_id: topology,
+            kind: kind_name,
+            pods,
+        },
+    };
+    let _ = state.event_tx.send(event);
+
+    emit_event(
+        state,
+        Some(topology_id).filter(|t| !t.is_empty()),
+        "chaos",
+        Some(subtype),
+        severity,
+        &title,
+        report.message.as_deref(),
+        Some(EventSourceType::Chaos),
+        Some(condition_id),
+        Some(serde_json::json!({
+            "kind": kind,
+            "resource": name,
+            "pods": report.pods,
+        })),
+    )
+    .await;
+}
+
+/// Injection phase of a chaos resource, from its status
+#[derive(Debug, Clone, PartialEq)]
+enum InjectionPhase {
+    /// Not (yet) injected in every selected pod
+    Pending,
+    /// `AllInjected` condition is true
+    Injected,
+    /// The last operation on some record failed
+    Failed,
+    /// Experiment stopped and `AllRecovered` condition is true
+    Recovered,
+}
+
+#[derive(Debug)]
+struct InjectionReport {
+    phase: InjectionPhase,
+    pods: Vec<PodInjection>,
+    message: Option<String>,
+}
+
+/// Read `status.conditions` and `status.experiment.containerRecords` of a chaos resource
+fn injection_report(data: &serde_json::Value) -> InjectionReport {
+    let status = data.get("status").cloned().unwrap_or_default();
+
+    let condition_true = |condition: &str| {
+        status
+            .get("conditions")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .any(|c| {
+                c.get("type").and_then(|t| t.as_str()) == Some(condition)
+                    && c.get("status").and_then(|s| s.as_str()) == Some("True")
+            })
+    };
+
+    let pods: Vec<PodInjection> = status
+        .pointer("/experiment/containerRecords")
+        .and_then(|r| r.as_array())
+        .into_iter()
+        .flatten()
+        .map(|record| {
+            let id = record.get("id").and_then(|i| i.as_str()).unwrap_or_default();
+            let mut parts = id.split('/').skip(1);
+            // Chaos Mesh keeps the history of apply/recover operations per record
+            let last_event = record
+                .get("events")
+                .and_then(|e| e.as_array())
+                .and_then(|e| e.last());
+            let error = last_event
+                .filter(|e| e.get("type").and_then(|t| t.as_str()) == Some("Failed"))
+                .map(|e| {
+                    e.get("message")
+                        .and_then(|m| m.as_str())
+                        .unwrap_or("injection failed")
+                        .to_string()
                 });
+            PodInjection {
+                pod: parts.next().unwrap_or(id).to_string(),
+                container: parts.next().map(String::from),
+                phase: record
+                    .get("phase")
+                    .and_then(|p| p.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                error,
             }
-            Ok(WatchEvent::Deleted(chaos)) => {
-                let name = chaos.metadata.name.clone().unwrap_or_default();
-                info!("NetworkChaos deleted: {}", name);
+        })
+        .collect();
+
+    let stopped =
+        status.pointer("/experiment/desiredPhase").and_then(|p| p.as_str()) == Some("Stop");
+    let failure = pods.iter().find_map(|p| p.error.clone());
+
+    let phase = if stopped && condition_true("AllRecovered") {
+        InjectionPhase::Recovered
+    } else if failure.is_some() {
+        InjectionPhase::Failed
+    } else if condition_true("AllInjected") {
+        InjectionPhase::Injected
+    } else {
+        InjectionPhase::Pending
+    };
+
+    InjectionReport {
+        phase,
+        pods,
+        message: failure,
+    }
+}
+
+/// Start watching Chaos Mesh `Schedule` resources (recurring conditions)
+///
+/// Emits a `chaos:schedule_fired` event every time a Schedule spawns a new run.
+pub async fn start_schedule_watcher(state: AppState) {
+    info!("Starting Schedule watcher");
+
+    let client = match Client::try_default().await {
+        Ok(c) => c,
+        Err(e) => {
+            warn!(
+                "Failed to create K8s client for schedule watcher: {}. Watcher disabled.",
+                e
+            );
+            return;
+        }
+    };
+
+    use kube::api::{Api, DynamicObject};
+    use kube::discovery::ApiResource;
+
+    let ar = ApiResource {
+        group: "chaos-mesh.org".to_string(),
+        version: "v1alpha1".to_string(),
+        api_version: "chaos-mesh.org/v1alpha1".to_string(),
+        kind: "Schedule".to_string(),
+        plural: "schedules".to_string(),
+    };
 
-                let condition_id = name.split('-').next_back().unwrap_or(&name).to_string();
+    let schedule_api: Api<DynamicObject> =
+        Api::namespaced_with(client, &state.config.k8s_namespace_sim, &ar);
+
+    match schedule_api.list(&Default::default()).await {
+        Ok(_) => {
+            info!("Chaos-mesh detected, starting Schedule watcher");
+        }
+        Err(e) => {
+            warn!("Chaos-mesh not available ({}), Schedule watcher disabled", e);
+            return;
+        }
+    }
 
-                let _ = event_tx.send(Event::ChaosRemoved { id: condition_id });
+    let watcher_config =
+        watcher::Config::default().labels("app.kubernetes.io/managed-by=networksim");
+
+    let mut schedule_stream = watcher::watcher(schedule_api, watcher_config).boxed();
+
+    // Last firing time seen per Schedule, so re-applied objects are not reported twice
+    let mut last_fired: HashMap<String, String> = HashMap::new();
+
+    while let Some(event) = schedule_stream.next().await {
+        match event {
+            Ok(WatchEvent::Applied(schedule)) => {
+                let name = schedule.metadata.name.clone().unwrap_or_default();
+                let Some((fired_at, chaos_name)) = schedule_firing(&schedule.data) else {
+                    continue;
+                };
+                if last_fired.get(&name) == Some(&fired_at) {
+                    continue;
+                }
+                last_fired.insert(name.clone(), fired_at.clone());
+
+                let labels = schedule.metadata.labels.clone().unwrap_or_default();
+                let topology_id = labels
+                    .get("networksim.io/topology")
+                    .cloned()
+                    .unwrap_or_default();
+                let condition_id = condition_id_from_name(&name, &topology_id);
+
+                info!("Schedule {} fired at {} ({})", name, fired_at, chaos_name);
+
+                let _ = state.event_tx.send(Event::ChaosScheduleFired {
+                    id: condition_id,
+                    topology_id,
+                    chaos_name,
+                    fired_at,
+                });
+            }
+            Ok(WatchEvent::Deleted(schedule)) => {
+                let name = schedule.metadata.name.clone().unwrap_or_default();
+                info!("Schedule deleted: {}", name);
+                last_fired.remove(&name);
             }
             Ok(WatchEvent::Restarted(items)) => {
-                info!(
-                    "NetworkChaos watcher restarted, {} items found",
-                    items.len()
-                );
+                info!("Schedule watcher restarted, {} items found", items.len());
+                // Past firings are history, only report new ones
+                for schedule in items {
+                    let name = schedule.metadata.name.clone().unwrap_or_default();
+                    if let Some((fired_at, _)) = schedule_firing(&schedule.data) {
+                        last_fired.insert(name, fired_at);
+                    }
+                }
             }
             Err(e) => {
-                error!("NetworkChaos watcher error: {}", e);
+                error!("Schedule watcher error: {}", e);
             }
         }
     }
 
-    warn!("NetworkChaos watcher stream ended");
+    warn!("Schedule watcher stream ended");
+}
+
+/// Last firing of a Schedule: (`status.time`, name of the most recently spawned chaos)
+fn schedule_firing(data: &serde_json::Value) -> Option<(String, String)> {
+    let status = data.get("status")?;
+    let fired_at = status.get("time")?.as_str()?.to_string();
+    let chaos_name = status
+        .get("active")
+        .and_then(|a| a.as_array())
+        .and_then(|a| a.last())
+        .and_then(|r| r.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    Some((fired_at, chaos_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_firing() {
+        let data = serde_json::json!({
+            "spec": {"schedule": "@every 2m"},
+            "status": {
+                "time": "2024-05-01T10:02:00Z",
+                "active": [{"kind": "NetworkChaos", "name": "ns-abc12345-cond1-x7k2p"}]
+            }
+        });
+
+        assert_eq!(
+            schedule_firing(&data),
+            Some((
+                "2024-05-01T10:02:00Z".to_string(),
+                "ns-abc12345-cond1-x7k2p".to_string()
+            ))
+        );
+        assert_eq!(schedule_firing(&serde_json::json!({"status": {}})), None);
+    }
+
+    #[test]
+    fn test_injection_report() {
+        let mut data = serde_json::json!({
+            "status": {
+                "conditions": [
+                    {"type": "AllInjected", "status": "True"},
+                    {"type": "AllRecovered", "status": "False"}
+                ],
+                "experiment": {
+                    "desiredPhase": "Run",
+                    "containerRecords": [
+                        {"id": "networksim-sim/ns-abc-node-1", "phase": "Injected"},
+                        {"id": "networksim-sim/ns-abc-node-2/main", "phase": "Injected"}
+                    ]
+                }
+            }
+        });
+
+        let report = injection_report(&data);
+        assert_eq!(report.phase, InjectionPhase::Injected);
+        assert_eq!(report.pods.len(), 2);
+        assert_eq!(report.pods[0].pod, "ns-abc-node-1");
+        assert_eq!(report.pods[0].container, None);
+        assert_eq!(report.pods[1].container.as_deref(), Some("main"));
+
+        data["status"]["experiment"]["containerRecords"][1]["events"] = serde_json::json!([
+            {"type": "Succeeded", "operation": "Apply"},
+            {"type": "Failed", "operation": "Apply", "message": "tc: command not found"}
+        ]);
+        let report = injection_report(&data);
+        assert_eq!(report.phase, InjectionPhase::Failed);
+        assert_eq!(report.message.as_deref(), Some("tc: command not found"));
+        assert_eq!(report.pods[1].error.as_deref(), Some("tc: command not found"));
+
+        data["status"] = serde_json::json!({
+            "conditions": [
+                {"type": "AllInjected", "status": "False"},
+                {"type": "AllRecovered", "status": "True"}
+            ],
+            "experiment": {
+                "desiredPhase": "Stop",
+                "containerRecords": [{"id": "networksim-sim/ns-abc-node-1", "phase": "Not Injected"}]
+            }
+        });
+        assert_eq!(injection_report(&data).phase, InjectionPhase::Recovered);
+
+        // Nothing injected yet
+        assert_eq!(
+            injection_report(&serde_json::json!({})).phase,
+            InjectionPhase::Pending
+        );
+    }
 }
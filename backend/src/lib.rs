@@ -10,6 +10,7 @@ pub mod error;
 pub mod helm;
 pub mod k8s;
 pub mod models;
+pub mod scenarios;
 
 use axum::{
     routing::{delete, get, post, put},
@@ -62,6 +63,10 @@ pub fn create_router(state: AppState) -> Router {
             post(api::chaos::start_all),
         )
         .route("/api/topologies/:id/chaos/stop", post(api::chaos::stop_all))
+        .route(
+            "/api/topologies/:id/chaos/effective",
+            get(api::chaos::effective_faults),
+        )
         .route(
             "/api/topologies/:id/chaos/:condition_id",
             delete(api::chaos::delete),
@@ -78,8 +83,19 @@ pub fn create_router(state: AppState) -> Router {
             "/api/topologies/:id/chaos/:condition_id/stop",
             post(api::chaos::stop),
         )
+        .route(
+            "/api/topologies/:id/nodes/:node_id/chaos/containers",
+            get(api::chaos::container_targets),
+        )
+        .route(
+            "/api/topologies/:id/chaos/:condition_id/preview",
+            get(api::chaos::preview_condition),
+        )
         // Chaos - global create
         .route("/api/chaos", post(api::chaos::create))
+        .route("/api/chaos/preview", post(api::chaos::preview))
+        .route("/api/chaos/reconcile", post(api::chaos::reconcile))
+        .route("/api/chaos/emergency-stop", post(api::chaos::emergency_stop))
         // Applications
         .route("/api/topologies/:topology_id/apps", post(api::applications::deploy_topology))
         .route("/api/topologies/:topology_id/apps/draft", post(api::applications::create_draft))
@@ -4,11 +4,13 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use networksim_backend::{
     api::AppState,
+    chaos::{start_chaos_expiry_timer, start_chaos_reconciler, start_chaos_supervisor},
     config::Config,
     create_router,
     db::Database,
     helm::HelmClient,
-    k8s::{start_chaos_watcher, start_pod_watcher, K8sClient},
+    k8s::{start_chaos_watcher, start_pod_watcher, start_schedule_watcher, K8sClient},
+    scenarios::recover_scenario_runs,
 };
 
 #[tokio::main]
@@ -42,6 +44,11 @@ async fn main() -> Result<()> {
     let state = state.with_helm(helm);
     tracing::info!("Helm client initialized");
 
+    // Complete chaos conditions whose duration elapsed (works with every chaos backend)
+    tokio::spawn(start_chaos_expiry_timer(state.clone()));
+    // Stop the chaos of a topology when an abort criterion trips
+    tokio::spawn(start_chaos_supervisor(state.clone()));
+
     // Spawn K8s Connection Manager (Automatic Reconnection)
     let mgr_state = state.clone();
     tokio::spawn(async move {
@@ -101,9 +108,23 @@ async fn main() -> Result<()> {
                     start_pod_watcher(event_tx).await;
                 });
 
-                let event_tx = mgr_state.event_tx.clone();
+                tokio::spawn(start_chaos_watcher(mgr_state.clone()));
+
+                tokio::spawn(start_schedule_watcher(mgr_state.clone()));
+
+                tokio::spawn(start_chaos_reconciler(mgr_state.clone()));
+
+                // Resume or roll back the scenario runs interrupted by the last shutdown,
+                // now that their chaos can be applied or lifted
+                let recovery_state = mgr_state.clone();
                 tokio::spawn(async move {
-                    start_chaos_watcher(event_tx).await;
+                    match recover_scenario_runs(&recovery_state).await {
+                        Ok(runs) if !runs.is_empty() => {
+                            tracing::info!("Recovered {} interrupted scenario runs", runs.len())
+                        }
+                        Ok(_) => {}
+                        Err(e) => tracing::warn!("Failed to recover scenario runs: {}", e),
+                    }
                 });
 
                 watchers_launched = true;
@@ -1,6 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use crate::chaos::ChaosType;
+use strum::{Display, EnumString};
+use crate::chaos::{deserialize_some, AbortCriteria, ChaosDirection, ChaosType};
 use utoipa::ToSchema;
 
 /// A test scenario composed of ordered chaos steps to run against a topology.
@@ -19,20 +21,26 @@ pub struct Scenario {
     /// Ordered steps that make up the scenario
     #[schema(value_type = Vec<ScenarioStep>)]
     pub steps: sqlx::types::Json<Vec<ScenarioStep>>,
+    /// Stop all chaos of the topology when one of these trips during a run
+    #[schema(value_type = Option<AbortCriteria>)]
+    pub abort_criteria: Option<sqlx::types::Json<AbortCriteria>>,
+    /// What to do with its runs when the server restarts in the middle of them
+    pub restart_policy: RestartPolicy,
     #[schema(example = "2025-01-01T12:00:00Z")]
     pub created_at: String,
     #[schema(example = "2025-01-01T12:05:00Z")]
     pub updated_at: String,
 }
 
-/// Single step inside a scenario, describing a chaos action to apply.
+/// Single step inside a scenario: a chaos action to apply, or an assertion to check.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ScenarioStep {
     #[schema(example = "step-1")]
     pub id: String,
     #[serde(rename = "type")]
-    /// Chaos type for the step (see ChaosType enum)
-    pub chaos_type: ChaosType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Chaos type of a chaos step (see ChaosType enum), absent for assertion steps
+    pub chaos_type: Option<ChaosType>,
     #[serde(rename = "sourceNodeId")]
     /// Source node id for the action
     #[schema(example = "node-1")]
@@ -54,6 +62,41 @@ pub struct ScenarioStep {
     #[serde(rename = "laneId")]
     #[schema(example = "lane-1")]
     pub lane_id: String,
+    /// Traffic a chaos step affects, egress by default like `POST /api/chaos`
+    #[serde(default)]
+    pub direction: ChaosDirection,
+    /// What an assertion step checks during its window, absent for chaos steps
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertion: Option<StepAssertion>,
+}
+
+/// Expectation checked by an assertion step between `start_at` and its end
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StepAssertion {
+    /// The step source node can, or cannot, reach its target node; the first sample decides
+    Reachability { expect_reachable: bool },
+    /// HTTP latency percentile from one application to another stays under a bound,
+    /// sampled over the whole window
+    HttpLatency {
+        from_app_id: String,
+        to_app_id: String,
+        #[serde(default)]
+        port: Option<u16>,
+        #[serde(default)]
+        path: Option<String>,
+        #[serde(default = "default_percentile")]
+        #[schema(example = 95.0)]
+        percentile: f64,
+        #[schema(example = 500.0)]
+        max_latency_ms: f64,
+    },
+    /// Every linked pair of nodes is connected before the window ends
+    LinkedPairsConnected,
+}
+
+fn default_percentile() -> f64 {
+    95.0
 }
 
 /// Request to create a new scenario for a topology.
@@ -68,6 +111,10 @@ pub struct CreateScenarioRequest {
     pub total_duration: i64,
     #[schema(value_type = Vec<ScenarioStep>)]
     pub steps: Vec<ScenarioStep>,
+    #[serde(default)]
+    pub abort_criteria: Option<AbortCriteria>,
+    #[serde(default)]
+    pub restart_policy: RestartPolicy,
 }
 
 /// Partial update for an existing scenario.
@@ -82,4 +129,166 @@ pub struct UpdateScenarioRequest {
     pub total_duration: Option<i64>,
     #[serde(default)]
     pub steps: Option<Vec<ScenarioStep>>,
+    /// Replace the abort criteria (omit to keep the current ones, `null` to remove them)
+    #[serde(default, deserialize_with = "deserialize_some")]
+    #[schema(value_type = Option<AbortCriteria>)]
+    pub abort_criteria: Option<Option<AbortCriteria>>,
+    #[serde(default)]
+    pub restart_policy: Option<RestartPolicy>,
+}
+
+/// What to do with a run left `running` by a server restart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[derive(sqlx::Type, Display, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RestartPolicy {
+    /// Remove the chaos the run applied and mark it `rolled_back`
+    #[default]
+    Rollback,
+    /// Continue with the remaining steps at their offsets from the original start
+    Resume,
+}
+
+/// Status of a scenario run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[derive(Display, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScenarioRunStatus {
+    #[default]
+    Running,
+    /// Timeline frozen until resumed, see `ScenarioRun.paused_at`
+    Paused,
+    /// Every step ran and was cleaned up
+    Completed,
+    /// At least one step could not be applied or cleaned up, or an assertion failed
+    Failed,
+    Cancelled,
+    /// The abort criteria of the scenario tripped
+    Aborted,
+    /// Undone after a server restart, see `RestartPolicy`
+    RolledBack,
+}
+
+impl ScenarioRunStatus {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, ScenarioRunStatus::Running | ScenarioRunStatus::Paused)
+    }
+}
+
+/// Status of a step inside a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[derive(Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RunStepStatus {
+    /// Waiting for its start time
+    #[default]
+    Scheduled,
+    /// Chaos injected
+    Applied,
+    /// Assertion being sampled
+    Probing,
+    /// Assertion held, see `result`
+    Passed,
+    /// Chaos lifted while the run is paused, injected again on resume
+    Suspended,
+    /// Could not be applied or cleaned up, or the assertion did not hold, see `error`
+    Failed,
+    /// Chaos removed after the step ended, or when the run stopped
+    CleanedUp,
+    /// Never applied, the run stopped before its start time
+    Skipped,
+}
+
+/// A step of a run: the scenario step as planned and what happened to it
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioRunStep {
+    pub step: ScenarioStep,
+    pub status: RunStepStatus,
+    /// Chaos condition created for the step once applied
+    pub condition_id: Option<String>,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub cleaned_up_at: Option<DateTime<Utc>>,
+    pub error: Option<String>,
+    /// Measurements of an assertion step
+    #[serde(default)]
+    pub samples: Vec<ProbeSample>,
+    /// Verdict of an assertion step once decided
+    #[serde(default)]
+    pub result: Option<AssertionResult>,
+}
+
+/// A measurement taken by an assertion step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ProbeSample {
+    /// Position on the run timeline, in seconds
+    pub at: f64,
+    /// Target reachable, request answered, or no linked pair blocked
+    pub ok: bool,
+    pub latency_ms: Option<f64>,
+    /// Linked pairs without connectivity
+    pub linked_blocked: Option<usize>,
+    /// The measurement itself failed
+    pub error: Option<String>,
+}
+
+/// Whether the assertion of a step held
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AssertionResult {
+    pub passed: bool,
+    /// What was observed against what was expected
+    #[schema(example = "p95 latency 612.0ms, expected under 500ms")]
+    pub message: String,
+    /// Value the verdict is based on: latency in ms, or blocked linked pairs
+    pub observed: Option<f64>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl ScenarioRunStep {
+    pub fn new(step: ScenarioStep) -> Self {
+        Self {
+            step,
+            status: RunStepStatus::Scheduled,
+            condition_id: None,
+            applied_at: None,
+            cleaned_up_at: None,
+            error: None,
+            samples: Vec::new(),
+            result: None,
+        }
+    }
+}
+
+/// An execution of a scenario
+///
+/// Keeps a copy of the scenario plan, so editing the scenario does not change a run.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScenarioRun {
+    #[schema(example = "run-1234")]
+    pub id: String,
+    #[schema(example = "scenario-1234")]
+    pub scenario_id: String,
+    #[schema(example = "topology-1234")]
+    pub topology_id: String,
+    pub status: ScenarioRunStatus,
+    /// Steps applied despite conflicting with active conditions
+    pub force: bool,
+    pub restart_policy: RestartPolicy,
+    #[schema(example = 60)]
+    pub total_duration: i64,
+    pub abort_criteria: Option<AbortCriteria>,
+    pub steps: Vec<ScenarioRunStep>,
+    /// Why the run failed, was aborted or left resources behind
+    pub error: Option<String>,
+    /// Seconds the timeline lags behind the wall clock: time spent paused, minus the time
+    /// skipped by advancing
+    pub time_shift: f64,
+    /// Position on the timeline, in seconds, while the run is paused
+    pub paused_at: Option<f64>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
 }
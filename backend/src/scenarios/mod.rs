@@ -0,0 +1,28 @@
+//! Scenario execution
+//!
+//! A run executes the steps of a scenario on its timeline from a single task. Chaos steps
+//! are built, validated and started through the same path as `POST /api/chaos`. Every step
+//! transition (scheduled, applied, failed, cleaned up) is saved in `scenario_runs` and
+//! streamed over the WebSocket. `ScenarioRunManager` holds the control channel of the
+//! runs in progress; cancelling a run removes the chaos of every step it applied.
+//!
+//! Runs can be paused, resumed and advanced step by step. Step offsets are positions on
+//! the run timeline, which stands still while paused and jumps ahead when advanced; faults
+//! active at the pause are held, or lifted and injected again on resume.
+//!
+//! Assertion steps check instead of injecting: they sample reachability, HTTP latency or
+//! linked pair connectivity during their window and record whether the expectation held,
+//! so a run fails when an assertion does.
+//!
+//! Since the plan and the state of every step are saved, a run survives a server restart:
+//! once Kubernetes is connected after startup, it is resumed at its current offset or
+//! rolled back, per `RestartPolicy`.
+
+mod probes;
+mod runner;
+
+pub use probes::validate_assertion;
+pub use runner::{
+    advance_run, cancel_run, cancel_runs, pause_run, recover_scenario_runs, resume_run, start_run,
+    step_condition, step_request, ScenarioRunManager,
+};
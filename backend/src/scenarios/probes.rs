@@ -0,0 +1,316 @@
+//! Assertion steps: sampling and verdicts
+
+use axum::extract::{Path, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+
+use crate::api::diagnostic::{run_app_to_app_test, AppToAppTestRequest};
+use crate::api::live_metrics::{measure_live_metrics, measure_node_pair};
+use crate::api::AppState;
+use crate::chaos::ParamError;
+use crate::models::{AssertionResult, ProbeSample, ScenarioStep, StepAssertion};
+
+/// Interval between two samples of an assertion step, in timeline seconds
+pub const PROBE_INTERVAL_SECS: f64 = 2.0;
+
+/// Timeout of an HTTP probe request, kept under the sample interval
+const HTTP_PROBE_TIMEOUT_SECS: u32 = 2;
+
+/// Check an assertion step: no chaos type, and what its assertion needs
+pub fn validate_assertion(step: &ScenarioStep, assertion: &StepAssertion) -> Vec<ParamError> {
+    let mut errors = Vec::new();
+    let mut error = |field: &str, message: &str| {
+        errors.push(ParamError {
+            field: field.to_string(),
+            message: message.to_string(),
+        })
+    };
+
+    if step.chaos_type.is_some() {
+        error("type", "assertion steps have no chaos type");
+    }
+    match assertion {
+        StepAssertion::Reachability { .. } => {
+            if step.target_node_id.as_deref().is_none_or(|t| t.trim().is_empty()) {
+                error("targetNodeId", "reachability assertions need a target node");
+            }
+        }
+        StepAssertion::HttpLatency {
+            from_app_id,
+            to_app_id,
+            percentile,
+            max_latency_ms,
+            ..
+        } => {
+            if from_app_id.trim().is_empty() {
+                error("assertion.from_app_id", "must be non-empty");
+            }
+            if to_app_id.trim().is_empty() {
+                error("assertion.to_app_id", "must be non-empty");
+            }
+            if !(*percentile > 0.0 && *percentile <= 100.0) {
+                error("assertion.percentile", "must be in (0, 100]");
+            }
+            if *max_latency_ms <= 0.0 {
+                error("assertion.max_latency_ms", "must be > 0");
+            }
+        }
+        StepAssertion::LinkedPairsConnected => {}
+    }
+    errors
+}
+
+/// Measure what the assertion checks, at position `at` of the run timeline
+pub async fn take_sample(
+    state: &AppState,
+    topology_id: &str,
+    step: &ScenarioStep,
+    assertion: &StepAssertion,
+    at: f64,
+) -> ProbeSample {
+    let mut sample = ProbeSample {
+        at,
+        ok: false,
+        latency_ms: None,
+        linked_blocked: None,
+        error: None,
+    };
+
+    match assertion {
+        StepAssertion::Reachability { .. } => {
+            let target = step.target_node_id.as_deref().unwrap_or_default();
+            match measure_node_pair(state, topology_id, &step.source_node_id, target).await {
+                Ok((connected, latency_ms, _)) => {
+                    sample.ok = connected;
+                    sample.latency_ms = latency_ms;
+                }
+                Err(e) => sample.error = Some(e.to_string()),
+            }
+        }
+        StepAssertion::HttpLatency {
+            from_app_id,
+            to_app_id,
+            port,
+            path,
+            ..
+        } => {
+            let request = AppToAppTestRequest {
+                from_app_id: from_app_id.clone(),
+                to_app_id: to_app_id.clone(),
+                test_type: "http".to_string(),
+                port: *port,
+                path: path.clone(),
+                timeout_secs: HTTP_PROBE_TIMEOUT_SECS,
+            };
+            let result = run_app_to_app_test(
+                State(state.clone()),
+                Path(topology_id.to_string()),
+                Json(request),
+            )
+            .await;
+            match result {
+                Ok(Json(result)) => {
+                    sample.ok = result.success;
+                    sample.latency_ms = result.latency_ms;
+                    sample.error = result.error;
+                }
+                Err(e) => sample.error = Some(e.to_string()),
+            }
+        }
+        StepAssertion::LinkedPairsConnected => {
+            match measure_live_metrics(state, topology_id).await {
+                Ok(snapshot) => {
+                    sample.ok = snapshot.summary.linked_blocked == 0;
+                    sample.linked_blocked = Some(snapshot.summary.linked_blocked);
+                }
+                Err(e) => sample.error = Some(e.to_string()),
+            }
+        }
+    }
+    sample
+}
+
+/// Verdict of the assertion from its samples, `None` while undecided
+///
+/// Reachability is decided by the first sample and linked pairs by the first sample
+/// without blocked pairs; otherwise the verdict waits for the end of the window.
+pub fn evaluate(
+    step: &ScenarioStep,
+    assertion: &StepAssertion,
+    samples: &[ProbeSample],
+    window_over: bool,
+    now: DateTime<Utc>,
+) -> Option<AssertionResult> {
+    let verdict = |passed: bool, message: String, observed: Option<f64>| AssertionResult {
+        passed,
+        message,
+        observed,
+        checked_at: now,
+    };
+    let unmeasured = |sample: Option<&ProbeSample>| match sample.and_then(|s| s.error.as_ref()) {
+        Some(e) => format!("Could not measure: {}", e),
+        None => "No sample taken".to_string(),
+    };
+
+    match assertion {
+        StepAssertion::Reachability { expect_reachable } => {
+            let sample = samples.first()?;
+            if sample.error.is_some() {
+                return Some(verdict(false, unmeasured(Some(sample)), None));
+            }
+            let message = format!(
+                "{} {} {}, expected {}",
+                step.source_node_id,
+                if sample.ok { "reached" } else { "could not reach" },
+                step.target_node_id.as_deref().unwrap_or_default(),
+                if *expect_reachable { "reachable" } else { "unreachable" },
+            );
+            Some(verdict(sample.ok == *expect_reachable, message, sample.latency_ms))
+        }
+        StepAssertion::LinkedPairsConnected => {
+            if let Some(sample) = samples.iter().find(|s| s.ok) {
+                let message = format!("All linked pairs connected at {:.1}s", sample.at);
+                return Some(verdict(true, message, Some(0.0)));
+            }
+            if !window_over {
+                return None;
+            }
+            let last = samples.last();
+            Some(match last.and_then(|s| s.linked_blocked) {
+                Some(blocked) => verdict(
+                    false,
+                    format!("{} linked pair(s) still blocked", blocked),
+                    Some(blocked as f64),
+                ),
+                None => verdict(false, unmeasured(last), None),
+            })
+        }
+        StepAssertion::HttpLatency {
+            percentile: p,
+            max_latency_ms,
+            ..
+        } => {
+            if !window_over {
+                return None;
+            }
+            if samples.is_empty() {
+                return Some(verdict(false, unmeasured(None), None));
+            }
+            // A failed request is slower than any bound
+            let latencies: Vec<f64> = samples
+                .iter()
+                .map(|s| if s.ok { s.latency_ms.unwrap_or(0.0) } else { f64::INFINITY })
+                .collect();
+            let observed = percentile(&latencies, *p);
+            if observed.is_infinite() {
+                let failed = samples.iter().filter(|s| !s.ok).count();
+                let message = format!(
+                    "p{} not reached: {} of {} request(s) failed",
+                    p,
+                    failed,
+                    samples.len()
+                );
+                return Some(verdict(false, message, None));
+            }
+            let message = format!(
+                "p{} latency {:.1}ms, expected under {}ms",
+                p, observed, max_latency_ms
+            );
+            Some(verdict(observed <= *max_latency_ms, message, Some(observed)))
+        }
+    }
+}
+
+/// Nearest-rank percentile of non-empty values
+fn percentile(values: &[f64], p: f64) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn assertion_step(assertion: StepAssertion) -> ScenarioStep {
+        ScenarioStep {
+            id: "check".to_string(),
+            chaos_type: None,
+            source_node_id: "node-1".to_string(),
+            target_node_id: Some("node-2".to_string()),
+            start_at: 30.0,
+            duration: 20.0,
+            params: json!({}),
+            lane_id: "lane-1".to_string(),
+            direction: Default::default(),
+            assertion: Some(assertion),
+        }
+    }
+
+    fn sample(at: f64, ok: bool, latency_ms: Option<f64>) -> ProbeSample {
+        ProbeSample {
+            at,
+            ok,
+            latency_ms,
+            linked_blocked: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values = [120.0, 80.0, 300.0, 90.0, 100.0];
+        assert_eq!(percentile(&values, 50.0), 100.0);
+        assert_eq!(percentile(&values, 95.0), 300.0);
+        assert_eq!(percentile(&values, 20.0), 80.0);
+    }
+
+    #[test]
+    fn test_evaluate_assertions() {
+        let now = Utc::now();
+
+        let unreachable = StepAssertion::Reachability { expect_reachable: false };
+        let step = assertion_step(unreachable.clone());
+        assert_eq!(evaluate(&step, &unreachable, &[], false, now), None);
+        let blocked = evaluate(&step, &unreachable, &[sample(30.0, false, None)], false, now);
+        assert!(blocked.unwrap().passed);
+        let reached = evaluate(&step, &unreachable, &[sample(30.0, true, Some(1.0))], false, now);
+        assert_eq!(
+            reached.unwrap().message,
+            "node-1 reached node-2, expected unreachable"
+        );
+
+        let latency = StepAssertion::HttpLatency {
+            from_app_id: "app-x".to_string(),
+            to_app_id: "app-y".to_string(),
+            port: None,
+            path: None,
+            percentile: 95.0,
+            max_latency_ms: 500.0,
+        };
+        let step = assertion_step(latency.clone());
+        let fast: Vec<ProbeSample> =
+            (0..10).map(|i| sample(i as f64, true, Some(100.0))).collect();
+        assert_eq!(evaluate(&step, &latency, &fast, false, now), None);
+        assert!(evaluate(&step, &latency, &fast, true, now).unwrap().passed);
+        let mut slow = fast.clone();
+        slow.push(sample(10.0, false, None));
+        let result = evaluate(&step, &latency, &slow, true, now).unwrap();
+        assert!(!result.passed);
+        assert_eq!(result.observed, None);
+
+        let linked = StepAssertion::LinkedPairsConnected;
+        let step = assertion_step(linked.clone());
+        let mut still_blocked = ProbeSample {
+            linked_blocked: Some(2),
+            ..sample(40.0, false, None)
+        };
+        assert_eq!(evaluate(&step, &linked, &[still_blocked.clone()], false, now), None);
+        let result = evaluate(&step, &linked, &[still_blocked.clone()], true, now).unwrap();
+        assert_eq!(result.message, "2 linked pair(s) still blocked");
+        still_blocked.ok = true;
+        assert!(evaluate(&step, &linked, &[still_blocked], false, now).unwrap().passed);
+    }
+}
@@ -0,0 +1,1141 @@
+//! Scenario run task and manager
+
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::{Id, JoinSet};
+use tokio::time::Duration;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::api::chaos::{activate_condition, build_condition};
+use crate::api::events::{emit_event, EventSeverity, EventSourceType};
+use crate::api::{AppState, Event};
+use crate::chaos::{
+    abort_reason, abort_topology, observe, AbortCriteria, ChaosCondition, ChaosConditionStatus,
+    ChaosRecurrence, CreateChaosRequest, PodSelection, SUPERVISOR_INTERVAL_SECS,
+};
+use super::probes::{evaluate, take_sample, PROBE_INTERVAL_SECS};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ProbeSample, RestartPolicy, RunStepStatus, Scenario, ScenarioRun, ScenarioRunStatus,
+    ScenarioRunStep, ScenarioStep, StepAssertion,
+};
+
+/// Requests sent to the task of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunAction {
+    /// Stop the run, removing the chaos of its steps
+    Cancel,
+    /// Freeze the timeline, removing the chaos of the applied steps when `lift_faults`
+    Pause { lift_faults: bool },
+    Resume,
+    /// Move the timeline to the next step start or end
+    Advance,
+}
+
+struct RunCommand {
+    action: RunAction,
+    reply: oneshot::Sender<AppResult<()>>,
+}
+
+struct RunHandle {
+    commands: mpsc::UnboundedSender<RunCommand>,
+    finished: watch::Receiver<bool>,
+}
+
+/// Samples of the assertion steps of a run, taken on their own tasks so a slow
+/// measurement holds up neither the run commands nor the other steps
+#[derive(Default)]
+struct Sampler {
+    tasks: JoinSet<ProbeSample>,
+    /// Step and timeline position of each sample in flight
+    in_flight: HashMap<Id, (usize, f64)>,
+}
+
+impl Sampler {
+    fn start(
+        &mut self,
+        state: &AppState,
+        run: &ScenarioRun,
+        i: usize,
+        assertion: StepAssertion,
+        at: f64,
+    ) {
+        let state = state.clone();
+        let topology_id = run.topology_id.clone();
+        let step = run.steps[i].step.clone();
+        let task = self
+            .tasks
+            .spawn(async move { take_sample(&state, &topology_id, &step, &assertion, at).await });
+        self.in_flight.insert(task.id(), (i, at));
+    }
+
+    /// Steps with a sample in flight
+    fn steps(&self) -> HashSet<usize> {
+        self.in_flight.values().map(|(i, _)| *i).collect()
+    }
+
+    /// Wait for the next sample and its step, `None` when no sample is in flight
+    async fn next(&mut self) -> Option<(usize, ProbeSample)> {
+        let result = self.tasks.join_next_with_id().await?;
+        let id = match &result {
+            Ok((id, _)) => *id,
+            Err(e) => e.id(),
+        };
+        let (i, at) = self.in_flight.remove(&id)?;
+        let sample = result.map(|(_, sample)| sample).unwrap_or_else(|e| ProbeSample {
+            at,
+            ok: false,
+            latency_ms: None,
+            linked_blocked: None,
+            error: Some(format!("Sampling failed: {}", e)),
+        });
+        Some((i, sample))
+    }
+}
+
+/// Runs executing in this server
+#[derive(Clone, Default)]
+pub struct ScenarioRunManager {
+    runs: Arc<Mutex<HashMap<String, RunHandle>>>,
+}
+
+impl ScenarioRunManager {
+    /// Whether the run is executing in this server
+    pub fn is_running(&self, run_id: &str) -> bool {
+        self.runs.lock().unwrap().contains_key(run_id)
+    }
+
+    fn register(&self, run_id: &str) -> (mpsc::UnboundedReceiver<RunCommand>, watch::Sender<bool>) {
+        let (commands, commands_rx) = mpsc::unbounded_channel();
+        let (finished_tx, finished) = watch::channel(false);
+        self.runs
+            .lock()
+            .unwrap()
+            .insert(run_id.to_string(), RunHandle { commands, finished });
+        (commands_rx, finished_tx)
+    }
+
+    fn unregister(&self, run_id: &str) {
+        self.runs.lock().unwrap().remove(run_id);
+    }
+
+    /// Send a request to a run and wait until it is handled, `None` if it is not executing here
+    ///
+    /// A cancelled run is waited for until it cleaned up.
+    async fn control(&self, run_id: &str, action: RunAction) -> Option<AppResult<()>> {
+        let (reply, replied) = oneshot::channel();
+        let mut finished = {
+            let runs = self.runs.lock().unwrap();
+            let handle = runs.get(run_id)?;
+            let _ = handle.commands.send(RunCommand { action, reply });
+            handle.finished.clone()
+        };
+        // Dropped without a reply: the run finished meanwhile
+        let result = replied.await.unwrap_or(Ok(()));
+        if action == RunAction::Cancel {
+            let _ = finished.wait_for(|finished| *finished).await;
+        }
+        Some(result)
+    }
+}
+
+/// Create request equivalent to a chaos step, `None` for assertion steps
+///
+/// Runs build and start the conditions of their steps from it, through the same path as
+/// the chaos API.
+pub fn step_request(topology_id: &str, step: &ScenarioStep) -> Option<CreateChaosRequest> {
+    Some(CreateChaosRequest {
+        topology_id: topology_id.to_string(),
+        source_node_id: step.source_node_id.clone(),
+        target_node_id: step.target_node_id.clone(),
+        external_targets: None,
+        selection: PodSelection::default(),
+        recurrence: ChaosRecurrence::default(),
+        chaos_type: step.chaos_type.clone()?,
+        direction: step.direction.clone(),
+        // The run lifts the step when it ends on the timeline, which pausing can push back
+        duration: None,
+        params: step.params.clone(),
+        // Scenario-level criteria are evaluated by the run itself
+        abort_criteria: None,
+    })
+}
+
+/// Condition a chaos step would start, without the checks of the chaos API
+///
+/// Used to compare steps with each other before a run; `None` for assertion steps.
+pub fn step_condition(
+    topology_id: &str,
+    step: &ScenarioStep,
+    condition_id: &str,
+) -> Option<ChaosCondition> {
+    let request = step_request(topology_id, step)?;
+    let now = Utc::now();
+    Some(ChaosCondition {
+        id: condition_id.to_string(),
+        topology_id: request.topology_id,
+        source_node_id: request.source_node_id,
+        target_node_id: request.target_node_id,
+        external_targets: Vec::new(),
+        selection: request.selection,
+        recurrence: request.recurrence,
+        chaos_type: request.chaos_type,
+        direction: request.direction,
+        duration: request.duration,
+        params: request.params,
+        abort_criteria: request.abort_criteria,
+        status: ChaosConditionStatus::Pending,
+        k8s_name: None,
+        started_at: None,
+        ends_at: None,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+/// Record a run of the scenario and start executing it
+pub async fn start_run(
+    state: &AppState,
+    scenario: &Scenario,
+    force: bool,
+) -> AppResult<ScenarioRun> {
+    let now = Utc::now();
+    let run = ScenarioRun {
+        id: Uuid::new_v4().to_string(),
+        scenario_id: scenario.id.clone(),
+        topology_id: scenario.topology_id.clone(),
+        status: ScenarioRunStatus::Running,
+        force,
+        restart_policy: scenario.restart_policy,
+        total_duration: scenario.total_duration,
+        abort_criteria: scenario.abort_criteria.as_ref().map(|c| c.0.clone()),
+        steps: scenario.steps.0.iter().cloned().map(ScenarioRunStep::new).collect(),
+        error: None,
+        time_shift: 0.0,
+        paused_at: None,
+        started_at: now,
+        finished_at: None,
+        updated_at: now,
+    };
+    state.db.create_scenario_run(&run).await?;
+
+    info!("Starting run {} of scenario {}", run.id, scenario.name);
+    let _ = state.event_tx.send(Event::ScenarioRunStarted {
+        run_id: run.id.clone(),
+        scenario_id: run.scenario_id.clone(),
+        topology_id: run.topology_id.clone(),
+    });
+
+    let (commands, finished) = state.runs.register(&run.id);
+    tokio::spawn(execute_run(state.clone(), run.clone(), commands, finished));
+
+    Ok(run)
+}
+
+/// Handle the runs a previous server process left `running`, per their `RestartPolicy`
+///
+/// Returns the runs after recovery: resumed runs are `running` or `paused` again, the others
+/// `rolled_back`, or `failed` when the chaos of a step could not be removed. Needs the
+/// chaos backend reachable, so it is called once Kubernetes is connected.
+pub async fn recover_scenario_runs(state: &AppState) -> AppResult<Vec<ScenarioRun>> {
+    let mut recovered = Vec::new();
+    for mut run in state.db.list_running_scenario_runs().await? {
+        if state.runs.is_running(&run.id) {
+            continue;
+        }
+
+        match run.restart_policy {
+            RestartPolicy::Resume => resume_interrupted_run(state, &mut run).await,
+            RestartPolicy::Rollback => {
+                info!("Rolling back scenario run {} after a restart", run.id);
+                let status = if stop_run(state, &mut run, ChaosConditionStatus::Paused)
+                    .await
+                    .is_empty()
+                {
+                    run.error = Some("Rolled back after a server restart".to_string());
+                    ScenarioRunStatus::RolledBack
+                } else {
+                    run.error = Some("Rollback after a server restart failed".to_string());
+                    ScenarioRunStatus::Failed
+                };
+                finish_run(state, &mut run, status).await;
+            }
+        }
+        recovered.push(run);
+    }
+
+    Ok(recovered)
+}
+
+/// Continue a run where its timeline is now, skipping the steps that ended meanwhile
+///
+/// The downtime counts as elapsed time, unless the run was paused.
+async fn resume_interrupted_run(state: &AppState, run: &mut ScenarioRun) {
+    let position = timeline_position(run, Utc::now());
+    info!("Resuming scenario run {} at {:.1}s", run.id, position);
+
+    for i in 0..run.steps.len() {
+        let step = &mut run.steps[i];
+        if step.status == RunStepStatus::Scheduled && step_end(&step.step) <= position {
+            step.status = RunStepStatus::Skipped;
+            step.error = Some("Missed while the server was down".to_string());
+            save_step(state, run, i).await;
+        }
+    }
+
+    emit_event(
+        state,
+        Some(&run.topology_id),
+        "scenario",
+        Some("resumed"),
+        EventSeverity::Info,
+        "Scenario run resumed",
+        Some(&format!("Resumed at {:.1}s after a server restart", position)),
+        Some(EventSourceType::Scenario),
+        Some(&run.scenario_id),
+        Some(serde_json::json!({ "run_id": run.id })),
+    )
+    .await;
+
+    let (commands, finished) = state.runs.register(&run.id);
+    tokio::spawn(execute_run(state.clone(), run.clone(), commands, finished));
+}
+
+/// Freeze the timeline of a run
+///
+/// With `lift_faults` the chaos of the applied steps is removed until the run resumes,
+/// otherwise it is held in place.
+pub async fn pause_run(
+    state: &AppState,
+    run_id: &str,
+    lift_faults: bool,
+) -> AppResult<ScenarioRun> {
+    control_run(state, run_id, RunAction::Pause { lift_faults }).await
+}
+
+/// Restart the timeline of a paused run, injecting again the faults lifted by the pause
+pub async fn resume_run(state: &AppState, run_id: &str) -> AppResult<ScenarioRun> {
+    control_run(state, run_id, RunAction::Resume).await
+}
+
+/// Move the timeline of a run to its next step start or end, and apply or lift that step
+///
+/// A paused run stays paused, so it can be stepped through.
+pub async fn advance_run(state: &AppState, run_id: &str) -> AppResult<ScenarioRun> {
+    control_run(state, run_id, RunAction::Advance).await
+}
+
+/// Cancel a run, removing the chaos of the steps it applied
+///
+/// The run fails instead when the chaos of a step cannot be removed. Runs left `running`
+/// by a previous server process are cleaned up from their saved state.
+pub async fn cancel_run(state: &AppState, run_id: &str) -> AppResult<ScenarioRun> {
+    control_run(state, run_id, RunAction::Cancel).await
+}
+
+/// Cancel the unfinished runs, of one topology if given, waiting until each cleaned up
+///
+/// Used before sweeping chaos, so runs do not inject their next steps again. Returns the
+/// cancelled runs.
+pub async fn cancel_runs(state: &AppState, topology_id: Option<&str>) -> AppResult<Vec<String>> {
+    let mut cancelled = Vec::new();
+    for run in state.db.list_running_scenario_runs().await? {
+        if topology_id.is_some_and(|id| id != run.topology_id) {
+            continue;
+        }
+        match cancel_run(state, &run.id).await {
+            Ok(_) => cancelled.push(run.id),
+            // Finished meanwhile
+            Err(AppError::Conflict(_)) => {}
+            Err(e) => warn!("Failed to cancel scenario run {}: {}", run.id, e),
+        }
+    }
+    Ok(cancelled)
+}
+
+async fn control_run(state: &AppState, run_id: &str, action: RunAction) -> AppResult<ScenarioRun> {
+    let mut run = state
+        .db
+        .get_scenario_run(run_id)
+        .await?
+        .ok_or_else(|| AppError::not_found(&format!("Scenario run {} not found", run_id)))?;
+    if run.status.is_finished() {
+        return Err(AppError::Conflict(format!(
+            "Scenario run {} already {}",
+            run_id, run.status
+        )));
+    }
+
+    match state.runs.control(run_id, action).await {
+        Some(result) => {
+            result?;
+            state
+                .db
+                .get_scenario_run(run_id)
+                .await?
+                .ok_or_else(|| AppError::not_found(&format!("Scenario run {} not found", run_id)))
+        }
+        None if action == RunAction::Cancel => {
+            warn!("Scenario run {} is not executing, cleaning up its saved state", run_id);
+            let status = cancel(state, &mut run).await;
+            finish_run(state, &mut run, status).await;
+            Ok(run)
+        }
+        None => Err(AppError::Conflict(format!(
+            "Scenario run {} is not executing in this server",
+            run_id
+        ))),
+    }
+}
+
+/// Apply and lift the steps on the scenario timeline until they are all done
+async fn execute_run(
+    state: AppState,
+    mut run: ScenarioRun,
+    mut commands: mpsc::UnboundedReceiver<RunCommand>,
+    finished: watch::Sender<bool>,
+) {
+    // Measured on its own task, so a slow check does not hold up the run commands
+    let (abort_tx, mut abort_rx) = mpsc::unbounded_channel();
+    let supervisor = run.abort_criteria.clone().map(|criteria| {
+        let topology_id = run.topology_id.clone();
+        tokio::spawn(supervise_run(state.clone(), topology_id, run.started_at, criteria, abort_tx))
+    });
+
+    let mut sampler = Sampler::default();
+    let status = loop {
+        let position = timeline_position(&run, Utc::now());
+        run_due_steps(&state, &mut run, position, &mut sampler).await;
+
+        // Steps with a sample in flight wait for it rather than for the timeline
+        let sampling = sampler.steps();
+        let next = next_transition(&run.steps, &sampling);
+        if next.is_none() && sampling.is_empty() {
+            break if run.steps.iter().any(|s| s.status == RunStepStatus::Failed) {
+                ScenarioRunStatus::Failed
+            } else {
+                ScenarioRunStatus::Completed
+            };
+        }
+        let wait = next.map_or(0.0, |next| (next - position).max(0.0));
+
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_secs_f64(wait)),
+                if run.paused_at.is_none() && next.is_some() => {}
+            command = commands.recv() => {
+                let (action, reply) = match command {
+                    Some(RunCommand { action, reply }) => (action, Some(reply)),
+                    None => (RunAction::Cancel, None),
+                };
+                let result = match action {
+                    RunAction::Cancel => {
+                        info!("Cancelling scenario run {}", run.id);
+                        let status = cancel(&state, &mut run).await;
+                        if let Some(reply) = reply {
+                            let _ = reply.send(Ok(()));
+                        }
+                        break status;
+                    }
+                    RunAction::Pause { lift_faults } => pause(&state, &mut run, lift_faults).await,
+                    RunAction::Resume => resume(&state, &mut run, &mut sampler).await,
+                    RunAction::Advance => advance(&state, &mut run, &mut sampler).await,
+                };
+                if let Some(reply) = reply {
+                    let _ = reply.send(result);
+                }
+            }
+            Some((i, sample)) = sampler.next(), if !sampling.is_empty() => {
+                record_sample(&state, &mut run, i, sample, &mut sampler).await;
+            }
+            Some(reason) = abort_rx.recv(), if supervisor.is_some() => {
+                abort_run(&state, &mut run, reason).await;
+                break ScenarioRunStatus::Aborted;
+            }
+        }
+    };
+    if let Some(supervisor) = supervisor {
+        supervisor.abort();
+    }
+
+    finish_run(&state, &mut run, status).await;
+    let _ = finished.send(true);
+    state.runs.unregister(&run.id);
+}
+
+/// Apply the steps that started and lift the steps that ended at `position`
+///
+/// Steps suspended by a pause are injected again once the run resumes, and assertion
+/// steps take their samples.
+async fn run_due_steps(
+    state: &AppState,
+    run: &mut ScenarioRun,
+    position: f64,
+    sampler: &mut Sampler,
+) {
+    for i in 0..run.steps.len() {
+        let step = &run.steps[i];
+        let ended = step_end(&step.step) <= position;
+        match step.status {
+            RunStepStatus::Scheduled if step.step.start_at <= position => {
+                if step.step.assertion.is_some() {
+                    run.steps[i].status = RunStepStatus::Probing;
+                    save_step(state, run, i).await;
+                    probe_step(state, run, i, position, sampler).await
+                } else {
+                    apply_step(state, run, i).await
+                }
+            }
+            RunStepStatus::Probing => probe_step(state, run, i, position, sampler).await,
+            RunStepStatus::Applied if ended => {
+                let completed = ChaosConditionStatus::Completed;
+                lift_step(state, run, i, completed, RunStepStatus::CleanedUp).await;
+            }
+            RunStepStatus::Suspended if ended => {
+                if let Some(condition_id) = &run.steps[i].condition_id {
+                    let _ = state
+                        .db
+                        .update_chaos_condition_status(
+                            condition_id,
+                            &ChaosConditionStatus::Completed,
+                            None,
+                        )
+                        .await;
+                }
+                run.steps[i].status = RunStepStatus::CleanedUp;
+                run.steps[i].cleaned_up_at = Some(Utc::now());
+                save_step(state, run, i).await;
+            }
+            RunStepStatus::Suspended if run.paused_at.is_none() => {
+                apply_step(state, run, i).await
+            }
+            _ => {}
+        }
+    }
+}
+
+async fn pause(state: &AppState, run: &mut ScenarioRun, lift_faults: bool) -> AppResult<()> {
+    if run.paused_at.is_some() {
+        return Err(AppError::Conflict(format!(
+            "Scenario run {} is already paused",
+            run.id
+        )));
+    }
+
+    let position = timeline_position(run, Utc::now());
+    run.paused_at = Some(position);
+    run.status = ScenarioRunStatus::Paused;
+    if lift_faults {
+        for i in 0..run.steps.len() {
+            if run.steps[i].status == RunStepStatus::Applied {
+                let paused = ChaosConditionStatus::Paused;
+                lift_step(state, run, i, paused, RunStepStatus::Suspended).await;
+            }
+        }
+    }
+    save_run(state, run).await;
+
+    info!("Scenario run {} paused at {:.1}s", run.id, position);
+    let _ = state.event_tx.send(Event::ScenarioRunPaused {
+        run_id: run.id.clone(),
+        position,
+        lift_faults,
+    });
+    let faults = if lift_faults { "lifted" } else { "held" };
+    let description = format!("Paused at {:.1}s, active faults {}", position, faults);
+    record_clock(state, run, "paused", position, &description).await;
+    Ok(())
+}
+
+async fn resume(state: &AppState, run: &mut ScenarioRun, sampler: &mut Sampler) -> AppResult<()> {
+    let Some(position) = run.paused_at.take() else {
+        return Err(AppError::Conflict(format!("Scenario run {} is not paused", run.id)));
+    };
+
+    set_timeline_position(run, position, Utc::now());
+    run.status = ScenarioRunStatus::Running;
+    save_run(state, run).await;
+    run_due_steps(state, run, position, sampler).await;
+
+    info!("Scenario run {} resumed at {:.1}s", run.id, position);
+    let _ = state.event_tx.send(Event::ScenarioRunResumed {
+        run_id: run.id.clone(),
+        position,
+    });
+    let description = format!("Resumed at {:.1}s", position);
+    record_clock(state, run, "resumed", position, &description).await;
+    Ok(())
+}
+
+/// Move the timeline to the next step transition, and run the step
+///
+/// The samples in flight are recorded first, so stepping through an assertion takes each
+/// of its samples in turn.
+async fn advance(state: &AppState, run: &mut ScenarioRun, sampler: &mut Sampler) -> AppResult<()> {
+    for _ in 0..sampler.in_flight.len() {
+        if let Some((i, sample)) = sampler.next().await {
+            record_sample(state, run, i, sample, sampler).await;
+        }
+    }
+    // Only a sample taken meanwhile is left to wait for
+    let Some(next) = next_transition(&run.steps, &sampler.steps()) else {
+        return Ok(());
+    };
+
+    let now = Utc::now();
+    let position = timeline_position(run, now).max(next);
+    set_timeline_position(run, position, now);
+    save_run(state, run).await;
+    run_due_steps(state, run, position, sampler).await;
+
+    info!("Scenario run {} advanced to {:.1}s", run.id, position);
+    let _ = state.event_tx.send(Event::ScenarioRunAdvanced {
+        run_id: run.id.clone(),
+        position,
+    });
+    let description = format!("Advanced to {:.1}s", position);
+    record_clock(state, run, "advanced", position, &description).await;
+    Ok(())
+}
+
+async fn record_clock(
+    state: &AppState,
+    run: &ScenarioRun,
+    subtype: &str,
+    position: f64,
+    description: &str,
+) {
+    emit_event(
+        state,
+        Some(&run.topology_id),
+        "scenario",
+        Some(subtype),
+        EventSeverity::Info,
+        &format!("Scenario run {}", subtype),
+        Some(description),
+        Some(EventSourceType::Scenario),
+        Some(&run.scenario_id),
+        Some(serde_json::json!({ "run_id": run.id, "position": position })),
+    )
+    .await;
+}
+
+/// Position on the timeline of a run, in seconds
+fn timeline_position(run: &ScenarioRun, now: DateTime<Utc>) -> f64 {
+    match run.paused_at {
+        Some(position) => position,
+        None => {
+            (now - run.started_at).to_std().unwrap_or_default().as_secs_f64() - run.time_shift
+        }
+    }
+}
+
+/// Move the timeline of a run to `position`, paused or not
+fn set_timeline_position(run: &mut ScenarioRun, position: f64, now: DateTime<Utc>) {
+    match run.paused_at {
+        Some(_) => run.paused_at = Some(position),
+        None => {
+            let elapsed = (now - run.started_at).to_std().unwrap_or_default().as_secs_f64();
+            run.time_shift = elapsed - position;
+        }
+    }
+}
+
+/// Seconds from the start of the run at which a step ends
+fn step_end(step: &ScenarioStep) -> f64 {
+    step.start_at + step.duration
+}
+
+/// Offset of the next step to apply, lift or sample, `None` once every step is done
+///
+/// Steps in `sampling` have a sample in flight and are left out.
+fn next_transition(steps: &[ScenarioRunStep], sampling: &HashSet<usize>) -> Option<f64> {
+    steps
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !sampling.contains(i))
+        .filter_map(|(_, s)| match s.status {
+            RunStepStatus::Scheduled => Some(s.step.start_at),
+            RunStepStatus::Applied | RunStepStatus::Suspended => Some(step_end(&s.step)),
+            RunStepStatus::Probing => Some(match s.samples.last() {
+                Some(last) => (last.at + PROBE_INTERVAL_SECS).min(step_end(&s.step)),
+                None => s.step.start_at,
+            }),
+            _ => None,
+        })
+        .min_by(f64::total_cmp)
+}
+
+/// Evaluate the abort criteria of a run periodically, sending why they trip
+async fn supervise_run(
+    state: AppState,
+    topology_id: String,
+    started_at: DateTime<Utc>,
+    criteria: AbortCriteria,
+    abort: mpsc::UnboundedSender<String>,
+) {
+    let mut interval = tokio::time::interval(Duration::from_secs(SUPERVISOR_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        let observations = observe(&state, &topology_id, &[&criteria]).await;
+        if let Some(reason) = abort_reason(&criteria, Some(started_at), Utc::now(), &observations) {
+            let _ = abort.send(reason);
+            return;
+        }
+    }
+}
+
+async fn apply_step(state: &AppState, run: &mut ScenarioRun, i: usize) {
+    let step = run.steps[i].step.clone();
+    let condition = match run.steps[i].condition_id.clone() {
+        // Lifted by a pause: inject the same condition again
+        Some(condition_id) => match state.db.get_chaos_condition(&condition_id).await {
+            Ok(Some(condition)) => condition,
+            result => {
+                let error = match result {
+                    Err(e) => e.to_string(),
+                    _ => format!("Chaos condition {} not found", condition_id),
+                };
+                fail_step(state, run, i, error).await;
+                return;
+            }
+        },
+        None => {
+            let Some(request) = step_request(&run.topology_id, &step) else {
+                fail_step(state, run, i, "Not a chaos step".to_string()).await;
+                return;
+            };
+            // Same validation and defaults as `POST /api/chaos`
+            let condition = match build_condition(state, request).await {
+                Ok(condition) => condition,
+                Err(e) => {
+                    fail_step(state, run, i, e.to_string()).await;
+                    return;
+                }
+            };
+
+            // 1. Persist condition in DB
+            if let Err(e) = state.db.create_chaos_condition(&condition).await {
+                error!("Failed to create chaos condition for step: {}", e);
+                let error = format!("Failed to create chaos condition: {}", e);
+                fail_step(state, run, i, error).await;
+                return;
+            }
+            run.steps[i].condition_id = Some(condition.id.clone());
+            condition
+        }
+    };
+
+    info!("Executing Scenario Step: {} on {}", condition.chaos_type, step.source_node_id);
+
+    // 2. Start it like `POST /api/topologies/:id/chaos/:condition_id/start`, checking the
+    // conditions started meanwhile by the API or other scenarios
+    let mut condition = condition;
+    match activate_condition(state, &mut condition, run.force).await {
+        Ok(()) => {
+            run.steps[i].status = RunStepStatus::Applied;
+            run.steps[i].applied_at = Some(Utc::now());
+            save_step(state, run, i).await;
+        }
+        Err(AppError::Conflict(message)) => {
+            warn!("Skipping scenario step {}: {}", step.id, message);
+            emit_event(
+                state,
+                Some(&run.topology_id),
+                "chaos",
+                Some("conflict"),
+                EventSeverity::Warning,
+                &format!("Scenario step {} skipped", step.id),
+                Some(&message),
+                Some(EventSourceType::Chaos),
+                Some(&condition.id),
+                None,
+            )
+            .await;
+            fail_step(state, run, i, message).await;
+        }
+        Err(e) => {
+            error!("Failed to apply chaos to K8s: {}", e);
+            fail_step(state, run, i, e.to_string()).await;
+        }
+    }
+}
+
+/// Sample an assertion step if a sample is due, and record its verdict once decided
+///
+/// The sample is taken by `sampler`; the step is decided once it is recorded.
+async fn probe_step(
+    state: &AppState,
+    run: &mut ScenarioRun,
+    i: usize,
+    position: f64,
+    sampler: &mut Sampler,
+) {
+    let step = run.steps[i].step.clone();
+    let Some(assertion) = step.assertion.clone() else {
+        return;
+    };
+    if sampler.steps().contains(&i) {
+        return;
+    }
+    let end = step_end(&step);
+    let ended = end <= position;
+
+    // One last sample at the end of the window
+    let due = run.steps[i].samples.last().is_none_or(|last| {
+        last.at + PROBE_INTERVAL_SECS <= position || (ended && last.at < end)
+    });
+    if due {
+        sampler.start(state, run, i, assertion, position);
+        return;
+    }
+
+    let samples = &run.steps[i].samples;
+    let Some(result) = evaluate(&step, &assertion, samples, ended, Utc::now()) else {
+        return;
+    };
+
+    info!("Assertion step {}: {}", step.id, result.message);
+    let (status, subtype, severity) = if result.passed {
+        (RunStepStatus::Passed, "assertion_passed", EventSeverity::Success)
+    } else {
+        (RunStepStatus::Failed, "assertion_failed", EventSeverity::Error)
+    };
+    emit_event(
+        state,
+        Some(&run.topology_id),
+        "scenario",
+        Some(subtype),
+        severity,
+        &format!("Assertion step {} {}", step.id, if result.passed { "passed" } else { "failed" }),
+        Some(&result.message),
+        Some(EventSourceType::Scenario),
+        Some(&run.scenario_id),
+        Some(serde_json::json!({ "run_id": run.id, "step_id": step.id, "result": result })),
+    )
+    .await;
+
+    run.steps[i].status = status;
+    if !result.passed {
+        run.steps[i].error = Some(result.message.clone());
+    }
+    run.steps[i].result = Some(result);
+    save_step(state, run, i).await;
+}
+
+/// Record a sample of an assertion step, and decide the step if it can be
+async fn record_sample(
+    state: &AppState,
+    run: &mut ScenarioRun,
+    i: usize,
+    sample: ProbeSample,
+    sampler: &mut Sampler,
+) {
+    // Skipped by a cancel meanwhile
+    if run.steps[i].status != RunStepStatus::Probing {
+        return;
+    }
+    run.steps[i].samples.push(sample);
+    save_run(state, run).await;
+
+    let position = timeline_position(run, Utc::now());
+    probe_step(state, run, i, position, sampler).await;
+}
+
+/// Remove the chaos of a step, marking its condition with `status` and the step with
+/// `step_status` (`cleaned_up`, or `suspended` by a pause)
+///
+/// Returns whether the chaos was removed; on failure the step is failed instead.
+async fn lift_step(
+    state: &AppState,
+    run: &mut ScenarioRun,
+    i: usize,
+    status: ChaosConditionStatus,
+    step_status: RunStepStatus,
+) -> bool {
+    let Some(condition_id) = run.steps[i].condition_id.clone() else {
+        return true;
+    };
+
+    match state.chaos.delete(&run.topology_id, &condition_id).await {
+        Ok(()) => {
+            let _ = state
+                .db
+                .update_chaos_condition_status(&condition_id, &status, None)
+                .await;
+            run.steps[i].status = step_status;
+            if step_status == RunStepStatus::CleanedUp {
+                run.steps[i].cleaned_up_at = Some(Utc::now());
+            }
+            save_step(state, run, i).await;
+            true
+        }
+        Err(e) => {
+            error!("Failed to cleanup chaos: {}", e);
+            fail_step(state, run, i, format!("Cleanup failed: {}", e)).await;
+            false
+        }
+    }
+}
+
+async fn fail_step(state: &AppState, run: &mut ScenarioRun, i: usize, error: String) {
+    if let Some(condition_id) = &run.steps[i].condition_id {
+        let _ = state
+            .db
+            .update_chaos_condition_status(condition_id, &ChaosConditionStatus::Failed, None)
+            .await;
+    }
+    run.steps[i].status = RunStepStatus::Failed;
+    run.steps[i].error = Some(error);
+    save_step(state, run, i).await;
+}
+
+/// Remove the chaos of every step the run applied and skip the pending ones and the
+/// assertions still sampling, returning the steps whose chaos could not be removed
+async fn stop_run(
+    state: &AppState,
+    run: &mut ScenarioRun,
+    status: ChaosConditionStatus,
+) -> Vec<String> {
+    let mut not_lifted = Vec::new();
+    for i in 0..run.steps.len() {
+        match run.steps[i].status {
+            RunStepStatus::Applied => {
+                if !lift_step(state, run, i, status.clone(), RunStepStatus::CleanedUp).await {
+                    not_lifted.push(run.steps[i].step.id.clone());
+                }
+            }
+            // Already lifted by the pause
+            RunStepStatus::Suspended => {
+                run.steps[i].status = RunStepStatus::CleanedUp;
+                run.steps[i].cleaned_up_at = Some(Utc::now());
+                save_step(state, run, i).await;
+            }
+            RunStepStatus::Scheduled | RunStepStatus::Probing => {
+                run.steps[i].status = RunStepStatus::Skipped;
+                save_step(state, run, i).await;
+            }
+            // A failed cleanup may have left the resource behind
+            RunStepStatus::Failed => {
+                if let Some(condition_id) = &run.steps[i].condition_id {
+                    if let Err(e) = state.chaos.delete(&run.topology_id, condition_id).await {
+                        warn!("Failed to cleanup chaos {}: {}", condition_id, e);
+                        not_lifted.push(run.steps[i].step.id.clone());
+                    }
+                }
+            }
+            RunStepStatus::CleanedUp | RunStepStatus::Skipped | RunStepStatus::Passed => {}
+        }
+    }
+    not_lifted
+}
+
+/// Stop a cancelled run, which fails when the chaos of a step is left in the cluster
+async fn cancel(state: &AppState, run: &mut ScenarioRun) -> ScenarioRunStatus {
+    let not_lifted = stop_run(state, run, ChaosConditionStatus::Paused).await;
+    if not_lifted.is_empty() {
+        return ScenarioRunStatus::Cancelled;
+    }
+    run.error = Some(format!(
+        "Cancel could not remove chaos of step {}",
+        not_lifted.join(", ")
+    ));
+    ScenarioRunStatus::Failed
+}
+
+/// The abort criteria tripped: stop all chaos of the topology
+async fn abort_run(state: &AppState, run: &mut ScenarioRun, reason: String) {
+    // Finished first, so the abort does not cancel this run (nor wait for it when another
+    // run aborts the topology meanwhile)
+    run.status = ScenarioRunStatus::Aborted;
+    save_run(state, run).await;
+
+    if let Err(e) = abort_topology(
+        state,
+        &run.topology_id,
+        &reason,
+        EventSourceType::Scenario,
+        &run.scenario_id,
+    )
+    .await
+    {
+        error!("Failed to abort scenario run {}: {}", run.id, e);
+    }
+
+    // The abort removed the chaos and paused the conditions
+    let now = Utc::now();
+    for i in 0..run.steps.len() {
+        let step = &mut run.steps[i];
+        match step.status {
+            RunStepStatus::Applied | RunStepStatus::Suspended => {
+                step.status = RunStepStatus::CleanedUp;
+                step.cleaned_up_at = Some(now);
+            }
+            RunStepStatus::Scheduled | RunStepStatus::Probing => {
+                step.status = RunStepStatus::Skipped
+            }
+            _ => continue,
+        }
+        save_step(state, run, i).await;
+    }
+    run.error = Some(reason);
+}
+
+/// Save the run and stream the new state of one of its steps
+async fn save_step(state: &AppState, run: &mut ScenarioRun, i: usize) {
+    save_run(state, run).await;
+
+    let step = &run.steps[i];
+    let _ = state.event_tx.send(Event::ScenarioStepUpdated {
+        run_id: run.id.clone(),
+        step_id: step.step.id.clone(),
+        status: step.status.to_string(),
+        condition_id: step.condition_id.clone(),
+        error: step.error.clone(),
+    });
+}
+
+async fn save_run(state: &AppState, run: &mut ScenarioRun) {
+    run.updated_at = Utc::now();
+    if let Err(e) = state.db.update_scenario_run(run).await {
+        error!("Failed to save scenario run {}: {}", run.id, e);
+    }
+}
+
+async fn finish_run(state: &AppState, run: &mut ScenarioRun, status: ScenarioRunStatus) {
+    let (failed_assertions, failed_steps): (Vec<_>, Vec<_>) = run
+        .steps
+        .iter()
+        .filter(|s| s.status == RunStepStatus::Failed)
+        .partition(|s| s.step.assertion.is_some());
+    if run.error.is_none() {
+        let mut failures = Vec::new();
+        if !failed_steps.is_empty() {
+            failures.push(format!("{} step(s) failed", failed_steps.len()));
+        }
+        if !failed_assertions.is_empty() {
+            failures.push(format!("{} assertion(s) failed", failed_assertions.len()));
+        }
+        if !failures.is_empty() {
+            run.error = Some(failures.join(", "));
+        }
+    }
+    run.status = status;
+    run.finished_at = Some(Utc::now());
+    save_run(state, run).await;
+
+    info!("Scenario run {} {}", run.id, run.status);
+    let _ = state.event_tx.send(Event::ScenarioRunFinished {
+        run_id: run.id.clone(),
+        scenario_id: run.scenario_id.clone(),
+        status: run.status.to_string(),
+        error: run.error.clone(),
+    });
+
+    let severity = match status {
+        ScenarioRunStatus::Completed => EventSeverity::Success,
+        ScenarioRunStatus::Cancelled
+        | ScenarioRunStatus::RolledBack
+        | ScenarioRunStatus::Running
+        | ScenarioRunStatus::Paused => EventSeverity::Warning,
+        ScenarioRunStatus::Failed | ScenarioRunStatus::Aborted => EventSeverity::Error,
+    };
+    emit_event(
+        state,
+        Some(&run.topology_id),
+        "scenario",
+        Some(&run.status.to_string()),
+        severity,
+        &format!("Scenario run {}", run.status),
+        run.error.as_deref(),
+        Some(EventSourceType::Scenario),
+        Some(&run.scenario_id),
+        Some(serde_json::json!({ "run_id": run.id })),
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chaos::ChaosType;
+    use serde_json::json;
+
+    fn run_step(id: &str, start_at: f64, duration: f64, status: RunStepStatus) -> ScenarioRunStep {
+        ScenarioRunStep {
+            status,
+            ..ScenarioRunStep::new(ScenarioStep {
+                id: id.to_string(),
+                chaos_type: Some(ChaosType::Delay),
+                source_node_id: "node-1".to_string(),
+                target_node_id: None,
+                start_at,
+                duration,
+                params: json!({"latency": "100ms"}),
+                lane_id: "lane-1".to_string(),
+                direction: Default::default(),
+                assertion: None,
+            })
+        }
+    }
+
+    #[test]
+    fn test_next_transition() {
+        let mut steps = vec![
+            run_step("a", 0.0, 10.0, RunStepStatus::Applied),
+            run_step("b", 5.0, 1.0, RunStepStatus::Scheduled),
+            run_step("c", 1.0, 1.0, RunStepStatus::CleanedUp),
+        ];
+        let none = HashSet::new();
+        // b starts before a ends, c is done
+        assert_eq!(next_transition(&steps, &none), Some(5.0));
+
+        steps[1].status = RunStepStatus::Failed;
+        assert_eq!(next_transition(&steps, &none), Some(10.0));
+
+        // A step with a sample in flight waits for it
+        steps[2].status = RunStepStatus::Probing;
+        assert_eq!(next_transition(&steps, &none), Some(1.0));
+        assert_eq!(next_transition(&steps, &HashSet::from([2])), Some(10.0));
+
+        steps[0].status = RunStepStatus::CleanedUp;
+        steps[2].status = RunStepStatus::Passed;
+        assert_eq!(next_transition(&steps, &none), None);
+    }
+
+    #[test]
+    fn test_timeline_position() {
+        let now = Utc::now();
+        let mut run = ScenarioRun {
+            id: "run-1".to_string(),
+            scenario_id: "scenario-1".to_string(),
+            topology_id: "topology-1".to_string(),
+            status: ScenarioRunStatus::Running,
+            force: false,
+            restart_policy: RestartPolicy::Rollback,
+            total_duration: 60,
+            abort_criteria: None,
+            steps: Vec::new(),
+            error: None,
+            time_shift: 0.0,
+            paused_at: None,
+            started_at: now - chrono::Duration::seconds(20),
+            finished_at: None,
+            updated_at: now,
+        };
+        assert_eq!(timeline_position(&run, now), 20.0);
+
+        // Advanced by 10s while running
+        set_timeline_position(&mut run, 30.0, now);
+        assert_eq!(timeline_position(&run, now + chrono::Duration::seconds(5)), 35.0);
+
+        // Frozen while paused, then restarts from there
+        run.paused_at = Some(30.0);
+        assert_eq!(timeline_position(&run, now + chrono::Duration::seconds(60)), 30.0);
+        run.paused_at = None;
+        set_timeline_position(&mut run, 30.0, now + chrono::Duration::seconds(60));
+        assert_eq!(timeline_position(&run, now + chrono::Duration::seconds(62)), 32.0);
+    }
+}
@@ -9,7 +9,23 @@ use axum::{
 use serde_json::{json, Value};
 use tower::ServiceExt;
 
-use networksim_backend::{api::AppState, config::Config, db::Database};
+use std::sync::Arc;
+
+use networksim_backend::{
+    api::AppState,
+    chaos::{
+        expire_chaos_conditions, supervise_chaos, ChaosBackend, ChaosCondition,
+        ChaosConditionStatus, ChaosStatus, InMemoryChaosBackend,
+    },
+    config::Config,
+    db::Database,
+    error::{AppError, AppResult},
+    models::{
+        AppStatus, Application, RunStepStatus, ScenarioRun, ScenarioRunStatus, ScenarioRunStep,
+        ScenarioStep,
+    },
+    scenarios::{recover_scenario_runs, step_condition},
+};
 
 async fn setup_app() -> axum::Router {
     let config = Config::default();
@@ -20,6 +36,45 @@ async fn setup_app() -> axum::Router {
     networksim_backend::create_router(state)
 }
 
+async fn setup_app_with_backend(
+    config: Option<Config>,
+) -> (axum::Router, AppState, InMemoryChaosBackend) {
+    let config = config.unwrap_or_default();
+    let db = Database::new("sqlite::memory:").await.unwrap();
+    db.run_migrations().await.unwrap();
+    let backend = InMemoryChaosBackend::new(&config.k8s_namespace_sim);
+    let state = AppState::new(db, config).with_chaos_backend(Arc::new(backend.clone()));
+
+    (networksim_backend::create_router(state.clone()), state, backend)
+}
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    payload: Option<Value>,
+) -> (StatusCode, Value) {
+    let body = payload.map_or_else(Body::empty, |p| Body::from(p.to_string()));
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(body)
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
 async fn create_test_topology(app: &axum::Router) -> String {
     let payload = json!({
         "name": "Chaos Test Topology",
@@ -218,35 +273,45 @@ async fn test_create_partition_chaos() {
 }
 
 #[tokio::test]
-async fn test_chaos_for_nonexistent_topology() {
+async fn test_create_container_kill_unknown_container() {
     let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "container-kill",
+        "direction": "to",
+        "params": {"container_names": ["does-not-exist"]}
+    });
 
     let response = app
+        .clone()
         .oneshot(
             Request::builder()
-                .uri("/api/topologies/nonexistent-id/chaos")
-                .body(Body::empty())
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
                 .unwrap(),
         )
         .await
         .unwrap();
 
-    // Should return 404 or empty array
-    let status = response.status();
-    assert!(status == StatusCode::OK || status == StatusCode::NOT_FOUND);
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
-async fn test_chaos_invalid_node_id() {
+async fn test_create_container_kill_main_container() {
     let app = setup_app().await;
     let topology_id = create_test_topology(&app).await;
 
     let chaos_payload = json!({
         "topology_id": topology_id,
-        "source_node_id": "nonexistent-node",
-        "chaos_type": "delay",
+        "source_node_id": "node-1",
+        "chaos_type": "container-kill",
         "direction": "to",
-        "params": {"latency": "100ms"}
+        "params": {"container_names": ["main"]}
     });
 
     let response = app
@@ -262,54 +327,2659 @@ async fn test_chaos_invalid_node_id() {
         .await
         .unwrap();
 
-    // Should fail validation or K8s apply
-    let status = response.status();
-    // We accept various error codes since this depends on implementation
-    assert!(status != StatusCode::OK || status == StatusCode::BAD_REQUEST || status == StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.status(), StatusCode::OK);
 }
 
 #[tokio::test]
-async fn test_chaos_types_serialization() {
-    // Test that all chaos types serialize correctly in requests
-    let chaos_types = vec![
-        ("delay", json!({"latency": "100ms"})),
-        ("loss", json!({"loss": "10"})),
-        ("bandwidth", json!({"rate": "1mbps"})),
-        ("corrupt", json!({"corrupt": "5"})),
-        ("duplicate", json!({"duplicate": "10"})),
-        ("partition", json!({})),
-    ];
+async fn test_create_http_delay_invalid_params() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
 
-    for (chaos_type, params) in chaos_types {
-        let request = json!({
-            "topology_id": "test-id",
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "http-delay",
+        "direction": "to",
+        "params": {"delay": "fast", "method": "FETCH"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+    let fields: Vec<&str> = json["error"]["details"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["params.method", "params.delay"]);
+}
+
+#[tokio::test]
+async fn test_create_chaos_rejects_invalid_params() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    for (chaos_type, params, field) in [
+        ("delay", json!({"latncy": "100ms"}), "params.latency"),
+        ("delay", json!({"latency": "100"}), "params.latency"),
+        ("loss", json!({"loss": "150"}), "params.loss"),
+        ("bandwidth", json!({"rate": "1mb"}), "params.rate"),
+        ("stress-cpu", json!({"load": 120}), "params.load"),
+        ("clock-skew", json!({"time_offset": "10 minutes"}), "params.time_offset"),
+    ] {
+        let chaos_payload = json!({
+            "topology_id": topology_id,
             "source_node_id": "node-1",
+            "target_node_id": "node-2",
             "chaos_type": chaos_type,
             "direction": "to",
             "params": params
         });
 
-        // Verify JSON serialization works
-        let serialized = serde_json::to_string(&request).unwrap();
-        assert!(serialized.contains(chaos_type));
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/chaos")
+                    .header("content-type", "application/json")
+                    .body(Body::from(chaos_payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", chaos_type);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["details"][0]["field"], field, "{}", chaos_type);
     }
 }
 
 #[tokio::test]
-async fn test_chaos_directions() {
-    // Test all direction types serialize correctly
-    let directions = vec!["to", "from", "both"];
+async fn test_create_scenario_rejects_invalid_step_params() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
 
-    for direction in directions {
-        let request = json!({
-            "topology_id": "test-id",
+    let scenario_payload = json!({
+        "name": "Invalid loss",
+        "total_duration": 60,
+        "steps": [{
+            "id": "step-1",
+            "type": "loss",
+            "sourceNodeId": "node-1",
+            "targetNodeId": "node-2",
+            "startAt": 0.0,
+            "duration": 10.0,
+            "params": {"loss": "25%"},
+            "laneId": "lane-1"
+        }]
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/topologies/{}/scenarios", topology_id))
+                .header("content-type", "application/json")
+                .body(Body::from(scenario_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"]["details"][0]["field"], "steps[0].params.loss");
+}
+
+#[tokio::test]
+async fn test_create_io_fault_without_volume() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    // node-1 has no applications, so there is no volume to default to
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "io-fault",
+        "direction": "to",
+        "params": {"errno": 5}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // An explicit volume path is accepted as-is
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "io-fault",
+        "direction": "to",
+        "params": {"errno": 5, "volume_path": "/data"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_io_chaos_defaults_to_app_volume() {
+    let (app, state, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    // An application on node-1 mounting a volume at /data
+    let application = Application {
+        id: uuid::Uuid::new_v4(),
+        topology_id: topology_id.parse().unwrap(),
+        node_selector: vec!["node-1".to_string()],
+        image_name: "postgres:16".to_string(),
+        namespace: "networksim-sim".to_string(),
+        values: Some(json!({"volumes": [{"name": "data", "mountPath": "/data"}]})),
+        status: AppStatus::Deployed,
+        release_name: "postgres".to_string(),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    };
+    state.db.create_application(&application).await.unwrap();
+    let container = format!("app-{}", application.id.simple());
+
+    for (chaos_type, params) in [
+        ("io-delay", json!({"delay": "50ms"})),
+        ("io-fault", json!({"errno": 5})),
+    ] {
+        let (status, created) = send(
+            &app,
+            "POST",
+            "/api/chaos",
+            Some(json!({
+                "topology_id": topology_id,
+                "source_node_id": "node-1",
+                "chaos_type": chaos_type,
+                "direction": "to",
+                "params": params
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}: {}", chaos_type, created);
+        let condition_id = created["id"].as_str().unwrap();
+        let base = format!("/api/topologies/{}/chaos/{}", topology_id, condition_id);
+        send(&app, "POST", &format!("{}/start", base), None).await;
+
+        let manifest = backend.manifest(&topology_id, condition_id).unwrap();
+        assert_eq!(manifest["spec"]["volumePath"], "/data", "{}", chaos_type);
+        assert_eq!(manifest["spec"]["path"], "/data/**/*", "{}", chaos_type);
+        assert_eq!(manifest["spec"]["containerNames"], json!([container]));
+        send(&app, "POST", &format!("{}/stop", base), None).await;
+    }
+}
+
+#[tokio::test]
+async fn test_create_chaos_with_external_targets() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "external_targets": ["10.20.0.0/16", "api.example.com"],
+        "chaos_type": "delay",
+        "direction": "to",
+        "params": {"latency": "150ms"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    let condition_id = json["id"].as_str().unwrap();
+
+    // Persisted and reported by affected-apps
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/chaos/{}/affected-apps", condition_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        json["external_targets"],
+        json!(["10.20.0.0/16", "api.example.com"])
+    );
+}
+
+#[tokio::test]
+async fn test_create_chaos_with_invalid_external_targets() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    for (chaos_type, direction, targets) in [
+        ("delay", "to", json!(["10.0.0.0/40"])),
+        ("delay", "both", json!(["10.0.0.1"])),
+        ("stress-cpu", "to", json!(["10.0.0.1"])),
+    ] {
+        let chaos_payload = json!({
+            "topology_id": topology_id,
             "source_node_id": "node-1",
-            "chaos_type": "delay",
+            "external_targets": targets,
+            "chaos_type": chaos_type,
             "direction": direction,
-            "params": {"latency": "100ms"}
+            "params": {}
         });
 
-        let serialized = serde_json::to_string(&request).unwrap();
-        assert!(serialized.contains(direction));
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/chaos")
+                    .header("content-type", "application/json")
+                    .body(Body::from(chaos_payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 }
+
+#[tokio::test]
+async fn test_create_chaos_with_selection_mode() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "loss",
+        "direction": "to",
+        "selection_mode": "fixed-percent",
+        "selection_value": "50",
+        "params": {"loss": "10"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Persisted and returned when listing
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/topologies/{}/chaos", topology_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json[0]["selection_mode"], "fixed-percent");
+    assert_eq!(json[0]["selection_value"], "50");
+}
+
+#[tokio::test]
+async fn test_create_chaos_with_invalid_selection_mode() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    for (mode, value) in [
+        ("fixed", json!(null)),
+        ("fixed-percent", json!("150")),
+        ("one", json!("2")),
+    ] {
+        let chaos_payload = json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "pod-kill",
+            "direction": "to",
+            "selection_mode": mode,
+            "selection_value": value,
+            "params": {}
+        });
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/chaos")
+                    .header("content-type", "application/json")
+                    .body(Body::from(chaos_payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
+#[tokio::test]
+async fn test_create_scheduled_chaos() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "loss",
+        "direction": "to",
+        "duration": "5s",
+        "schedule": "@every 2m",
+        "history_limit": 5,
+        "params": {"loss": "100"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/topologies/{}/chaos", topology_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json[0]["schedule"], "@every 2m");
+    assert_eq!(json[0]["history_limit"], 5);
+}
+
+#[tokio::test]
+async fn test_create_scheduled_chaos_with_invalid_schedule() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    for (schedule, duration) in [
+        (json!("every two minutes"), json!("5s")),
+        (json!("*/2 * * * *"), json!(null)),
+    ] {
+        let chaos_payload = json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "delay",
+            "direction": "to",
+            "duration": duration,
+            "schedule": schedule,
+            "params": {"latency": "100ms"}
+        });
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/chaos")
+                    .header("content-type", "application/json")
+                    .body(Body::from(chaos_payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
+#[tokio::test]
+async fn test_create_and_update_chaos_with_invalid_duration() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    for duration in ["30", "5 minutes", "-10s"] {
+        let (status, body) = send(
+            &app,
+            "POST",
+            "/api/chaos",
+            Some(json!({
+                "topology_id": topology_id,
+                "source_node_id": "node-1",
+                "chaos_type": "delay",
+                "duration": duration,
+                "params": {"latency": "100ms"}
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "duration {}", duration);
+        assert_eq!(body["error"]["details"][0]["field"], "duration");
+    }
+
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "delay",
+            "duration": "30s",
+            "params": {"latency": "100ms"}
+        })),
+    )
+    .await;
+    let (status, body) = send(
+        &app,
+        "PUT",
+        &format!(
+            "/api/topologies/{}/chaos/{}",
+            topology_id,
+            created["id"].as_str().unwrap()
+        ),
+        Some(json!({"direction": "to", "duration": "30", "params": {"latency": "100ms"}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["details"][0]["field"], "duration");
+}
+
+#[tokio::test]
+async fn test_preview_chaos() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "target_node_id": "node-2",
+        "chaos_type": "delay",
+        "direction": "to",
+        "duration": "30s",
+        "params": {"latency": "100ms"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos/preview")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["kind"], "NetworkChaos");
+    assert_eq!(json["manifest"]["spec"]["delay"]["latency"], "100ms");
+    assert!(json["manifest_yaml"]
+        .as_str()
+        .unwrap()
+        .contains("kind: NetworkChaos"));
+    // No cluster in tests: the dry-run is skipped and reported as a warning
+    assert_eq!(json["dry_run_ok"], false);
+    assert!(json["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|w| w.as_str().unwrap().contains("Kubernetes unavailable")));
+
+    // Nothing is persisted by a preview
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/topologies/{}/chaos", topology_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert!(json.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_preview_existing_scheduled_chaos() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "loss",
+        "direction": "to",
+        "duration": "5s",
+        "schedule": "@every 2m",
+        "params": {"loss": "100"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let created: Value = serde_json::from_slice(&body).unwrap();
+    let condition_id = created["id"].as_str().unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/topologies/{}/chaos/{}/preview",
+                    topology_id, condition_id
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["kind"], "Schedule");
+    assert_eq!(json["manifest"]["spec"]["schedule"], "@every 2m");
+    assert_eq!(json["manifest"]["spec"]["type"], "NetworkChaos");
+}
+
+#[tokio::test]
+async fn test_preview_chaos_invalid_params() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "node-1",
+        "chaos_type": "delay",
+        "direction": "to",
+        "params": {"latency": "fast"}
+    });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos/preview")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_preview_uses_configured_namespace() {
+    let config = Config {
+        k8s_namespace_sim: "game-day".to_string(),
+        ..Config::default()
+    };
+    let (app, _, _) = setup_app_with_backend(Some(config)).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (status, preview) = send(
+        &app,
+        "POST",
+        "/api/chaos/preview",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "target_node_id": "node-2",
+            "chaos_type": "delay",
+            "direction": "to",
+            "params": {"latency": "100ms"}
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(preview["namespace"], "game-day");
+    assert_eq!(preview["manifest"]["metadata"]["namespace"], "game-day");
+    assert_eq!(preview["manifest"]["spec"]["selector"]["namespaces"], json!(["game-day"]));
+
+    // The documented response has a schema
+    let (_, openapi) = send(&app, "GET", "/api-docs/openapi.json", None).await;
+    let response = &openapi["paths"]["/api/chaos/preview"]["post"]["responses"]["200"];
+    assert_eq!(
+        response["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/ChaosPreviewResponse"
+    );
+    assert!(openapi["components"]["schemas"]["ChaosPreviewResponse"].is_object());
+}
+
+#[tokio::test]
+async fn test_chaos_lifecycle_with_in_memory_backend() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (status, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "target_node_id": "node-2",
+            "chaos_type": "delay",
+            "direction": "to",
+            "duration": "30s",
+            "params": {"latency": "100ms"}
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let condition_id = created["id"].as_str().unwrap().to_string();
+    let base = format!("/api/topologies/{}/chaos/{}", topology_id, condition_id);
+    assert!(backend.is_empty());
+
+    // Start applies the manifest
+    let (status, started) = send(&app, "POST", &format!("{}/start", base), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(started["status"], "active");
+    let manifest = backend.manifest(&topology_id, &condition_id).unwrap();
+    assert_eq!(started["k8s_name"], manifest["metadata"]["name"]);
+    assert_eq!(manifest["spec"]["delay"]["latency"], "100ms");
+
+    // Updating an active condition re-applies it
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &base,
+        Some(json!({
+            "direction": "to",
+            "duration": "30s",
+            "params": {"latency": "250ms"}
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let manifest = backend.manifest(&topology_id, &condition_id).unwrap();
+    assert_eq!(manifest["spec"]["delay"]["latency"], "250ms");
+
+    // Stop removes it but keeps the condition
+    let (status, stopped) = send(&app, "POST", &format!("{}/stop", base), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(stopped["status"], "paused");
+    assert!(backend.is_empty());
+
+    // Start all / stop all
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/start", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(backend.len(), 1);
+
+    let (status, _) = send(&app, "DELETE", &base, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(backend.is_empty());
+
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    assert!(conditions.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_stop_and_restart_scheduled_chaos() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "target_node_id": "node-2",
+            "chaos_type": "loss",
+            "direction": "to",
+            "duration": "5s",
+            "schedule": "@every 2m",
+            "params": {"loss": "100"}
+        })),
+    )
+    .await;
+    let condition_id = created["id"].as_str().unwrap().to_string();
+    let base = format!("/api/topologies/{}/chaos/{}", topology_id, condition_id);
+    let pause_annotation = || {
+        backend.manifest(&topology_id, &condition_id).unwrap()["metadata"]["annotations"]
+            ["experiment.chaos-mesh.org/pause"]
+            .clone()
+    };
+
+    send(&app, "POST", &format!("{}/start", base), None).await;
+    assert_eq!(backend.manifest(&topology_id, &condition_id).unwrap()["kind"], "Schedule");
+
+    // Stopping pauses the Schedule instead of deleting it
+    let (status, stopped) = send(&app, "POST", &format!("{}/stop", base), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(stopped["status"], "paused");
+    assert_eq!(pause_annotation(), "true");
+
+    // The paused Schedule is not adopted back by the reconciler
+    let (_, corrections) = send(&app, "POST", "/api/chaos/reconcile", None).await;
+    assert_eq!(corrections, json!([]));
+
+    // Starting resumes it
+    let (_, started) = send(&app, "POST", &format!("{}/start", base), None).await;
+    assert_eq!(started["status"], "active");
+    assert_eq!(pause_annotation(), Value::Null);
+    assert_eq!(backend.len(), 1);
+
+    // Editing a stopped condition drops its paused Schedule, the next start creates it
+    send(&app, "POST", &format!("{}/stop", base), None).await;
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &base,
+        Some(json!({"direction": "to", "duration": "10s", "params": {"loss": "50"}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(backend.is_empty());
+    send(&app, "POST", &format!("{}/start", base), None).await;
+    let manifest = backend.manifest(&topology_id, &condition_id).unwrap();
+    assert_eq!(manifest["spec"]["networkChaos"]["loss"]["loss"], "50");
+
+    // Deleting a stopped condition removes its paused Schedule too
+    send(&app, "POST", &format!("{}/stop", base), None).await;
+    assert_eq!(backend.len(), 1);
+    let (status, _) = send(&app, "DELETE", &base, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(backend.is_empty());
+
+    // And so does deleting all conditions of the topology
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "loss",
+            "duration": "5s",
+            "schedule": "@every 2m",
+            "params": {"loss": "100"}
+        })),
+    )
+    .await;
+    let base = format!(
+        "/api/topologies/{}/chaos/{}",
+        topology_id,
+        created["id"].as_str().unwrap()
+    );
+    send(&app, "POST", &format!("{}/start", base), None).await;
+    send(&app, "POST", &format!("{}/stop", base), None).await;
+    assert_eq!(backend.len(), 1);
+    let (status, _) = send(
+        &app,
+        "DELETE",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(backend.is_empty());
+}
+
+#[tokio::test]
+async fn test_run_scenario_with_in_memory_backend() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (status, scenario) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Short loss",
+            "total_duration": 1,
+            "steps": [{
+                "id": "step-1",
+                "type": "loss",
+                "sourceNodeId": "node-1",
+                "targetNodeId": "node-2",
+                "startAt": 0.0,
+                "duration": 0.3,
+                "params": {"loss": "50"},
+                "laneId": "lane-1"
+            }]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let scenario_id = scenario["data"]["id"].as_str().unwrap();
+
+    let (status, run) =
+        send(&app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(run["data"]["status"], "running");
+    let run_id = run["data"]["id"].as_str().unwrap();
+
+    // The step is applied, then removed once its duration elapses
+    let mut applied = false;
+    let mut run = Value::Null;
+    for _ in 0..50 {
+        applied |= backend.len() == 1;
+        (_, run) = send(&app, "GET", &format!("/api/scenario-runs/{}", run_id), None).await;
+        if run["data"]["status"] != "running" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert!(applied);
+    assert!(backend.is_empty());
+
+    let run = &run["data"];
+    assert_eq!(run["status"], "completed");
+    assert!(run["finished_at"].is_string());
+    let step = &run["steps"][0];
+    assert_eq!(step["step"]["id"], "step-1");
+    assert_eq!(step["status"], "cleaned_up");
+    assert!(step["applied_at"].is_string());
+    assert!(step["cleaned_up_at"].is_string());
+
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(conditions[0]["id"], step["condition_id"]);
+    assert_eq!(conditions[0]["status"], "completed");
+
+    let (_, runs) = send(&app, "GET", &format!("/api/scenarios/{}/runs", scenario_id), None).await;
+    assert_eq!(runs["data"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_cancel_scenario_run() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let step = |id: &str, source: &str, start_at: f64| {
+        json!({
+            "id": id,
+            "type": "loss",
+            "sourceNodeId": source,
+            "startAt": start_at,
+            "duration": 30.0,
+            "params": {"loss": "50"},
+            "laneId": "lane-1"
+        })
+    };
+    let (_, scenario) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Long loss",
+            "total_duration": 60,
+            "steps": [step("step-1", "node-1", 0.0), step("step-2", "node-2", 20.0)]
+        })),
+    )
+    .await;
+    let scenario_id = scenario["data"]["id"].as_str().unwrap();
+
+    let (_, run) = send(&app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+    let run_id = run["data"]["id"].as_str().unwrap();
+    for _ in 0..50 {
+        if backend.len() == 1 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(backend.len(), 1);
+
+    let (status, cancelled) =
+        send(&app, "POST", &format!("/api/scenario-runs/{}/cancel", run_id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(backend.is_empty());
+    let run = &cancelled["data"];
+    assert_eq!(run["status"], "cancelled");
+    assert_eq!(run["steps"][0]["status"], "cleaned_up");
+    assert_eq!(run["steps"][1]["status"], "skipped");
+
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(conditions[0]["status"], "paused");
+
+    let (status, _) =
+        send(&app, "POST", &format!("/api/scenario-runs/{}/cancel", run_id), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(&app, "GET", "/api/scenario-runs/unknown", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_pause_resume_and_advance_scenario_run() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let step = |id: &str, source: &str, start_at: f64, duration: f64| {
+        json!({
+            "id": id,
+            "type": "loss",
+            "sourceNodeId": source,
+            "startAt": start_at,
+            "duration": duration,
+            "params": {"loss": "50"},
+            "laneId": "lane-1"
+        })
+    };
+    let (_, scenario) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Game day",
+            "total_duration": 120,
+            "steps": [step("step-1", "node-1", 0.0, 30.0), step("step-2", "node-2", 100.0, 10.0)]
+        })),
+    )
+    .await;
+    let scenario_id = scenario["data"]["id"].as_str().unwrap();
+
+    let (_, run) = send(&app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+    let run_id = run["data"]["id"].as_str().unwrap().to_string();
+    for _ in 0..50 {
+        if backend.len() == 1 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(backend.len(), 1);
+    let control = |action: &str| format!("/api/scenario-runs/{}/{}", run_id, action);
+
+    // Hold: the fault stays while the clock is frozen
+    let (status, paused) = send(&app, "POST", &control("pause"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(paused["data"]["status"], "paused");
+    assert!(paused["data"]["paused_at"].as_f64().unwrap() < 30.0);
+    assert_eq!(paused["data"]["steps"][0]["status"], "applied");
+    assert_eq!(backend.len(), 1);
+    let (status, _) = send(&app, "POST", &control("pause"), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, resumed) = send(&app, "POST", &control("resume"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(resumed["data"]["status"], "running");
+    assert!(resumed["data"]["paused_at"].is_null());
+    let (status, _) = send(&app, "POST", &control("resume"), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Lift: the fault is removed while paused and injected again on resume
+    let (_, paused) = send(&app, "POST", &control("pause?lift_faults=true"), None).await;
+    let step_1 = &paused["data"]["steps"][0];
+    assert_eq!(step_1["status"], "suspended");
+    assert!(backend.is_empty());
+    let condition_id = step_1["condition_id"].as_str().unwrap().to_string();
+    let (_, resumed) = send(&app, "POST", &control("resume"), None).await;
+    assert_eq!(resumed["data"]["steps"][0]["status"], "applied");
+    assert_eq!(resumed["data"]["steps"][0]["condition_id"], condition_id.as_str());
+    assert!(backend.manifest(&topology_id, &condition_id).is_some());
+
+    // Step through: each advance runs the next transition and stays paused
+    send(&app, "POST", &control("pause"), None).await;
+    let (_, advanced) = send(&app, "POST", &control("advance"), None).await;
+    let run = &advanced["data"];
+    assert_eq!(run["status"], "paused");
+    assert_eq!(run["paused_at"], 30.0);
+    assert_eq!(run["steps"][0]["status"], "cleaned_up");
+    assert!(backend.is_empty());
+
+    let (_, advanced) = send(&app, "POST", &control("advance"), None).await;
+    assert_eq!(advanced["data"]["paused_at"], 100.0);
+    assert_eq!(advanced["data"]["steps"][1]["status"], "applied");
+    assert_eq!(backend.len(), 1);
+
+    send(&app, "POST", &control("advance"), None).await;
+    let mut run = json!(null);
+    for _ in 0..50 {
+        (_, run) = send(&app, "GET", &format!("/api/scenario-runs/{}", run_id), None).await;
+        if run["data"]["status"] == "completed" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(run["data"]["status"], "completed");
+    assert_eq!(run["data"]["steps"][1]["status"], "cleaned_up");
+    assert!(backend.is_empty());
+
+    let (status, _) = send(&app, "POST", &control("advance"), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_scenario_run_abort_criteria() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (_, scenario) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Unattended",
+            "total_duration": 120,
+            "abort_criteria": {"max_duration": "0s"},
+            "steps": [{
+                "id": "step-1",
+                "type": "loss",
+                "sourceNodeId": "node-1",
+                "startAt": 0.0,
+                "duration": 100.0,
+                "params": {"loss": "50"},
+                "laneId": "lane-1"
+            }]
+        })),
+    )
+    .await;
+    let scenario_id = scenario["data"]["id"].as_str().unwrap();
+
+    let (_, run) = send(&app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+    let run_url = format!("/api/scenario-runs/{}", run["data"]["id"].as_str().unwrap());
+    let mut run = json!(null);
+    for _ in 0..100 {
+        (_, run) = send(&app, "GET", &run_url, None).await;
+        if run["data"]["status"] == "aborted" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    let run = &run["data"];
+    assert_eq!(run["status"], "aborted");
+    assert!(run["error"].as_str().unwrap().contains("maximum duration"));
+    assert_eq!(run["steps"][0]["status"], "cleaned_up");
+    assert!(backend.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_removes_abort_criteria() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "delay",
+            "params": {"latency": "100ms"},
+            "abort_criteria": {"max_duration": "10m"}
+        })),
+    )
+    .await;
+    let condition_url = format!(
+        "/api/topologies/{}/chaos/{}",
+        topology_id,
+        created["id"].as_str().unwrap()
+    );
+    let update = json!({"direction": "to", "params": {"latency": "200ms"}});
+
+    // Omitted: kept
+    let (_, updated) = send(&app, "PUT", &condition_url, Some(update.clone())).await;
+    assert_eq!(updated["abort_criteria"]["max_duration"], "10m");
+    // Explicit null: removed
+    let mut remove = update;
+    remove["abort_criteria"] = Value::Null;
+    let (status, updated) = send(&app, "PUT", &condition_url, Some(remove)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(updated["abort_criteria"].is_null());
+
+    let (_, scenario) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Guarded",
+            "total_duration": 60,
+            "abort_criteria": {"abort_on_app_failure": true},
+            "steps": [{
+                "id": "step-1",
+                "type": "loss",
+                "sourceNodeId": "node-1",
+                "startAt": 0.0,
+                "duration": 10.0,
+                "params": {"loss": "50"},
+                "laneId": "lane-1"
+            }]
+        })),
+    )
+    .await;
+    let scenario_url = format!("/api/scenarios/{}", scenario["data"]["id"].as_str().unwrap());
+
+    let (_, updated) = send(&app, "PUT", &scenario_url, Some(json!({"name": "Renamed"}))).await;
+    assert_eq!(updated["data"]["abort_criteria"]["abort_on_app_failure"], true);
+    let (status, updated) =
+        send(&app, "PUT", &scenario_url, Some(json!({"abort_criteria": null}))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(updated["data"]["abort_criteria"].is_null());
+}
+
+#[tokio::test]
+async fn test_scenario_assertion_steps() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let check = |id: &str, start_at: f64, duration: f64, assertion: Value| {
+        json!({
+            "id": id,
+            "sourceNodeId": "node-1",
+            "targetNodeId": "node-2",
+            "startAt": start_at,
+            "duration": duration,
+            "params": {},
+            "laneId": "lane-2",
+            "assertion": assertion
+        })
+    };
+    let scenarios = format!("/api/topologies/{}/scenarios", topology_id);
+    let unreachable = json!({"kind": "reachability", "expect_reachable": false});
+
+    // An assertion step has no chaos type, reachability needs a target
+    let mut invalid = check("check", 0.0, 5.0, unreachable.clone());
+    invalid["type"] = json!("loss");
+    let (status, body) = send(
+        &app,
+        "POST",
+        &scenarios,
+        Some(json!({"name": "Invalid", "total_duration": 60, "steps": [invalid]})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["details"][0]["field"], "steps[0].type");
+    let mut invalid = check("check", 0.0, 5.0, unreachable.clone());
+    invalid["targetNodeId"] = Value::Null;
+    let (status, _) = send(
+        &app,
+        "POST",
+        &scenarios,
+        Some(json!({"name": "Invalid", "total_duration": 60, "steps": [invalid]})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, scenario) = send(
+        &app,
+        "POST",
+        &scenarios,
+        Some(json!({
+            "name": "Partition is detected and heals",
+            "total_duration": 60,
+            "steps": [
+                {
+                    "id": "fault",
+                    "type": "partition",
+                    "sourceNodeId": "node-1",
+                    "targetNodeId": "node-2",
+                    "startAt": 0.0,
+                    "duration": 30.0,
+                    "params": {},
+                    "laneId": "lane-1"
+                },
+                check("cut", 0.0, 5.0, unreachable),
+                check("heal", 30.0, 10.0, json!({"kind": "linked_pairs_connected"}))
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let scenario = &scenario["data"];
+    assert!(scenario["steps"][1].get("type").is_none());
+    let scenario_id = scenario["id"].as_str().unwrap();
+
+    let (_, run) = send(&app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+    let run_id = run["data"]["id"].as_str().unwrap().to_string();
+    let run_url = format!("/api/scenario-runs/{}", run_id);
+    let control = |action: &str| format!("{}/{}", run_url, action);
+    let mut run = json!(null);
+    for _ in 0..50 {
+        (_, run) = send(&app, "GET", &run_url, None).await;
+        // Sampled on its own task: probing until the sample is in
+        let status = run["data"]["steps"][1]["status"].as_str().unwrap();
+        if status != "scheduled" && status != "probing" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    // Only the chaos step reaches the backend
+    assert_eq!(backend.len(), 1);
+
+    // Without a cluster the probes cannot measure, so the assertions fail
+    let cut = &run["data"]["steps"][1];
+    assert_eq!(cut["status"], "failed");
+    assert_eq!(cut["result"]["passed"], false);
+    assert!(cut["result"]["message"].as_str().unwrap().starts_with("Could not measure"));
+    assert_eq!(cut["samples"].as_array().unwrap().len(), 1);
+
+    // Step through the sampling of the second assertion until the run finishes
+    send(&app, "POST", &control("pause"), None).await;
+    for _ in 0..20 {
+        let (status, _) = send(&app, "POST", &control("advance"), None).await;
+        if status != StatusCode::OK {
+            break;
+        }
+    }
+    for _ in 0..50 {
+        (_, run) = send(&app, "GET", &run_url, None).await;
+        if run["data"]["status"] != "paused" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    let run = &run["data"];
+    assert_eq!(run["status"], "failed");
+    assert_eq!(run["error"], "2 assertion(s) failed");
+    assert_eq!(run["steps"][0]["status"], "cleaned_up");
+    let heal = &run["steps"][2];
+    assert_eq!(heal["status"], "failed");
+    // Every 2s of the window, plus one at its end
+    assert_eq!(heal["samples"].as_array().unwrap().len(), 6);
+    assert_eq!(heal["samples"][5]["at"], 40.0);
+    assert!(backend.is_empty());
+}
+
+#[tokio::test]
+async fn test_reconcile_chaos_with_in_memory_backend() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let mut ids = Vec::new();
+    // On different nodes, so they do not conflict
+    for (node, loss) in [("node-1", "10"), ("node-2", "20"), ("node-3", "30")] {
+        let (_, created) = send(
+            &app,
+            "POST",
+            "/api/chaos",
+            Some(json!({
+                "topology_id": topology_id,
+                "source_node_id": node,
+                "chaos_type": "loss",
+                "direction": "to",
+                "duration": "30s",
+                "params": {"loss": loss}
+            })),
+        )
+        .await;
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/start", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, stopped) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/{}/stop", topology_id, ids[2]),
+        None,
+    )
+    .await;
+
+    // Drift: deleted out of band, duration elapsed, re-applied out of band, orphan
+    backend.delete(&topology_id, &ids[0]).await.unwrap();
+    backend.set_phase(&topology_id, &ids[1], "Finished");
+    let paused: ChaosCondition = serde_json::from_value(stopped).unwrap();
+    backend.create(&paused).await.unwrap();
+    let orphan = ChaosCondition {
+        id: "orphan01".to_string(),
+        ..paused.clone()
+    };
+    backend.create(&orphan).await.unwrap();
+
+    let (status, corrections) = send(&app, "POST", "/api/chaos/reconcile", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let action_of = |id: &str| {
+        corrections
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["condition_id"] == id)
+            .map(|c| c["action"].as_str().unwrap().to_string())
+    };
+    assert_eq!(action_of(&ids[0]).as_deref(), Some("marked_paused"));
+    assert_eq!(action_of(&ids[1]).as_deref(), Some("expired"));
+    assert_eq!(action_of(&ids[2]).as_deref(), Some("adopted"));
+    assert_eq!(action_of("orphan01").as_deref(), Some("garbage_collected"));
+
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    let status_of = |id: &str| {
+        conditions
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["id"] == id)
+            .map(|c| c["status"].as_str().unwrap().to_string())
+            .unwrap()
+    };
+    assert_eq!(status_of(&ids[0]), "paused");
+    assert_eq!(status_of(&ids[1]), "completed");
+    assert_eq!(status_of(&ids[2]), "active");
+    assert_eq!(backend.len(), 1);
+
+    // Every correction is recorded as an event
+    let (_, events) = send(
+        &app,
+        "GET",
+        &format!("/api/events?topology_id={}&event_type=chaos", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(events["total"], 4);
+
+    // A second pass finds nothing to fix
+    let (_, corrections) = send(&app, "POST", "/api/chaos/reconcile", None).await;
+    assert!(corrections.as_array().unwrap().is_empty());
+}
+
+/// In-memory backend whose deletes fail for one condition
+struct FailingDeleteBackend {
+    inner: InMemoryChaosBackend,
+    failing: Arc<std::sync::Mutex<String>>,
+}
+
+#[async_trait::async_trait]
+impl ChaosBackend for FailingDeleteBackend {
+    async fn create(&self, condition: &ChaosCondition) -> AppResult<String> {
+        self.inner.create(condition).await
+    }
+
+    async fn delete(&self, topology_id: &str, condition_id: &str) -> AppResult<()> {
+        if condition_id == *self.failing.lock().unwrap() {
+            return Err(AppError::internal("delete refused"));
+        }
+        self.inner.delete(topology_id, condition_id).await
+    }
+
+    async fn list(&self, topology_id: &str) -> AppResult<Vec<ChaosStatus>> {
+        self.inner.list(topology_id).await
+    }
+
+    async fn list_managed(&self) -> AppResult<Vec<ChaosStatus>> {
+        self.inner.list_managed().await
+    }
+}
+
+#[tokio::test]
+async fn test_reconcile_continues_after_failed_correction() {
+    let (_, state, inner) = setup_app_with_backend(None).await;
+    let backend = FailingDeleteBackend {
+        inner: inner.clone(),
+        failing: Arc::new(std::sync::Mutex::new("orphan01".to_string())),
+    };
+    let state = state.with_chaos_backend(Arc::new(backend));
+    let app = networksim_backend::create_router(state);
+    let topology_id = create_test_topology(&app).await;
+
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "loss",
+            "direction": "to",
+            "params": {"loss": "10"}
+        })),
+    )
+    .await;
+    let id = created["id"].as_str().unwrap().to_string();
+    let (_, started) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/{}/start", topology_id, id),
+        None,
+    )
+    .await;
+    let condition: ChaosCondition = serde_json::from_value(started).unwrap();
+
+    // Two orphans, the first cannot be deleted; the active condition lost its resource
+    for orphan in ["orphan01", "orphan02"] {
+        let orphan = ChaosCondition {
+            id: orphan.to_string(),
+            ..condition.clone()
+        };
+        inner.create(&orphan).await.unwrap();
+    }
+    inner.delete(&topology_id, &id).await.unwrap();
+
+    let (status, corrections) = send(&app, "POST", "/api/chaos/reconcile", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let corrections = corrections.as_array().unwrap();
+    assert_eq!(corrections.len(), 3);
+    let correction_of = |id: &str| corrections.iter().find(|c| c["condition_id"] == id).unwrap();
+    assert_eq!(correction_of(&id)["action"], "marked_paused");
+    assert!(correction_of(&id)["error"].is_null());
+    assert!(correction_of("orphan01")["error"]
+        .as_str()
+        .unwrap()
+        .contains("delete refused"));
+    assert!(correction_of("orphan02")["error"].is_null());
+    assert_eq!(inner.len(), 1);
+
+    // Only the applied corrections are recorded as events
+    let (_, events) = send(
+        &app,
+        "GET",
+        &format!("/api/events?topology_id={}&event_type=chaos", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(events["total"], 2);
+
+    // The failed one is retried on the next pass
+    let (_, corrections) = send(&app, "POST", "/api/chaos/reconcile", None).await;
+    assert_eq!(corrections.as_array().unwrap().len(), 1);
+    assert_eq!(corrections[0]["condition_id"], "orphan01");
+}
+
+/// In-memory backend that creates and starts a condition while listing resources
+struct RacingListBackend {
+    inner: InMemoryChaosBackend,
+    db: Database,
+    late: std::sync::Mutex<Option<ChaosCondition>>,
+}
+
+#[async_trait::async_trait]
+impl ChaosBackend for RacingListBackend {
+    async fn create(&self, condition: &ChaosCondition) -> AppResult<String> {
+        self.inner.create(condition).await
+    }
+
+    async fn delete(&self, topology_id: &str, condition_id: &str) -> AppResult<()> {
+        self.inner.delete(topology_id, condition_id).await
+    }
+
+    async fn list(&self, topology_id: &str) -> AppResult<Vec<ChaosStatus>> {
+        self.inner.list(topology_id).await
+    }
+
+    async fn list_managed(&self) -> AppResult<Vec<ChaosStatus>> {
+        let late = self.late.lock().unwrap().take();
+        if let Some(condition) = late {
+            self.db.create_chaos_condition(&condition).await.unwrap();
+            let name = self.inner.create(&condition).await?;
+            self.db
+                .update_chaos_condition_status(
+                    &condition.id,
+                    &ChaosConditionStatus::Active,
+                    Some(&name),
+                )
+                .await
+                .unwrap();
+        }
+        self.inner.list_managed().await
+    }
+}
+
+#[tokio::test]
+async fn test_reconcile_keeps_resource_of_condition_started_during_pass() {
+    let (_, state, inner) = setup_app_with_backend(None).await;
+    let backend = Arc::new(RacingListBackend {
+        inner: inner.clone(),
+        db: state.db.clone(),
+        late: std::sync::Mutex::new(None),
+    });
+    let state = state.with_chaos_backend(backend.clone());
+    let app = networksim_backend::create_router(state.clone());
+    let topology_id = create_test_topology(&app).await;
+
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "loss",
+            "direction": "to",
+            "params": {"loss": "10"}
+        })),
+    )
+    .await;
+    let condition: ChaosCondition = serde_json::from_value(created).unwrap();
+    *backend.late.lock().unwrap() = Some(ChaosCondition {
+        id: "late0001".to_string(),
+        ..condition
+    });
+
+    // Created between the DB snapshot and the cluster listing, like a scenario step
+    let (status, corrections) = send(&app, "POST", "/api/chaos/reconcile", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(corrections, json!([]));
+    assert_eq!(inner.len(), 1);
+    let late = state.db.get_chaos_condition("late0001").await.unwrap().unwrap();
+    assert_eq!(late.status, ChaosConditionStatus::Active);
+
+    // The next pass sees both and leaves them alone
+    let (_, corrections) = send(&app, "POST", "/api/chaos/reconcile", None).await;
+    assert_eq!(corrections, json!([]));
+}
+
+#[tokio::test]
+async fn test_tc_backend_rejects_unsupported_chaos_type() {
+    let config = Config {
+        chaos_backend: "tc".to_string(),
+        ..Config::default()
+    };
+    let db = Database::new("sqlite::memory:").await.unwrap();
+    db.run_migrations().await.unwrap();
+    let app = networksim_backend::create_router(AppState::new(db, config));
+    let topology_id = create_test_topology(&app).await;
+
+    let (status, body) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "stress-cpu",
+            "direction": "to",
+            "duration": "30s",
+            "params": {"workers": 1, "load": 50}
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"].as_str().unwrap().contains("tc backend"));
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "target_node_id": "node-2",
+            "chaos_type": "loss",
+            "direction": "both",
+            "duration": "30s",
+            "params": {"loss": "20"}
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_chaos_for_nonexistent_topology() {
+    let app = setup_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/topologies/nonexistent-id/chaos")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Should return 404 or empty array
+    let status = response.status();
+    assert!(status == StatusCode::OK || status == StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_chaos_invalid_node_id() {
+    let app = setup_app().await;
+    let topology_id = create_test_topology(&app).await;
+
+    let chaos_payload = json!({
+        "topology_id": topology_id,
+        "source_node_id": "nonexistent-node",
+        "chaos_type": "delay",
+        "direction": "to",
+        "params": {"latency": "100ms"}
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/chaos")
+                .header("content-type", "application/json")
+                .body(Body::from(chaos_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Should fail validation or K8s apply
+    let status = response.status();
+    // We accept various error codes since this depends on implementation
+    assert!(status != StatusCode::OK || status == StatusCode::BAD_REQUEST || status == StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_chaos_types_serialization() {
+    // Test that all chaos types serialize correctly in requests
+    let chaos_types = vec![
+        ("delay", json!({"latency": "100ms"})),
+        ("loss", json!({"loss": "10"})),
+        ("bandwidth", json!({"rate": "1mbps"})),
+        ("corrupt", json!({"corrupt": "5"})),
+        ("duplicate", json!({"duplicate": "10"})),
+        ("partition", json!({})),
+    ];
+
+    for (chaos_type, params) in chaos_types {
+        let request = json!({
+            "topology_id": "test-id",
+            "source_node_id": "node-1",
+            "chaos_type": chaos_type,
+            "direction": "to",
+            "params": params
+        });
+
+        // Verify JSON serialization works
+        let serialized = serde_json::to_string(&request).unwrap();
+        assert!(serialized.contains(chaos_type));
+    }
+}
+
+#[tokio::test]
+async fn test_chaos_directions() {
+    // Test all direction types serialize correctly
+    let directions = vec!["to", "from", "both"];
+
+    for direction in directions {
+        let request = json!({
+            "topology_id": "test-id",
+            "source_node_id": "node-1",
+            "chaos_type": "delay",
+            "direction": direction,
+            "params": {"latency": "100ms"}
+        });
+
+        let serialized = serde_json::to_string(&request).unwrap();
+        assert!(serialized.contains(direction));
+    }
+}
+
+#[tokio::test]
+async fn test_expired_condition_completes() {
+    let (app, state, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "delay",
+            "direction": "to",
+            "duration": "1s",
+            "params": {"latency": "100ms"}
+        })),
+    )
+    .await;
+    let id = created["id"].as_str().unwrap().to_string();
+    assert!(created["ends_at"].is_null());
+
+    let (status, started) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/{}/start", topology_id, id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(started["ends_at"].is_string());
+
+    // Only active conditions with a duration are read by the expiry check
+    let (_, open_ended) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-2",
+            "chaos_type": "delay",
+            "direction": "to",
+            "params": {"latency": "100ms"}
+        })),
+    )
+    .await;
+    let open_ended_id = open_ended["id"].as_str().unwrap();
+    send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/{}/start", topology_id, open_ended_id),
+        None,
+    )
+    .await;
+    let expiring = state.db.list_expiring_chaos_conditions().await.unwrap();
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring[0].id, id);
+    backend.delete(&topology_id, open_ended_id).await.unwrap();
+
+    // Not expired yet
+    assert!(expire_chaos_conditions(&state).await.unwrap().is_empty());
+    assert_eq!(backend.len(), 1);
+
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    let expired = expire_chaos_conditions(&state).await.unwrap();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, id);
+    assert!(backend.is_empty());
+
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    let condition = &conditions[0];
+    assert_eq!(condition["status"], "completed");
+    // Start time is kept so reports show when the condition ran
+    assert!(condition["started_at"].is_string());
+
+    let (_, events) = send(
+        &app,
+        "GET",
+        &format!("/api/events?topology_id={}&event_type=chaos", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(events["total"], 1);
+
+    // Completed conditions are not expired twice
+    assert!(expire_chaos_conditions(&state).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_abort_criteria_stop_chaos() {
+    let (app, state, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let (status, invalid) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "delay",
+            "params": {"latency": "100ms"},
+            "abort_criteria": {"max_duration": "soon"}
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(invalid["error"]["details"][0]["field"], "abort_criteria.max_duration");
+
+    // No duration: only the abort criterion stops it
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-1",
+            "chaos_type": "delay",
+            "params": {"latency": "100ms"},
+            "abort_criteria": {"max_duration": "1s"}
+        })),
+    )
+    .await;
+    let id = created["id"].as_str().unwrap().to_string();
+    assert_eq!(created["abort_criteria"]["max_duration"], "1s");
+
+    send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/{}/start", topology_id, id),
+        None,
+    )
+    .await;
+    assert!(supervise_chaos(&state).await.unwrap().is_empty());
+    assert_eq!(backend.len(), 1);
+
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    let aborted = supervise_chaos(&state).await.unwrap();
+    assert_eq!(aborted.len(), 1);
+    assert_eq!(aborted[0].0, topology_id);
+    assert!(backend.is_empty());
+
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(conditions[0]["id"], id.as_str());
+    assert_eq!(conditions[0]["status"], "paused");
+
+    let (_, events) = send(
+        &app,
+        "GET",
+        &format!("/api/events?topology_id={}&event_type=chaos", topology_id),
+        None,
+    )
+    .await;
+    let events = events["events"].as_array().unwrap();
+    assert!(events.iter().any(|e| e["event_subtype"] == "aborted"));
+
+    // Nothing active is left to supervise
+    assert!(supervise_chaos(&state).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_update_active_condition_checks_conflicts() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    // Opposite directions of the same pair do not overlap
+    let mut ids = Vec::new();
+    for (source, target) in [("node-1", "node-2"), ("node-2", "node-1")] {
+        let (_, created) = send(
+            &app,
+            "POST",
+            "/api/chaos",
+            Some(json!({
+                "topology_id": topology_id,
+                "source_node_id": source,
+                "target_node_id": target,
+                "chaos_type": "delay",
+                "direction": "to",
+                "params": {"latency": "100ms"}
+            })),
+        )
+        .await;
+        let id = created["id"].as_str().unwrap().to_string();
+        let (status, _) = send(
+            &app,
+            "POST",
+            &format!("/api/topologies/{}/chaos/{}/start", topology_id, id),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        ids.push(id);
+    }
+
+    // Both directions adds node-1 -> node-2, already delayed by the first one
+    let update_uri = format!("/api/topologies/{}/chaos/{}", topology_id, ids[1]);
+    let update = json!({"direction": "both", "params": {"latency": "300ms"}});
+    let (status, body) = send(&app, "PUT", &update_uri, Some(update.clone())).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(body["error"].as_str().unwrap().contains(&ids[0]));
+    // The running resource and the stored condition are untouched
+    let manifest = backend.manifest(&topology_id, &ids[1]).unwrap();
+    assert_eq!(manifest["spec"]["direction"], "to");
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    let stored = conditions.as_array().unwrap().iter().find(|c| c["id"] == ids[1].as_str());
+    assert_eq!(stored.unwrap()["direction"], "to");
+
+    let (status, updated) =
+        send(&app, "PUT", &format!("{}?force=true", update_uri), Some(update)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["direction"], "both");
+    let manifest = backend.manifest(&topology_id, &ids[1]).unwrap();
+    assert_eq!(manifest["spec"]["direction"], "both");
+}
+
+#[tokio::test]
+async fn test_start_conflicting_condition() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let mut ids = Vec::new();
+    for latency in ["100ms", "200ms"] {
+        let (_, created) = send(
+            &app,
+            "POST",
+            "/api/chaos",
+            Some(json!({
+                "topology_id": topology_id,
+                "source_node_id": "node-1",
+                "target_node_id": "node-2",
+                "chaos_type": "delay",
+                "direction": "to",
+                "params": {"latency": latency}
+            })),
+        )
+        .await;
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+
+    let start_uri = |id: &str| format!("/api/topologies/{}/chaos/{}/start", topology_id, id);
+    let (status, _) = send(&app, "POST", &start_uri(&ids[0]), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Two delays on the same pair overwrite each other
+    let (status, body) = send(&app, "POST", &start_uri(&ids[1]), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(body["error"].as_str().unwrap().contains(&ids[0]));
+    assert_eq!(backend.len(), 1);
+
+    // start_all skips it too
+    let (_, result) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/start", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(result["started"], 0);
+    assert_eq!(result["errors"].as_array().unwrap().len(), 1);
+
+    let (_, effective) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos/effective", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(effective["pairs"].as_array().unwrap().len(), 1);
+    assert_eq!(effective["pairs"][0]["source_node_id"], "node-1");
+    assert_eq!(effective["pairs"][0]["target"], "node-2");
+    assert!(effective["pairs"][0]["conflicts"].as_array().unwrap().is_empty());
+
+    // Forced: started, and the effective fault set shows the conflict
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{}?force=true", start_uri(&ids[1])),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, effective) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos/effective", topology_id),
+        None,
+    )
+    .await;
+    assert_eq!(effective["pairs"][0]["faults"].as_array().unwrap().len(), 2);
+    assert_eq!(effective["pairs"][0]["conflicts"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_run_scenario_with_conflicting_steps() {
+    let (app, _, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let step = |id: &str, start_at: f64| {
+        json!({
+            "id": id,
+            "type": "delay",
+            "sourceNodeId": "node-1",
+            "targetNodeId": "node-2",
+            "startAt": start_at,
+            "duration": 10.0,
+            "params": {"latency": "100ms"},
+            "laneId": "lane-1"
+        })
+    };
+    let (_, scenario) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Overlapping delays",
+            "total_duration": 30,
+            "steps": [step("step-1", 0.0), step("step-2", 5.0), step("step-3", 10.0)]
+        })),
+    )
+    .await;
+    let scenario_id = scenario["data"]["id"].as_str().unwrap();
+
+    // step-3 starts when step-1 ends, only step-2 overlaps both
+    let (status, body) =
+        send(&app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let error = body["error"].as_str().unwrap();
+    assert!(error.contains("step-1 and step-2"));
+    assert!(error.contains("step-2 and step-3"));
+    assert!(!error.contains("step-1 and step-3"));
+    assert!(backend.is_empty());
+}
+
+#[tokio::test]
+async fn test_emergency_stop() {
+    let (app, state, backend) = setup_app_with_backend(None).await;
+
+    // One active condition on each of two topologies
+    let mut started = Vec::new();
+    for _ in 0..2 {
+        let topology_id = create_test_topology(&app).await;
+        let (_, created) = send(
+            &app,
+            "POST",
+            "/api/chaos",
+            Some(json!({
+                "topology_id": topology_id,
+                "source_node_id": "node-1",
+                "chaos_type": "delay",
+                "params": {"latency": "100ms"}
+            })),
+        )
+        .await;
+        let id = created["id"].as_str().unwrap().to_string();
+        let (_, condition) = send(
+            &app,
+            "POST",
+            &format!("/api/topologies/{}/chaos/{}/start", topology_id, id),
+            None,
+        )
+        .await;
+        started.push(condition);
+    }
+
+    // A resource left behind, unknown to the database
+    let orphan = ChaosCondition {
+        id: "orphan01".to_string(),
+        ..serde_json::from_value(started[0].clone()).unwrap()
+    };
+    backend.create(&orphan).await.unwrap();
+    assert_eq!(backend.len(), 3);
+
+    let (status, report) = send(&app, "POST", "/api/chaos/emergency-stop", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(backend.is_empty());
+    assert_eq!(report["failed"], 0);
+    assert_eq!(report["paused_conditions"].as_array().unwrap().len(), 2);
+    let resources = report["resources"].as_array().unwrap();
+    assert_eq!(resources.len(), 3);
+    assert!(resources.iter().all(|r| r["outcome"] == "deleted"));
+    let unknown: Vec<&Value> = resources.iter().filter(|r| r["known"] == false).collect();
+    assert_eq!(unknown.len(), 1);
+    assert_eq!(unknown[0]["condition_id"], "orphan01");
+
+    for condition in &started {
+        let (_, conditions) = send(
+            &app,
+            "GET",
+            &format!("/api/topologies/{}/chaos", condition["topology_id"].as_str().unwrap()),
+            None,
+        )
+        .await;
+        assert_eq!(conditions[0]["status"], "paused");
+    }
+
+    // Nothing left: a second call succeeds with no resources
+    let (status, report) = send(&app, "POST", "/api/chaos/emergency-stop", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(report["resources"].as_array().unwrap().is_empty());
+    assert!(report["paused_conditions"].as_array().unwrap().is_empty());
+}
+
+/// Start a run with a fault on node-1 now and another on node-2 after 300ms
+async fn start_two_step_run(app: &axum::Router, topology_id: &str) -> String {
+    let step = |id: &str, source: &str, start_at: f64| {
+        json!({
+            "id": id,
+            "type": "loss",
+            "sourceNodeId": source,
+            "startAt": start_at,
+            "duration": 60.0,
+            "params": {"loss": "50"},
+            "laneId": "lane-1"
+        })
+    };
+    let (_, scenario) = send(
+        app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Two faults",
+            "total_duration": 120,
+            "steps": [step("step-1", "node-1", 0.0), step("step-2", "node-2", 0.3)]
+        })),
+    )
+    .await;
+    let scenario_id = scenario["data"]["id"].as_str().unwrap();
+    let (_, run) = send(app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+    run["data"]["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_stopping_chaos_cancels_scenario_runs() {
+    let (app, state, backend) = setup_app_with_backend(None).await;
+    let run_status = |run_id: String| {
+        let app = app.clone();
+        async move {
+            let (_, run) = send(&app, "GET", &format!("/api/scenario-runs/{}", run_id), None).await;
+            run["data"]["status"].as_str().unwrap().to_string()
+        }
+    };
+
+    // A condition abort cancels the runs of its topology
+    let topology_id = create_test_topology(&app).await;
+    let (_, created) = send(
+        &app,
+        "POST",
+        "/api/chaos",
+        Some(json!({
+            "topology_id": topology_id,
+            "source_node_id": "node-3",
+            "chaos_type": "delay",
+            "params": {"latency": "100ms"},
+            "abort_criteria": {"max_duration": "0s"}
+        })),
+    )
+    .await;
+    let id = created["id"].as_str().unwrap();
+    send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/chaos/{}/start", topology_id, id),
+        None,
+    )
+    .await;
+    let run_id = start_two_step_run(&app, &topology_id).await;
+    for _ in 0..50 {
+        if backend.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(backend.len(), 2);
+
+    assert_eq!(supervise_chaos(&state).await.unwrap().len(), 1);
+    assert_eq!(run_status(run_id.clone()).await, "cancelled");
+    assert!(!state.runs.is_running(&run_id));
+    // The second step is not applied after the sweep
+    tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+    assert!(backend.is_empty());
+
+    // The emergency stop cancels every run
+    let topology_id = create_test_topology(&app).await;
+    let run_id = start_two_step_run(&app, &topology_id).await;
+    for _ in 0..50 {
+        if backend.len() == 1 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(backend.len(), 1);
+
+    let (status, report) = send(&app, "POST", "/api/chaos/emergency-stop", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["cancelled_runs"], json!([run_id]));
+    assert_eq!(run_status(run_id).await, "cancelled");
+    tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+    assert!(backend.is_empty());
+}
+
+/// A run left `running` by a server that stopped 5s into it, with its first step applied
+async fn interrupted_run(
+    state: &AppState,
+    app: &axum::Router,
+    topology_id: &str,
+    restart_policy: &str,
+) -> ScenarioRun {
+    let step = |id: &str, source: &str, start_at: f64, duration: f64| {
+        json!({
+            "id": id,
+            "type": "loss",
+            "sourceNodeId": source,
+            "startAt": start_at,
+            "duration": duration,
+            "params": {"loss": "50"},
+            "laneId": "lane-1"
+        })
+    };
+    let (_, scenario) = send(
+        app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": format!("Interrupted ({})", restart_policy),
+            "total_duration": 60,
+            "restart_policy": restart_policy,
+            "steps": [
+                step("step-1", "node-1", 0.0, 30.0),
+                step("step-2", "node-2", 1.0, 1.0),
+                step("step-3", "node-3", 20.0, 5.0)
+            ]
+        })),
+    )
+    .await;
+    let scenario = &scenario["data"];
+    assert_eq!(scenario["restart_policy"], restart_policy);
+    let steps: Vec<ScenarioStep> = serde_json::from_value(scenario["steps"].clone()).unwrap();
+
+    let condition =
+        step_condition(topology_id, &steps[0], &uuid::Uuid::new_v4().to_string()).unwrap();
+    state.db.create_chaos_condition(&condition).await.unwrap();
+    state.chaos.create(&condition).await.unwrap();
+    state
+        .db
+        .update_chaos_condition_status(&condition.id, &ChaosConditionStatus::Active, None)
+        .await
+        .unwrap();
+
+    let started_at = chrono::Utc::now() - chrono::Duration::seconds(5);
+    let mut run_steps: Vec<ScenarioRunStep> = steps.into_iter().map(ScenarioRunStep::new).collect();
+    run_steps[0].status = RunStepStatus::Applied;
+    run_steps[0].condition_id = Some(condition.id.clone());
+    run_steps[0].applied_at = Some(started_at);
+    let run = ScenarioRun {
+        id: uuid::Uuid::new_v4().to_string(),
+        scenario_id: scenario["id"].as_str().unwrap().to_string(),
+        topology_id: topology_id.to_string(),
+        status: ScenarioRunStatus::Running,
+        force: false,
+        restart_policy: serde_json::from_value(json!(restart_policy)).unwrap(),
+        total_duration: 60,
+        abort_criteria: None,
+        steps: run_steps,
+        error: None,
+        time_shift: 0.0,
+        paused_at: None,
+        started_at,
+        finished_at: None,
+        updated_at: started_at,
+    };
+    state.db.create_scenario_run(&run).await.unwrap();
+    run
+}
+
+#[tokio::test]
+async fn test_recover_interrupted_scenario_runs() {
+    let (app, state, backend) = setup_app_with_backend(None).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let rolled_back = interrupted_run(&state, &app, &topology_id, "rollback").await;
+    let resumed = interrupted_run(&state, &app, &topology_id, "resume").await;
+    assert_eq!(backend.len(), 2);
+
+    let recovered = recover_scenario_runs(&state).await.unwrap();
+    assert_eq!(recovered.len(), 2);
+
+    // Rollback: the applied step is removed, nothing else runs
+    let (_, run) =
+        send(&app, "GET", &format!("/api/scenario-runs/{}", rolled_back.id), None).await;
+    let run = &run["data"];
+    assert_eq!(run["status"], "rolled_back");
+    let statuses: Vec<&str> = run["steps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, ["cleaned_up", "skipped", "skipped"]);
+    let rolled_back_condition = rolled_back.steps[0].condition_id.as_deref().unwrap();
+    assert!(backend.manifest(&topology_id, rolled_back_condition).is_none());
+    let condition = state.db.get_chaos_condition(rolled_back_condition).await.unwrap().unwrap();
+    assert_eq!(condition.status, ChaosConditionStatus::Paused);
+
+    // Resume: the step that ended meanwhile is skipped, the others keep their offsets
+    assert!(state.runs.is_running(&resumed.id));
+    assert_eq!(backend.len(), 1);
+    let (_, run) = send(&app, "GET", &format!("/api/scenario-runs/{}", resumed.id), None).await;
+    let run = &run["data"];
+    assert_eq!(run["status"], "running");
+    assert_eq!(run["steps"][0]["status"], "applied");
+    assert_eq!(run["steps"][1]["status"], "skipped");
+    assert!(run["steps"][1]["error"].is_string());
+    assert_eq!(run["steps"][2]["status"], "scheduled");
+
+    // Recovering again does not touch the run executing now
+    assert!(recover_scenario_runs(&state).await.unwrap().is_empty());
+
+    let (status, _) =
+        send(&app, "POST", &format!("/api/scenario-runs/{}/cancel", resumed.id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(backend.is_empty());
+}
+
+#[tokio::test]
+async fn test_recover_run_with_failed_rollback() {
+    let (_, state, inner) = setup_app_with_backend(None).await;
+    let failing = Arc::new(std::sync::Mutex::new(String::new()));
+    let backend = FailingDeleteBackend {
+        inner: inner.clone(),
+        failing: failing.clone(),
+    };
+    let state = state.with_chaos_backend(Arc::new(backend));
+    let app = networksim_backend::create_router(state.clone());
+    let topology_id = create_test_topology(&app).await;
+
+    let run = interrupted_run(&state, &app, &topology_id, "rollback").await;
+    let condition_id = run.steps[0].condition_id.clone().unwrap();
+    *failing.lock().unwrap() = condition_id.clone();
+
+    // The applied step cannot be lifted: the run is not reported as rolled back
+    let recovered = recover_scenario_runs(&state).await.unwrap();
+    assert_eq!(recovered.len(), 1);
+    let (_, run) = send(&app, "GET", &format!("/api/scenario-runs/{}", run.id), None).await;
+    let run = &run["data"];
+    assert_eq!(run["status"], "failed");
+    assert!(run["error"].as_str().unwrap().contains("Rollback"));
+    assert_eq!(run["steps"][0]["status"], "failed");
+    assert!(inner.manifest(&topology_id, &condition_id).is_some());
+}
+
+#[tokio::test]
+async fn test_cancel_run_with_failed_cleanup() {
+    let (_, state, inner) = setup_app_with_backend(None).await;
+    let failing = Arc::new(std::sync::Mutex::new(String::new()));
+    let backend = FailingDeleteBackend {
+        inner: inner.clone(),
+        failing: failing.clone(),
+    };
+    let state = state.with_chaos_backend(Arc::new(backend));
+    let app = networksim_backend::create_router(state);
+    let topology_id = create_test_topology(&app).await;
+
+    let run_id = start_two_step_run(&app, &topology_id).await;
+    for _ in 0..50 {
+        if !inner.is_empty() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    let (_, conditions) = send(
+        &app,
+        "GET",
+        &format!("/api/topologies/{}/chaos", topology_id),
+        None,
+    )
+    .await;
+    let step_1 = conditions
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["source_node_id"] == "node-1")
+        .unwrap();
+    let condition_id = step_1["id"].as_str().unwrap().to_string();
+    *failing.lock().unwrap() = condition_id.clone();
+
+    // The applied step cannot be lifted: the run is not reported as cancelled
+    let (status, run) =
+        send(&app, "POST", &format!("/api/scenario-runs/{}/cancel", run_id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let run = &run["data"];
+    assert_eq!(run["status"], "failed");
+    assert_eq!(run["error"], "Cancel could not remove chaos of step step-1");
+    assert_eq!(run["steps"][0]["status"], "failed");
+    assert!(inner.manifest(&topology_id, &condition_id).is_some());
+}
+
+/// Manifest with the condition id blanked out, so two conditions can be compared
+fn manifest_shape(backend: &InMemoryChaosBackend, topology_id: &str, condition_id: &str) -> Value {
+    let manifest = backend.manifest(topology_id, condition_id).unwrap();
+    serde_json::from_str(&manifest.to_string().replace(condition_id, "<id>")).unwrap()
+}
+
+#[tokio::test]
+async fn test_scenario_steps_match_chaos_api_manifests() {
+    let config = Config {
+        k8s_namespace_sim: "game-day".to_string(),
+        ..Config::default()
+    };
+    let (app, _, backend) = setup_app_with_backend(Some(config)).await;
+    let topology_id = create_test_topology(&app).await;
+
+    let cases = [
+        ("delay", Some("node-2"), "both", json!({"latency": "100ms", "jitter": "10ms"})),
+        ("loss", None, "from", json!({"loss": "50"})),
+        ("bandwidth", Some("node-3"), "to", json!({"rate": "1mbps"})),
+        ("partition", Some("node-2"), "both", json!({})),
+    ];
+    for (chaos_type, target, direction, params) in cases {
+        // Through the chaos API
+        let (status, created) = send(
+            &app,
+            "POST",
+            "/api/chaos",
+            Some(json!({
+                "topology_id": topology_id,
+                "source_node_id": "node-1",
+                "target_node_id": target,
+                "chaos_type": chaos_type,
+                "direction": direction,
+                "params": params
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}: {}", chaos_type, created);
+        let condition_id = created["id"].as_str().unwrap();
+        let condition_url = format!("/api/topologies/{}/chaos/{}", topology_id, condition_id);
+        send(&app, "POST", &format!("{}/start", condition_url), None).await;
+        let from_api = manifest_shape(&backend, &topology_id, condition_id);
+        send(&app, "POST", &format!("{}/stop", condition_url), None).await;
+        assert!(backend.is_empty());
+
+        // As a scenario step
+        let (status, scenario) = send(
+            &app,
+            "POST",
+            &format!("/api/topologies/{}/scenarios", topology_id),
+            Some(json!({
+                "name": format!("Single {}", chaos_type),
+                "total_duration": 60,
+                "steps": [{
+                    "id": "step-1",
+                    "type": chaos_type,
+                    "sourceNodeId": "node-1",
+                    "targetNodeId": target,
+                    "direction": direction,
+                    "startAt": 0.0,
+                    "duration": 60.0,
+                    "params": params,
+                    "laneId": "lane-1"
+                }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}: {}", chaos_type, scenario);
+        let scenario_id = scenario["data"]["id"].as_str().unwrap();
+        let (_, run) =
+            send(&app, "POST", &format!("/api/scenarios/{}/run", scenario_id), None).await;
+        let run_url = format!("/api/scenario-runs/{}", run["data"]["id"].as_str().unwrap());
+        let mut run = json!(null);
+        for _ in 0..50 {
+            (_, run) = send(&app, "GET", &run_url, None).await;
+            if run["data"]["steps"][0]["status"] == "applied" {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        let step_condition_id = run["data"]["steps"][0]["condition_id"].as_str().unwrap();
+        let from_step = manifest_shape(&backend, &topology_id, step_condition_id);
+        send(&app, "POST", &format!("{}/cancel", run_url), None).await;
+
+        assert_eq!(from_step, from_api, "{} manifests differ", chaos_type);
+        assert_eq!(from_step["metadata"]["namespace"], "game-day");
+        if chaos_type != "partition" {
+            assert_eq!(from_step["spec"]["direction"], direction);
+        }
+    }
+
+    // Steps are validated like conditions of the chaos API
+    let (status, body) = send(
+        &app,
+        "POST",
+        &format!("/api/topologies/{}/scenarios", topology_id),
+        Some(json!({
+            "name": "Unknown node",
+            "total_duration": 60,
+            "steps": [{
+                "id": "step-1",
+                "type": "delay",
+                "sourceNodeId": "node-9",
+                "startAt": 0.0,
+                "duration": 10.0,
+                "params": {"latency": "100ms"},
+                "laneId": "lane-1"
+            }]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["details"][0]["field"], "steps[0]");
+}
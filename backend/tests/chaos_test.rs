@@ -12,6 +12,20 @@ fn test_chaos_type_display() {
     assert_eq!(ChaosType::Corrupt.to_string(), "corrupt");
     assert_eq!(ChaosType::Duplicate.to_string(), "duplicate");
     assert_eq!(ChaosType::Partition.to_string(), "partition");
+    assert_eq!(ChaosType::Reorder.to_string(), "reorder");
+    assert_eq!(ChaosType::FlakyLink.to_string(), "flaky-link");
+    assert_eq!(ChaosType::StressMemory.to_string(), "stress-memory");
+    assert_eq!(ChaosType::DnsError.to_string(), "dns-error");
+    assert_eq!(ChaosType::DnsRandom.to_string(), "dns-random");
+    assert_eq!(ChaosType::ClockSkew.to_string(), "clock-skew");
+    assert_eq!(ChaosType::PodFailure.to_string(), "pod-failure");
+    assert_eq!(ChaosType::ContainerKill.to_string(), "container-kill");
+    assert_eq!(ChaosType::IoFault.to_string(), "io-fault");
+    assert_eq!(ChaosType::IoMistake.to_string(), "io-mistake");
+    assert_eq!(ChaosType::IoAttr.to_string(), "io-attr");
+    assert_eq!(ChaosType::HttpDelay.to_string(), "http-delay");
+    assert_eq!(ChaosType::HttpReplace.to_string(), "http-replace");
+    assert_eq!(ChaosType::HttpPatch.to_string(), "http-patch");
 }
 
 #[test]
@@ -121,10 +135,14 @@ fn test_create_chaos_request_minimal() {
 fn test_chaos_status_serialization() {
     let status = ChaosStatus {
         name: "ns-abc12345-cond1".to_string(),
+        topology_id: "abc12345".to_string(),
         condition_id: "cond1".to_string(),
         chaos_type: ChaosType::Delay,
         phase: "Running".to_string(),
         target_pods: vec!["node-1".to_string(), "node-2".to_string()],
+        injected_pods: vec!["ns-abc12345-node-1".to_string()],
+        selection_mode: SelectionMode::FixedPercent,
+        matched_pods: 1,
         message: Some("Chaos injected successfully".to_string()),
     };
 
@@ -134,6 +152,7 @@ fn test_chaos_status_serialization() {
     assert_eq!(json["chaos_type"], "delay");
     assert_eq!(json["phase"], "Running");
     assert_eq!(json["target_pods"].as_array().unwrap().len(), 2);
+    assert_eq!(json["selection_mode"], "fixed-percent");
 }
 
 #[test]
@@ -145,6 +164,20 @@ fn test_all_chaos_types_serializable() {
         ChaosType::Corrupt,
         ChaosType::Duplicate,
         ChaosType::Partition,
+        ChaosType::Reorder,
+        ChaosType::FlakyLink,
+        ChaosType::StressMemory,
+        ChaosType::DnsError,
+        ChaosType::DnsRandom,
+        ChaosType::ClockSkew,
+        ChaosType::PodFailure,
+        ChaosType::ContainerKill,
+        ChaosType::IoFault,
+        ChaosType::IoMistake,
+        ChaosType::IoAttr,
+        ChaosType::HttpDelay,
+        ChaosType::HttpReplace,
+        ChaosType::HttpPatch,
     ];
 
     for chaos_type in types {
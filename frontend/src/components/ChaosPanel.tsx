@@ -15,6 +15,12 @@ import './ChaosPanel.css';
 import { AffectedAppsModal } from './AffectedAppsModal';
 import { useToast } from './Toast';
 
+// Validation errors come as { error: { code, message, details: [{ field, message }] } }
+function apiErrorMessage(err: any, fallback: string): string {
+  const error = err.response?.data?.error;
+  return (typeof error === 'object' ? error?.message : error) || fallback;
+}
+
 // Countdown timer component for active chaos with duration
 function ChaosCountdown({ startedAt, duration, onExpired }: {
   startedAt?: string;
@@ -265,7 +271,7 @@ export function ChaosPanel({ topologyId, nodes, links, applications = [], onClos
       queryClient.invalidateQueries({ queryKey: ['chaos-conditions', topologyId] });
       setError(null);
     } catch (err: any) {
-      setError(err.response?.data?.error || 'Failed to create chaos condition');
+      setError(apiErrorMessage(err, 'Failed to create chaos condition'));
       console.error(err);
     } finally {
       setLoading(false);
@@ -454,7 +460,7 @@ export function ChaosPanel({ topologyId, nodes, links, applications = [], onClos
       queryClient.invalidateQueries({ queryKey: ['chaos-conditions', topologyId] });
       setEditingCondition(null);
     } catch (err: any) {
-      setError(err.response?.data?.error || 'Failed to update chaos condition');
+      setError(apiErrorMessage(err, 'Failed to update chaos condition'));
       console.error(err);
     } finally {
       setLoading(false);
@@ -2,7 +2,7 @@ import { useRef, useState, useEffect } from 'react';
 import { 
   ZoomIn, ZoomOut, Clock, Save
 } from 'lucide-react';
-import { ChaosType, ChaosParams } from '../../services/api';
+import { ChaosType, ChaosParams, ChaosDirection, StepAssertion } from '../../services/api';
 import { TimelineRuler } from './TimelineRuler';
 import { TimelineTracks } from './TimelineTracks';
 import { TimelineControls } from './TimelineControls';
@@ -19,6 +19,8 @@ export interface ScenarioStep {
   duration: number; // seconds
   params: ChaosParams;
   laneId: string; // usually sourceNodeId
+  direction?: ChaosDirection; // 'to' by default, like POST /api/chaos
+  assertion?: StepAssertion; // Assertion steps check instead of injecting chaos
 }
 
 import { Scenario as ApiScenario } from '../../services/api';
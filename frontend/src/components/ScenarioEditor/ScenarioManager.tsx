@@ -91,7 +91,8 @@ export const ScenarioManager: React.FC<ScenarioManagerProps> = ({
           // alert('Scenario saved successfully');
       } catch (err: any) {
            console.error("Failed to save scenario", err);
-           const msg = err.response?.data?.error || err.message || "Unknown error";
+           const error = err.response?.data?.error;
+           const msg = (typeof error === 'object' ? error?.message : error) || err.message || "Unknown error";
            alert(`Failed to save scenario: ${msg}`);
       }
   };
@@ -222,9 +222,16 @@ export interface CreateChaosRequest {
   direction: ChaosDirection;
   duration?: string;
   params: ChaosParams;
+  abort_criteria?: AbortCriteria;
 }
 
-export type ChaosConditionStatus = 'pending' | 'active' | 'paused';
+export interface AbortCriteria {
+  max_linked_blocked?: number;
+  abort_on_app_failure?: boolean;
+  max_duration?: string;
+}
+
+export type ChaosConditionStatus = 'pending' | 'active' | 'paused' | 'completed' | 'failed';
 
 export interface ChaosCondition {
   id: string;
@@ -238,6 +245,8 @@ export interface ChaosCondition {
   k8s_name?: string;
   status: ChaosConditionStatus;
   started_at?: string;
+  ends_at?: string;
+  abort_criteria?: AbortCriteria;
   created_at: string;
   updated_at: string;
 }
@@ -251,6 +260,23 @@ export interface ChaosStatus {
   message?: string;
 }
 
+export interface StoppedResource {
+  kind: string;
+  namespace?: string;
+  name: string;
+  topology_id?: string;
+  condition_id?: string;
+  outcome: 'deleted' | 'already_gone' | 'failed';
+  error?: string;
+  known: boolean;
+}
+
+export interface EmergencyStopReport {
+  resources: StoppedResource[];
+  failed: number;
+  paused_conditions: string[];
+}
+
 export const chaosApi = {
   list: async (topologyId: string): Promise<ChaosCondition[]> => {
     const response = await api.get(`/api/topologies/${topologyId}/chaos`);
@@ -295,6 +321,11 @@ export const chaosApi = {
     const response = await api.delete(`/api/topologies/${topologyId}/chaos`);
     return response.data;
   },
+
+  emergencyStop: async (): Promise<EmergencyStopReport> => {
+    const response = await api.post('/api/chaos/emergency-stop');
+    return response.data;
+  },
 };
 
 // Scenarios
@@ -305,10 +336,87 @@ export interface Scenario {
   description?: string;
   total_duration: number;
   steps: any[]; // ScenarioStep[] but simplified
+  abort_criteria?: AbortCriteria;
+  restart_policy?: 'rollback' | 'resume';
   created_at: string;
   updated_at: string;
 }
 
+export type StepAssertion =
+  | { kind: 'reachability'; expect_reachable: boolean }
+  | {
+      kind: 'http_latency';
+      from_app_id: string;
+      to_app_id: string;
+      port?: number;
+      path?: string;
+      percentile?: number; // Default 95
+      max_latency_ms: number;
+    }
+  | { kind: 'linked_pairs_connected' };
+
+export interface ProbeSample {
+  at: number; // Timeline position, in seconds
+  ok: boolean;
+  latency_ms?: number;
+  linked_blocked?: number;
+  error?: string;
+}
+
+export interface AssertionResult {
+  passed: boolean;
+  message: string;
+  observed?: number;
+  checked_at: string;
+}
+
+export type ScenarioRunStatus =
+  | 'running'
+  | 'paused'
+  | 'completed'
+  | 'failed'
+  | 'cancelled'
+  | 'aborted'
+  | 'rolled_back';
+export type RunStepStatus =
+  | 'scheduled'
+  | 'applied'
+  | 'probing'
+  | 'passed'
+  | 'suspended'
+  | 'failed'
+  | 'cleaned_up'
+  | 'skipped';
+
+export interface ScenarioRunStep {
+  step: any; // ScenarioStep
+  status: RunStepStatus;
+  condition_id?: string;
+  applied_at?: string;
+  cleaned_up_at?: string;
+  error?: string;
+  samples: ProbeSample[];
+  result?: AssertionResult;
+}
+
+export interface ScenarioRun {
+  id: string;
+  scenario_id: string;
+  topology_id: string;
+  status: ScenarioRunStatus;
+  force: boolean;
+  restart_policy: 'rollback' | 'resume';
+  total_duration: number;
+  abort_criteria?: AbortCriteria;
+  steps: ScenarioRunStep[];
+  error?: string;
+  time_shift: number;
+  paused_at?: number; // Timeline position while paused, in seconds
+  started_at: string;
+  finished_at?: string;
+  updated_at: string;
+}
+
 export const scenariosApi = {
   list: async (topologyId: string): Promise<Scenario[]> => {
     const response = await api.get(`/api/topologies/${topologyId}/scenarios`);
@@ -338,8 +446,41 @@ export const scenariosApi = {
     await api.delete(`/api/scenarios/${id}`);
   },
 
-  run: async (id: string): Promise<void> => {
-    await api.post(`/api/scenarios/${id}/run`);
+  run: async (id: string): Promise<ScenarioRun> => {
+    const response = await api.post(`/api/scenarios/${id}/run`);
+    return response.data.data;
+  },
+
+  listRuns: async (id: string): Promise<ScenarioRun[]> => {
+    const response = await api.get(`/api/scenarios/${id}/runs`);
+    return response.data.data;
+  },
+
+  getRun: async (runId: string): Promise<ScenarioRun> => {
+    const response = await api.get(`/api/scenario-runs/${runId}`);
+    return response.data.data;
+  },
+
+  cancelRun: async (runId: string): Promise<ScenarioRun> => {
+    const response = await api.post(`/api/scenario-runs/${runId}/cancel`);
+    return response.data.data;
+  },
+
+  pauseRun: async (runId: string, liftFaults = false): Promise<ScenarioRun> => {
+    const response = await api.post(`/api/scenario-runs/${runId}/pause`, null, {
+      params: { lift_faults: liftFaults },
+    });
+    return response.data.data;
+  },
+
+  resumeRun: async (runId: string): Promise<ScenarioRun> => {
+    const response = await api.post(`/api/scenario-runs/${runId}/resume`);
+    return response.data.data;
+  },
+
+  advanceRun: async (runId: string): Promise<ScenarioRun> => {
+    const response = await api.post(`/api/scenario-runs/${runId}/advance`);
+    return response.data.data;
   }
 };
 
@@ -843,6 +984,8 @@ export interface TopologyReport {
   chaos_summary: {
     total_conditions: number;
     active_conditions: number;
+    completed_conditions: number;
+    failed_conditions: number;
     conditions_by_type: Array<{ chaos_type: string; count: number }>;
     conditions: Array<{
       id: string;
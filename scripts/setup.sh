@@ -410,6 +410,7 @@ install_chaos_mesh() {
         -n chaos-mesh \
         --set chaosDaemon.runtime=containerd \
         --set chaosDaemon.socketPath=/run/k3s/containerd/containerd.sock \
+        --set dnsServer.create=true \
         --version ${CHAOS_MESH_VERSION} \
         --wait
     